@@ -0,0 +1,183 @@
+use liars_table::{AnimationSettings, RouletteResult, SpinOutcome};
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+
+/// `--no-animation`で演出を無効にしたか（このプロセスでだけ有効）
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
+/// シリンダーが最低何周してから止まるか
+const MIN_ROTATIONS: usize = 2;
+/// 「ため」の間に表示する点の数
+const SUSPENSE_DOTS: usize = 3;
+
+/// 演出を無効にする（`--no-animation`）
+pub fn set_reduced_motion() {
+    REDUCED_MOTION.store(true, Ordering::Relaxed);
+}
+
+/// 演出を表示するか
+///
+/// 設定で無効、`--no-animation`の指定、長さが0、出力が端末でない（スクリプトやテスト）
+/// のいずれかの場合は表示しない
+pub fn is_enabled(settings: &AnimationSettings) -> bool {
+    settings.enabled
+        && settings.duration_ms > 0
+        && !REDUCED_MOTION.load(Ordering::Relaxed)
+        && io::stdout().is_terminal()
+}
+
+/// 演出に表示する文言
+#[derive(Debug, Clone)]
+pub struct SpinLabels {
+    pub spinning: String,
+    pub click: String,
+    pub bang: String,
+}
+
+/// 演出の1コマ（表示してから次のコマまで待つ時間）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub text: String,
+    pub delay: Duration,
+}
+
+/// 回転・ため・結果の表示のコマを作る
+///
+/// 全体の約6割で止まった位置まで回転しながら徐々に遅くなり、
+/// 約3割のためのあと、残りの時間で結果（バン/カチッ）を表示する
+pub fn spin_frames(
+    outcome: &SpinOutcome,
+    chambers: u8,
+    duration: Duration,
+    labels: &SpinLabels,
+) -> Vec<Frame> {
+    let chambers = chambers.max(1) as usize;
+    let total_ms = duration.as_millis() as u64;
+    let spin_ms = total_ms * 6 / 10;
+    let suspense_ms = total_ms * 3 / 10;
+    let reveal_ms = total_ms - spin_ms - suspense_ms;
+
+    // 1歩ごとの待ち時間を1, 2, 3, ...の比で増やして減速を表現する
+    let steps = MIN_ROTATIONS * chambers + outcome.chamber % chambers + 1;
+    let weight_total = (steps * (steps + 1) / 2) as u64;
+    let mut frames: Vec<Frame> = (0..steps)
+        .map(|step| Frame {
+            text: format!(
+                "{}  {}",
+                cylinder(chambers, step % chambers, None),
+                labels.spinning
+            ),
+            delay: Duration::from_millis(spin_ms * (step as u64 + 1) / weight_total),
+        })
+        .collect();
+
+    let stopped = cylinder(chambers, outcome.chamber % chambers, None);
+    for dots in 1..=SUSPENSE_DOTS {
+        frames.push(Frame {
+            text: format!("{}  {}", stopped, ".".repeat(dots)),
+            delay: Duration::from_millis(suspense_ms / SUSPENSE_DOTS as u64),
+        });
+    }
+
+    let sound = match outcome.result {
+        RouletteResult::Out => &labels.bang,
+        RouletteResult::Safe => &labels.click,
+    };
+    frames.push(Frame {
+        text: format!(
+            "{}  {}",
+            cylinder(chambers, outcome.chamber % chambers, Some(outcome)),
+            sound
+        ),
+        delay: Duration::from_millis(reveal_ms),
+    });
+    frames
+}
+
+/// シリンダーの表示（撃鉄の位置を◉、結果の表示では撃った位置を💥か◌で示す）
+fn cylinder(chambers: usize, position: usize, revealed: Option<&SpinOutcome>) -> String {
+    let slots: Vec<&str> = (0..chambers)
+        .map(|index| match revealed {
+            Some(outcome) if index == position => match outcome.result {
+                RouletteResult::Out => "💥",
+                RouletteResult::Safe => "◌",
+            },
+            _ if index == position => "◉",
+            _ => "○",
+        })
+        .collect();
+    format!("🔫 ⟨ {} ⟩", slots.join(" "))
+}
+
+/// コマを同じ行に上書きしながら表示する
+pub fn play(frames: &[Frame]) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    for frame in frames {
+        // 行を消してから書き直す
+        write!(stdout, "\r\x1b[2K{}", frame.text)?;
+        stdout.flush()?;
+        thread::sleep(frame.delay);
+    }
+    writeln!(stdout)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels() -> SpinLabels {
+        SpinLabels {
+            spinning: "spinning".to_string(),
+            click: "click".to_string(),
+            bang: "bang".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_frames_stop_on_fired_chamber() {
+        let outcome = SpinOutcome {
+            loaded_chambers: vec![3],
+            chamber: 3,
+            result: RouletteResult::Out,
+        };
+        let frames = spin_frames(&outcome, 6, Duration::from_millis(1500), &labels());
+
+        // 2周してから4番目の位置で止まり、結果を表示する
+        let last_spin = &frames[frames.len() - SUSPENSE_DOTS - 2];
+        assert!(last_spin.text.starts_with("🔫 ⟨ ○ ○ ○ ◉ ○ ○ ⟩"));
+        let reveal = frames.last().unwrap();
+        assert_eq!(reveal.text, "🔫 ⟨ ○ ○ ○ 💥 ○ ○ ⟩  bang");
+        assert_eq!(frames.len(), 2 * 6 + 4 + SUSPENSE_DOTS + 1);
+    }
+
+    #[test]
+    fn test_frames_slow_down_within_duration() {
+        let outcome = SpinOutcome {
+            loaded_chambers: vec![0],
+            chamber: 2,
+            result: RouletteResult::Safe,
+        };
+        let duration = Duration::from_millis(2000);
+        let frames = spin_frames(&outcome, 6, duration, &labels());
+
+        let spin_delays: Vec<Duration> = frames[..frames.len() - SUSPENSE_DOTS - 1]
+            .iter()
+            .map(|frame| frame.delay)
+            .collect();
+        assert!(spin_delays.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        let total: Duration = frames.iter().map(|frame| frame.delay).sum();
+        assert!(total <= duration);
+        assert!(total >= duration - Duration::from_millis(50));
+        assert!(frames.last().unwrap().text.ends_with("click"));
+    }
+
+    #[test]
+    fn test_reduced_motion_disables_animation() {
+        set_reduced_motion();
+        assert!(!is_enabled(&AnimationSettings::default()));
+    }
+}
@@ -0,0 +1,585 @@
+use crate::config::{ConfigError, GameConfig, SeatStatus};
+use crate::i18n::{I18nError, I18nManager};
+use crate::rng::{self, RandomSource};
+use crate::roulette::{self, RouletteResult, SpinOutcome};
+use crate::storage::Storage;
+use std::io;
+use thiserror::Error;
+
+/// コマンドの接頭辞
+pub const COMMAND_PREFIX: char = '!';
+
+/// チャットのコマンドを処理する際のエラー（操作できない場合は返信文で伝える）
+#[derive(Error, Debug)]
+#[non_exhaustive]
+#[allow(clippy::enum_variant_names)]
+pub enum BotError {
+    #[error("Configuration error: {0}")]
+    ConfigError(#[from] ConfigError),
+    #[error("I18n error: {0}")]
+    I18nError(#[from] I18nError),
+    #[error("IO error: {0}")]
+    IoError(#[from] io::Error),
+    #[error("Recording error: {0}")]
+    RecordError(String),
+}
+
+/// 操作の対象（`@名前`・名前・座席番号）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    Id(u8),
+    Name(String),
+}
+
+/// チャットから受け付けるコマンド
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// `!roulette @名前`
+    Roulette(Target),
+    /// `!recover 番号`
+    Recover(Target),
+    /// `!revive 番号`
+    Revive(Target),
+    /// `!status`
+    Status,
+    /// `!newgame`
+    NewGame,
+    /// `!help`
+    Help,
+}
+
+/// コマンドとして解釈できなかった理由
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// 知らないコマンド
+    Unknown(String),
+    /// 対象の指定が必要なコマンドに対象がない
+    MissingTarget(String),
+}
+
+/// 1行のメッセージをコマンドとして解釈する（`!`で始まらないメッセージは`None`）
+pub fn parse_command(text: &str) -> Option<Result<Command, ParseError>> {
+    let body = text.trim().strip_prefix(COMMAND_PREFIX)?;
+    let (name, rest) = match body.split_once(char::is_whitespace) {
+        Some((name, rest)) => (name, rest.trim()),
+        None => (body, ""),
+    };
+    let name = name.to_lowercase();
+
+    let target =
+        || parse_target(rest).ok_or_else(|| ParseError::MissingTarget(format!("!{}", name)));
+    let command = match name.as_str() {
+        "roulette" | "r" => target().map(Command::Roulette),
+        "recover" => target().map(Command::Recover),
+        "revive" => target().map(Command::Revive),
+        "status" | "s" => Ok(Command::Status),
+        "newgame" => Ok(Command::NewGame),
+        "help" => Ok(Command::Help),
+        _ => Err(ParseError::Unknown(format!("!{}", name))),
+    };
+    Some(command)
+}
+
+/// `@名前`・名前・座席番号を対象として解釈する
+fn parse_target(text: &str) -> Option<Target> {
+    let text = text.trim();
+    if let Ok(id) = text.parse::<u8>() {
+        return Some(Target::Id(id));
+    }
+    let name = text.strip_prefix('@').unwrap_or(text).trim();
+    (!name.is_empty()).then(|| Target::Name(name.to_string()))
+}
+
+/// 保存した操作（[`ActionRecorder`]に渡す）
+#[derive(Debug)]
+pub struct RecordedAction<'a> {
+    pub command: &'a Command,
+    /// 返信した結果のメッセージ
+    pub message: &'a str,
+    pub before: &'a GameConfig,
+    pub after: &'a GameConfig,
+    /// ロシアンルーレットの対象と結果
+    pub spin: Option<(u8, &'a SpinOutcome)>,
+}
+
+/// 保存した操作を取り消し履歴・観戦画面・トーナメントなどに記録する
+pub trait ActionRecorder {
+    /// 操作を保存した後に呼ばれる
+    fn record(&mut self, action: &RecordedAction<'_>) -> Result<(), BotError>;
+}
+
+/// チャットとのメッセージのやり取り（Discord・標準入出力など）
+pub trait Transport {
+    /// 次のメッセージを受け取る（終了した場合は`None`）
+    fn receive(&mut self) -> io::Result<Option<String>>;
+    /// 返信を送る
+    fn send(&mut self, message: &str) -> io::Result<()>;
+}
+
+/// 標準入力の1行を1メッセージとして扱う参照実装
+#[cfg(feature = "native")]
+#[derive(Debug, Default)]
+pub struct StdioTransport;
+
+#[cfg(feature = "native")]
+impl Transport for StdioTransport {
+    fn receive(&mut self) -> io::Result<Option<String>> {
+        let mut line = String::new();
+        if io::stdin().read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        Ok(Some(line.trim_end().to_string()))
+    }
+
+    fn send(&mut self, message: &str) -> io::Result<()> {
+        use std::io::Write;
+
+        let mut stdout = io::stdout();
+        writeln!(stdout, "{}", message)?;
+        stdout.flush()
+    }
+}
+
+/// 操作の結果（成功した場合はメッセージとロシアンルーレットの対象・結果、拒否された場合は理由）
+type Reply = Result<(String, Option<(u8, SpinOutcome)>), String>;
+
+/// チャットのコマンドを名前付きの卓への操作に変換する
+///
+/// コマンドごとに保存先から読み込み直して保存するため、
+/// 同じ卓を対話式UIやWeb画面から同時に操作しても内容を上書きしない
+pub struct CommandAdapter<S: Storage> {
+    table_name: String,
+    storage: S,
+    i18n: I18nManager,
+    rng: Box<dyn RandomSource>,
+    recorder: Option<Box<dyn ActionRecorder>>,
+}
+
+impl<S: Storage> CommandAdapter<S> {
+    /// 卓の名前（状態の表示に使う）と保存先から作成
+    pub fn new(table_name: &str, storage: S, i18n: I18nManager) -> Self {
+        Self {
+            table_name: table_name.to_string(),
+            storage,
+            i18n,
+            rng: Box::new(rng::default_rng()),
+            recorder: None,
+        }
+    }
+
+    /// 乱数を差し替える（テスト用）
+    pub fn with_rng(mut self, rng: impl RandomSource + 'static) -> Self {
+        self.rng = Box::new(rng);
+        self
+    }
+
+    /// 保存した操作の記録先を設定する
+    pub fn with_recorder(mut self, recorder: impl ActionRecorder + 'static) -> Self {
+        self.recorder = Some(Box::new(recorder));
+        self
+    }
+
+    /// 保存先
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// メッセージを処理して返信文を返す（コマンドでないメッセージは`None`）
+    pub fn handle(&mut self, text: &str) -> Result<Option<String>, BotError> {
+        let Some(parsed) = parse_command(text) else {
+            return Ok(None);
+        };
+
+        let (mut config, report) = GameConfig::load_from_storage(&self.storage)?;
+        if self.i18n.current_language() != config.language
+            && self.i18n.is_language_available(&config.language)
+        {
+            self.i18n.load_language(&config.language)?;
+        }
+
+        let command = match parsed {
+            Ok(command) => command,
+            Err(ParseError::Unknown(command)) => {
+                return Ok(Some(self.i18n.get_message_with(
+                    "bot_unknown_command",
+                    &[("command", &command)],
+                )?));
+            }
+            Err(ParseError::MissingTarget(command)) => {
+                return Ok(Some(self.i18n.get_message_with(
+                    "bot_missing_target",
+                    &[("command", &command)],
+                )?));
+            }
+        };
+
+        let before = config.clone();
+        let reply = match &command {
+            Command::Status => return Ok(Some(self.status(&config)?)),
+            Command::Help => return Ok(Some(self.i18n.get_message("bot_help")?)),
+            Command::Roulette(target) => self.roulette(&mut config, target)?,
+            Command::Recover(target) => self
+                .change_seat(
+                    &mut config,
+                    target,
+                    SeatStatus::Out,
+                    ("tui_not_recoverable", "player_recovered"),
+                )?
+                .map(|message| (message, None)),
+            Command::Revive(target) => self
+                .change_seat(
+                    &mut config,
+                    target,
+                    SeatStatus::Benched,
+                    ("tui_not_revivable", "player_revived"),
+                )?
+                .map(|message| (message, None)),
+            Command::NewGame => {
+                config.reset_game();
+                Ok((self.i18n.get_message("game_reset")?, None))
+            }
+        };
+
+        // 操作できなかった場合は保存せずに理由を返す
+        let (message, spin) = match reply {
+            Ok(reply) => reply,
+            Err(reason) => return Ok(Some(reason)),
+        };
+        // 移行・復旧が必要な内容は、バックアップを作らずに上書きしない
+        if report.is_some() {
+            return Ok(Some(self.i18n.get_message("bot_migration_required")?));
+        }
+        match config.save_to_storage(&mut self.storage) {
+            Ok(()) => {}
+            Err(ConfigError::StaleConfig) => return Ok(Some(self.i18n.get_message("bot_stale")?)),
+            Err(e) => return Err(e.into()),
+        }
+
+        if let Some(recorder) = self.recorder.as_mut() {
+            recorder.record(&RecordedAction {
+                command: &command,
+                message: &message,
+                before: &before,
+                after: &config,
+                spin: spin.as_ref().map(|(id, outcome)| (*id, outcome)),
+            })?;
+        }
+        Ok(Some(message))
+    }
+
+    /// メッセージがなくなるまで受け取って返信する
+    pub fn serve(&mut self, transport: &mut impl Transport) -> Result<(), BotError> {
+        while let Some(message) = transport.receive()? {
+            if let Some(reply) = self.handle(&message)? {
+                transport.send(&reply)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// ロシアンルーレットを実行する（操作できない場合は`Err`に理由）
+    fn roulette(&mut self, config: &mut GameConfig, target: &Target) -> Result<Reply, BotError> {
+        if config.game_completed {
+            return Ok(Err(self.i18n.get_message("web_game_over")?));
+        }
+        let (id, name) = match self.seat(config, target, SeatStatus::Alive, "tui_not_alive")? {
+            Ok(seat) => seat,
+            Err(reason) => return Ok(Err(reason)),
+        };
+
+        let outcome = roulette::spin_chambers_with(&config.roulette_config(), &mut self.rng);
+        config.resolve_roulette(id, &outcome.result)?;
+        let key = match outcome.result {
+            RouletteResult::Safe => "roulette_result_safe",
+            RouletteResult::Out => "roulette_result_out",
+        };
+        let mut message = self.i18n.get_message_with(key, &[("name", &name)])?;
+        if let Some(winner) = config.check_winner() {
+            let winner = self
+                .i18n
+                .get_message_with("game_winner", &[("name", &winner.name)])?;
+            message = format!("{}\n{}", message, winner);
+            config.mark_game_completed();
+        }
+        Ok(Ok((message, Some((id, outcome)))))
+    }
+
+    /// 回復・蘇生する（`keys`は操作できない場合と成功した場合のメッセージ）
+    fn change_seat(
+        &self,
+        config: &mut GameConfig,
+        target: &Target,
+        expected: SeatStatus,
+        keys: (&str, &str),
+    ) -> Result<Result<String, String>, BotError> {
+        let (id, name) = match self.seat(config, target, expected, keys.0)? {
+            Ok(seat) => seat,
+            Err(reason) => return Ok(Err(reason)),
+        };
+
+        match expected {
+            SeatStatus::Out => config.recover_player(id)?,
+            _ => config.revive_player(id)?,
+        }
+        Ok(Ok(self
+            .i18n
+            .get_message_with(keys.1, &[("name", &name)])?))
+    }
+
+    /// 対象の座席を探し、期待する状態ならプレイヤーIDと名前を返す
+    fn seat(
+        &self,
+        config: &GameConfig,
+        target: &Target,
+        expected: SeatStatus,
+        not_allowed_key: &str,
+    ) -> Result<Result<(u8, String), String>, BotError> {
+        let player = match target {
+            Target::Id(id) => config.get_player(*id),
+            Target::Name(name) => config
+                .players
+                .iter()
+                .find(|p| p.name.to_lowercase() == name.to_lowercase()),
+        };
+        let Some(player) = player else {
+            let target = match target {
+                Target::Id(id) => id.to_string(),
+                Target::Name(name) => name.clone(),
+            };
+            return Ok(Err(self
+                .i18n
+                .get_message_with("bot_unknown_player", &[("name", &target)])?));
+        };
+
+        if config.seat_status(player) != expected {
+            return Ok(Err(self
+                .i18n
+                .get_message_with(not_allowed_key, &[("name", &player.name)])?));
+        }
+        Ok(Ok((player.id, player.name.clone())))
+    }
+
+    /// 座席・ターン・確率の一覧
+    fn status(&self, config: &GameConfig) -> Result<String, BotError> {
+        let mut lines = vec![self.i18n.get_message_with(
+            "bot_status_title",
+            &[
+                ("table", &self.table_name),
+                ("round", &config.round.to_string()),
+            ],
+        )?];
+
+        for player in &config.players {
+            let status = config.seat_status(player);
+            let status_key = match status {
+                SeatStatus::Alive => "tui_status_alive",
+                SeatStatus::Out => "tui_status_out",
+                SeatStatus::Benched => "tui_status_benched",
+            };
+            let is_turn = status == SeatStatus::Alive
+                && player.id == config.current_turn
+                && !config.game_completed;
+            lines.push(self.i18n.get_message_with(
+                "bot_status_seat",
+                &[
+                    ("marker", if is_turn { "▶" } else { "・" }),
+                    ("id", &player.id.to_string()),
+                    ("name", &player.name),
+                    ("status", &self.i18n.get_message(status_key)?),
+                ],
+            )?);
+        }
+
+        match (config.check_winner(), config.current_player()) {
+            (Some(winner), _) => lines.push(
+                self.i18n
+                    .get_message_with("game_winner", &[("name", &winner.name)])?,
+            ),
+            (None, Some(player)) => lines.push(
+                self.i18n
+                    .get_message_with("current_turn", &[("name", &player.name)])?,
+            ),
+            (None, None) => {}
+        }
+        lines.push(self.i18n.get_message_with(
+            "roulette_probability",
+            &[
+                ("live", &config.live_rounds.to_string()),
+                ("capacity", &config.bullet_capacity.to_string()),
+                (
+                    "percentage",
+                    &format!("{:.0}", config.roulette_probability_percentage()),
+                ),
+            ],
+        )?);
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::MemoryStorage;
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+
+    /// 決められたメッセージを返し、返信を記録するテスト用のやり取り
+    #[derive(Default)]
+    struct FakeTransport {
+        incoming: VecDeque<String>,
+        sent: Vec<String>,
+    }
+
+    impl Transport for FakeTransport {
+        fn receive(&mut self) -> io::Result<Option<String>> {
+            Ok(self.incoming.pop_front())
+        }
+
+        fn send(&mut self, message: &str) -> io::Result<()> {
+            self.sent.push(message.to_string());
+            Ok(())
+        }
+    }
+
+    fn adapter(config: GameConfig) -> CommandAdapter<MemoryStorage> {
+        let mut storage = MemoryStorage::default();
+        let mut config = config;
+        config.save_to_storage(&mut storage).unwrap();
+        let mut i18n = I18nManager::bundled().unwrap();
+        i18n.load_language("en").unwrap();
+        CommandAdapter::new("friday", storage, i18n).with_rng(rng::seeded(1))
+    }
+
+    fn english_config() -> GameConfig {
+        let mut config = GameConfig::default();
+        config.language = "en".to_string();
+        config.change_player_name(1, "Alice".to_string()).unwrap();
+        config.change_player_name(2, "Bob".to_string()).unwrap();
+        config
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(parse_command("hello"), None);
+        assert_eq!(
+            parse_command("!roulette @Alice Smith"),
+            Some(Ok(Command::Roulette(Target::Name(
+                "Alice Smith".to_string()
+            ))))
+        );
+        assert_eq!(
+            parse_command(" !REVIVE 3 "),
+            Some(Ok(Command::Revive(Target::Id(3))))
+        );
+        assert_eq!(parse_command("!status"), Some(Ok(Command::Status)));
+        assert_eq!(
+            parse_command("!recover"),
+            Some(Err(ParseError::MissingTarget("!recover".to_string())))
+        );
+        assert_eq!(
+            parse_command("!dance"),
+            Some(Err(ParseError::Unknown("!dance".to_string())))
+        );
+    }
+
+    #[test]
+    fn test_roulette_updates_named_table() {
+        let mut adapter = adapter(english_config());
+        let reply = adapter.handle("!roulette @alice").unwrap().unwrap();
+        assert!(reply.contains("Alice"));
+
+        // 結果は保存先に反映される
+        let (config, _) = GameConfig::load_from_storage(adapter.storage()).unwrap();
+        assert_eq!(config.round, 2);
+        let alive = config.get_player(1).unwrap().is_active;
+        assert_eq!(reply.contains("SAFE"), alive);
+    }
+
+    #[test]
+    fn test_unmigrated_config_is_not_overwritten() {
+        // 現在の形式として読み取れない内容は、バックアップなしに上書きしない
+        let content = "players = \"broken\"\nlanguage = \"en\"\n";
+        let storage = MemoryStorage::new(Some(content.to_string()));
+        let i18n = I18nManager::new().unwrap();
+        let mut adapter = CommandAdapter::new("friday", storage, i18n).with_rng(rng::seeded(1));
+
+        let reply = adapter.handle("!newgame").unwrap().unwrap();
+        assert!(reply.contains("migrated"));
+        assert_eq!(adapter.storage().contents(), Some(content));
+    }
+
+    /// 記録された操作のコマンドを集めるテスト用の記録先
+    #[derive(Clone, Default)]
+    struct FakeRecorder(Rc<RefCell<Vec<Command>>>);
+
+    impl ActionRecorder for FakeRecorder {
+        fn record(&mut self, action: &RecordedAction<'_>) -> Result<(), BotError> {
+            // ロシアンルーレットだけが対象と結果を持つ
+            let is_roulette = matches!(action.command, Command::Roulette(_));
+            assert_eq!(action.spin.is_some(), is_roulette);
+            assert_ne!(action.before.round, action.after.round, "{:?}", action);
+            self.0.borrow_mut().push(action.command.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_saved_commands_are_recorded() {
+        let recorder = FakeRecorder::default();
+        let mut adapter = adapter(english_config()).with_recorder(recorder.clone());
+        adapter.handle("!roulette 1").unwrap();
+        adapter.handle("!revive 1").unwrap();
+        adapter.handle("!status").unwrap();
+        adapter.handle("!newgame").unwrap();
+
+        // 拒否された操作と状態の表示は記録されない
+        assert_eq!(
+            *recorder.0.borrow(),
+            vec![Command::Roulette(Target::Id(1)), Command::NewGame]
+        );
+    }
+
+    #[test]
+    fn test_rejected_commands_reply_with_reason() {
+        let mut adapter = adapter(english_config());
+        let unknown = adapter.handle("!roulette @Nobody").unwrap().unwrap();
+        assert!(unknown.contains("Nobody"));
+        let not_benched = adapter.handle("!revive 1").unwrap().unwrap();
+        assert!(not_benched.contains("Alice"));
+        assert!(
+            adapter
+                .handle("!dance")
+                .unwrap()
+                .unwrap()
+                .contains("!dance")
+        );
+        assert_eq!(adapter.handle("just chatting").unwrap(), None);
+
+        // 拒否された操作は保存されない
+        let (config, _) = GameConfig::load_from_storage(adapter.storage()).unwrap();
+        assert_eq!(config.round, 1);
+    }
+
+    #[test]
+    fn test_serve_replies_through_transport() {
+        let mut config = english_config();
+        config.eliminate_player(2).unwrap();
+        let mut adapter = adapter(config);
+        let mut transport = FakeTransport {
+            incoming: ["!recover Bob", "lol", "!status"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            sent: Vec::new(),
+        };
+
+        adapter.serve(&mut transport).unwrap();
+        assert_eq!(transport.sent.len(), 2);
+        assert!(transport.sent[0].contains("Bob"));
+        let status = &transport.sent[1];
+        assert!(status.contains("friday"));
+        assert!(status.contains("▶ 1. Alice"));
+        assert!(!status.contains("Out"));
+    }
+}
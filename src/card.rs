@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// カードの種類
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CardType {
     Ace,
@@ -9,6 +10,21 @@ pub enum CardType {
     Joker,
 }
 
+impl CardType {
+    /// 宣言できる種類（JOKERは宣言できない）
+    pub const DECLARABLE: [CardType; 3] = [CardType::Ace, CardType::Queen, CardType::King];
+
+    /// 宣言に対して有効なカードか（JOKERはどの宣言にも有効）
+    pub fn satisfies(self, declared: CardType) -> bool {
+        self == declared || self == CardType::Joker
+    }
+}
+
+/// 出されたカードがすべて宣言どおりか判定する
+pub fn is_honest_play(cards: impl IntoIterator<Item = CardType>, declared: CardType) -> bool {
+    cards.into_iter().all(|card_type| card_type.satisfies(declared))
+}
+
 impl fmt::Display for CardType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -20,6 +36,7 @@ impl fmt::Display for CardType {
     }
 }
 
+/// 1枚のカード
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Card {
     pub card_type: CardType,
@@ -45,6 +62,7 @@ impl fmt::Display for Card {
     }
 }
 
+/// 山札（各種類4枚ずつの16枚）
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Deck {
     pub cards: Vec<Card>,
@@ -65,14 +83,22 @@ impl Deck {
     }
     
     pub fn shuffle(&mut self) {
+        self.shuffle_with(&mut crate::rng::default_rng());
+    }
+
+    /// 指定した乱数でシャッフル
+    pub fn shuffle_with<R: crate::rng::RandomSource + ?Sized>(&mut self, rng: &mut R) {
         use rand::seq::SliceRandom;
-        use rand::rngs::OsRng;
-        
-        self.cards.shuffle(&mut OsRng);
+
+        self.cards.shuffle(&mut crate::rng::adapt(rng));
     }
     
-    pub fn deal(&mut self, count: usize) -> Vec<Card> {
-        self.cards.drain(0..count.min(self.cards.len())).collect()
+    /// 上から`count`枚を配る（足りない場合は何も取らずに`None`）
+    pub fn deal(&mut self, count: usize) -> Option<Vec<Card>> {
+        if count > self.cards.len() {
+            return None;
+        }
+        Some(self.cards.drain(0..count).collect())
     }
     
     pub fn remaining(&self) -> usize {
@@ -116,13 +142,26 @@ mod tests {
         assert_eq!(format!("{}", joker), "Joker");
     }
     
+    #[test]
+    fn test_is_honest_play() {
+        use CardType::*;
+        assert!(is_honest_play([Ace, Ace], Ace));
+        assert!(is_honest_play([Ace, Joker], Ace));
+        assert!(is_honest_play([Joker], King));
+        assert!(!is_honest_play([Queen, Joker], Ace));
+    }
+
     #[test]
     fn test_deal() {
         let mut deck = Deck::new();
         let original_count = deck.cards.len();
         
-        let dealt = deck.deal(5);
+        let dealt = deck.deal(5).unwrap();
         assert_eq!(dealt.len(), 5);
         assert_eq!(deck.cards.len(), original_count - 5);
+        
+        // 残りより多くは配らず、山札も減らさない
+        assert!(deck.deal(original_count).is_none());
+        assert_eq!(deck.cards.len(), original_count - 5);
     }
 }
@@ -0,0 +1,705 @@
+use liars_table::{
+    Card, CardType, ConfigError, FileStorage, Game, GameConfig, GameError, GameEvent, I18nError,
+    I18nManager, PublicEvent, PublicView, RouletteResult, Storage, backup_file, game_path,
+    known_cards, public_log,
+};
+use std::io::{self, Write};
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+#[allow(clippy::enum_variant_names)]
+pub enum CardGameError {
+    #[error("I18n error: {0}")]
+    I18nError(#[from] I18nError),
+    #[error("Config error: {0}")]
+    ConfigError(#[from] ConfigError),
+    #[error("Game error: {0}")]
+    GameError(#[from] GameError),
+    #[error("IO error: {0}")]
+    IoError(#[from] io::Error),
+    #[error("JSON error: {0}")]
+    JsonError(#[from] serde_json::Error),
+}
+
+/// カードゲームのコマンド（`liars_table card <コマンド>`、または対話ループの1行）
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CardCommand {
+    /// 卓の参加者で新しいゲームを始める（`force`: 終わっていないゲームを置き換える）
+    New {
+        force: bool,
+    },
+    Deal,
+    /// プレイヤー・出すカードの位置（0から）・宣言
+    Play(u8, Vec<usize>, CardType),
+    /// 直前のプレイを受け入れるプレイヤー
+    Accept(u8),
+    /// 疑うプレイヤー
+    Challenge(u8),
+    Roulette(u8),
+    Status,
+    /// 手札を表示するプレイヤー
+    Hand(u8),
+    /// 公開情報（プレイヤーを指定するとその手札も合わせた内訳）
+    Known(Option<u8>),
+    /// プレイヤーから見える情報をJSONで出力する（ボットの入力）
+    View(u8),
+    Help,
+    Quit,
+}
+
+/// 1行のコマンドを解釈する（カードの位置は1から数えた番号で受け付ける）
+fn parse_command(words: &[&str]) -> Option<CardCommand> {
+    let player = |index: usize| words.get(index).and_then(|w| w.parse::<u8>().ok());
+    let command = match words.first()?.to_lowercase().as_str() {
+        "new" => match words.get(1).copied() {
+            None => CardCommand::New { force: false },
+            Some("--force" | "-f") => CardCommand::New { force: true },
+            Some(_) => return None,
+        },
+        "deal" => CardCommand::Deal,
+        "play" => {
+            let positions = words
+                .get(2)?
+                .split(',')
+                .map(|p| p.trim().parse::<usize>().ok()?.checked_sub(1))
+                .collect::<Option<Vec<_>>>()?;
+            CardCommand::Play(player(1)?, positions, parse_rank(words.get(3)?)?)
+        }
+        "accept" => CardCommand::Accept(player(1)?),
+        "challenge" => CardCommand::Challenge(player(1)?),
+        "roulette" => CardCommand::Roulette(player(1)?),
+        "status" => CardCommand::Status,
+        "hand" => CardCommand::Hand(player(1)?),
+        "known" if words.len() == 1 => CardCommand::Known(None),
+        "known" => CardCommand::Known(Some(player(1)?)),
+        "view" => CardCommand::View(player(1)?),
+        "help" => CardCommand::Help,
+        "quit" | "exit" | "q" => CardCommand::Quit,
+        _ => return None,
+    };
+    Some(command)
+}
+
+/// 宣言する種類（JOKERは宣言できない）
+fn parse_rank(word: &str) -> Option<CardType> {
+    match word.to_lowercase().as_str() {
+        "ace" | "a" => Some(CardType::Ace),
+        "queen" | "q" => Some(CardType::Queen),
+        "king" | "k" => Some(CardType::King),
+        _ => None,
+    }
+}
+
+/// 保存先と結び付いた進行中のカードゲーム（操作のたびに自動保存する）
+pub struct CardSession<S: Storage> {
+    i18n: I18nManager,
+    config: GameConfig,
+    storage: S,
+    game: Option<Game>,
+    /// 保存内容を読み込めなかった場合のエラー
+    corrupt: Option<String>,
+}
+
+impl CardSession<FileStorage> {
+    /// 使用中の卓の設定と、保存されたカードゲームを読み込む
+    pub fn open() -> Result<Self, CardGameError> {
+        let mut i18n = I18nManager::new()?;
+        let config = GameConfig::load()?;
+        if i18n.is_language_available(&config.language) {
+            i18n.load_language(&config.language)?;
+        }
+        Self::with_file(i18n, config, &game_path())
+    }
+
+    /// 保存ファイルから再開する
+    ///
+    /// 壊れている場合は上書きする前にタイムスタンプ付きの名前でコピーを残す
+    fn with_file(
+        i18n: I18nManager,
+        config: GameConfig,
+        path: &Path,
+    ) -> Result<Self, CardGameError> {
+        let mut session = Self::with_storage(i18n, config, FileStorage::new(path))?;
+        if let Some(error) = session.corrupt.take() {
+            let backup = backup_file(path)?;
+            let message = session.i18n.get_message_with(
+                "card_corrupt",
+                &[("error", error), ("backup", backup.display().to_string())],
+            )?;
+            eprintln!("{}", message);
+        }
+        Ok(session)
+    }
+}
+
+impl<S: Storage> CardSession<S> {
+    /// 保存先から進行中のゲームを再開する（壊れている場合は読み込まない）
+    fn with_storage(
+        i18n: I18nManager,
+        config: GameConfig,
+        storage: S,
+    ) -> Result<Self, CardGameError> {
+        let mut session = Self {
+            i18n,
+            config,
+            storage,
+            game: None,
+            corrupt: None,
+        };
+        match Game::load_from_storage(&session.storage) {
+            Ok(game) => session.game = game,
+            Err(GameError::CorruptState(error)) => session.corrupt = Some(error),
+            Err(e) => return Err(e.into()),
+        }
+        Ok(session)
+    }
+
+    /// 再開したゲームの案内
+    fn greeting(&self) -> Result<String, CardGameError> {
+        match &self.game {
+            Some(game) => Ok(self.i18n.get_message_with(
+                "card_resumed",
+                &[("events", game.history.len().to_string())],
+            )?),
+            None => Ok(self.i18n.get_message("card_no_game")?),
+        }
+    }
+
+    /// コマンドを実行して表示する行を返す（ゲームを変更した場合は保存する）
+    fn execute(&mut self, command: CardCommand) -> Result<Vec<String>, CardGameError> {
+        let lines = match command {
+            CardCommand::New { force } => {
+                // 終わっていないゲームは明示的な指定がなければ置き換えない
+                if !force && self.game.as_ref().is_some_and(|g| g.get_winner().is_none()) {
+                    return Ok(vec![self.i18n.get_message("card_unfinished_game")?]);
+                }
+                let participants = self.config.participants();
+                let mut game = Game::try_new(participants.len() as u8, self.config.live_rounds)?;
+                game.roulette_config = self.config.roulette_config();
+                game.set_deck_composition(self.config.deck)?;
+                game.rules = self.config.rules;
+                for (player, seat) in game.players.iter_mut().zip(participants) {
+                    player.name = seat.name.clone();
+                }
+                self.game = Some(game);
+                vec![self.i18n.get_message_with(
+                    "card_new_game",
+                    &[
+                        ("count", participants.len().to_string()),
+                        ("bullets", self.config.live_rounds.to_string()),
+                    ],
+                )?]
+            }
+            CardCommand::Status => return self.status(),
+            CardCommand::Hand(player_id) => {
+                let game = self.game()?;
+                let player = game
+                    .players
+                    .iter()
+                    .find(|p| p.id == player_id)
+                    .ok_or(GameError::PlayerNotFound)?;
+                let cards = player
+                    .hand
+                    .iter()
+                    .enumerate()
+                    .map(|(i, card)| format!("{}:{}", i + 1, card))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                return Ok(vec![self.i18n.get_message_with(
+                    "card_hand",
+                    &[("name", player.name.clone()), ("cards", cards)],
+                )?]);
+            }
+            CardCommand::Known(viewer) => return self.known(viewer),
+            CardCommand::View(viewer) => {
+                let view = PublicView::new(self.game()?, viewer)?;
+                return Ok(vec![serde_json::to_string(&view)?]);
+            }
+            CardCommand::Help => return Ok(vec![self.i18n.get_message("card_help")?]),
+            CardCommand::Quit => return Ok(Vec::new()),
+            CardCommand::Deal => {
+                self.game_mut()?.deal_cards()?;
+                vec![self.i18n.get_message("replay_dealt")?]
+            }
+            CardCommand::Play(player_id, positions, rank) => {
+                let count = positions.len();
+                self.game_mut()?.play_cards(player_id, positions, rank)?;
+                vec![self.i18n.get_message_with(
+                    "play_recorded",
+                    &[
+                        ("name", self.name(player_id)?),
+                        ("rank", rank.to_string()),
+                        ("count", count.to_string()),
+                    ],
+                )?]
+            }
+            CardCommand::Accept(player_id) => {
+                self.game_mut()?.accept(player_id)?;
+                let pile = self.game()?.pile.len();
+                vec![self.i18n.get_message_with(
+                    "card_accepted",
+                    &[("name", self.name(player_id)?), ("pile", pile.to_string())],
+                )?]
+            }
+            CardCommand::Challenge(challenger) => {
+                let result = self.game_mut()?.challenge(challenger)?;
+                let verdict = if result.is_liar {
+                    "verdict_liar"
+                } else {
+                    "verdict_honest"
+                };
+                vec![
+                    self.i18n.get_message_with(
+                        "challenge_declared",
+                        &[
+                            ("accuser", self.name(challenger)?),
+                            ("accused", self.name(result.target_player)?),
+                        ],
+                    )?,
+                    self.i18n.get_message_with(
+                        "card_revealed",
+                        &[("cards", cards_text(&result.actual_cards))],
+                    )?,
+                    self.i18n.get_message(verdict)?,
+                ]
+            }
+            CardCommand::Roulette(target) => {
+                let result = self.game_mut()?.execute_roulette(target)?;
+                let key = match result {
+                    RouletteResult::Safe => "roulette_result_safe",
+                    RouletteResult::Out => "roulette_result_out",
+                };
+                let mut lines = vec![
+                    self.i18n
+                        .get_message_with(key, &[("name", self.name(target)?)])?,
+                ];
+                // チャレンジに負けたプレイヤーならラウンドが終わり、配り直される
+                if let Some(GameEvent::Dealt { .. }) = self.game()?.history.last() {
+                    lines.push(self.i18n.get_message("card_new_round")?);
+                }
+                lines
+            }
+        };
+
+        let mut lines = lines;
+        if let Some(winner) = self.game()?.get_winner() {
+            lines.push(
+                self.i18n
+                    .get_message_with("game_winner", &[("name", self.name(winner)?)])?,
+            );
+        }
+        if let Some(game) = &self.game {
+            game.save_to_storage(&mut self.storage)?;
+        }
+        Ok(lines)
+    }
+
+    /// 手番・場のカード・各プレイヤーの手札の枚数
+    fn status(&self) -> Result<Vec<String>, CardGameError> {
+        let game = self.game()?;
+        let mut lines = Vec::new();
+        if game.get_winner().is_none() {
+            lines.push(
+                self.i18n.get_message_with(
+                    "current_turn",
+                    &[("name", self.name(game.current_player)?)],
+                )?,
+            );
+        }
+        if let Some(last_play) = &game.last_play {
+            lines.push(self.i18n.get_message_with(
+                "card_last_play",
+                &[
+                    ("name", self.name(last_play.player_id)?),
+                    ("rank", last_play.declared_type.to_string()),
+                    ("count", last_play.cards.len().to_string()),
+                ],
+            )?);
+        }
+        for player in &game.players {
+            let mut line = self.i18n.get_message_with(
+                "card_status_player",
+                &[
+                    ("name", player.name.clone()),
+                    ("count", player.cards_count().to_string()),
+                ],
+            )?;
+            if !player.is_active {
+                line = format!("{} ({})", line, self.i18n.get_message("tui_status_out")?);
+            }
+            lines.push(line);
+        }
+        if !game.pile.is_empty() {
+            lines.push(
+                self.i18n
+                    .get_message_with("card_pile", &[("count", game.pile.len().to_string())])?,
+            );
+        }
+        if !game.discard.is_empty() {
+            lines.push(
+                self.i18n
+                    .get_message_with("card_discard", &[("cards", cards_text(&game.discard))])?,
+            );
+        }
+        Ok(lines)
+    }
+
+    /// 公開情報のパネル（種類ごとの内訳・捨て札・宣言と公開の記録）
+    fn known(&self, viewer: Option<u8>) -> Result<Vec<String>, CardGameError> {
+        let game = self.game()?;
+        let mut lines = vec![match viewer {
+            Some(id) => {
+                let player = game
+                    .players
+                    .iter()
+                    .find(|p| p.id == id)
+                    .ok_or(GameError::PlayerNotFound)?;
+                self.i18n
+                    .get_message_with("card_known_viewer", &[("name", player.name.clone())])?
+            }
+            None => self.i18n.get_message("card_known_title")?,
+        }];
+
+        for count in known_cards(game, viewer) {
+            lines.push(self.i18n.get_message_with(
+                "card_known_row",
+                &[
+                    ("rank", count.card_type.to_string()),
+                    ("total", count.total.to_string()),
+                    ("revealed", count.revealed.to_string()),
+                    ("in_hand", count.in_hand.to_string()),
+                    ("played", count.played.to_string()),
+                    ("unseen", count.unseen.to_string()),
+                ],
+            )?);
+        }
+        let discard = if game.discard.is_empty() {
+            "-".to_string()
+        } else {
+            cards_text(&game.discard)
+        };
+        lines.push(
+            self.i18n
+                .get_message_with("card_discard", &[("cards", discard)])?,
+        );
+
+        for event in public_log(game) {
+            let line = match event {
+                PublicEvent::Dealt { .. } => self.i18n.get_message("replay_dealt")?,
+                PublicEvent::Played {
+                    player_id,
+                    count,
+                    declared_type,
+                } => self.i18n.get_message_with(
+                    "card_log_played",
+                    &[
+                        ("name", self.name(player_id)?),
+                        ("rank", declared_type.to_string()),
+                        ("count", count.to_string()),
+                    ],
+                )?,
+                PublicEvent::Accepted {
+                    player_id,
+                    target_player,
+                    count,
+                } => self.i18n.get_message_with(
+                    "card_log_accepted",
+                    &[
+                        ("name", self.name(player_id)?),
+                        ("accused", self.name(target_player)?),
+                        ("count", count.to_string()),
+                    ],
+                )?,
+                PublicEvent::Revealed {
+                    challenger_id,
+                    target_player,
+                    cards,
+                    is_liar,
+                    ..
+                } => {
+                    let verdict = if is_liar {
+                        "verdict_liar"
+                    } else {
+                        "verdict_honest"
+                    };
+                    self.i18n.get_message_with(
+                        "card_log_revealed",
+                        &[
+                            ("accuser", self.name(challenger_id)?),
+                            ("accused", self.name(target_player)?),
+                            ("cards", cards_text(&cards)),
+                            ("verdict", self.i18n.get_message(verdict)?),
+                        ],
+                    )?
+                }
+                PublicEvent::Roulette { player_id, result } => {
+                    let key = match result {
+                        RouletteResult::Safe => "roulette_result_safe",
+                        RouletteResult::Out => "roulette_result_out",
+                    };
+                    self.i18n
+                        .get_message_with(key, &[("name", self.name(player_id)?)])?
+                }
+            };
+            lines.push(format!("  {}", line));
+        }
+        Ok(lines)
+    }
+
+    fn game(&self) -> Result<&Game, CardGameError> {
+        self.game
+            .as_ref()
+            .ok_or(GameError::GameNotInitialized.into())
+    }
+
+    fn game_mut(&mut self) -> Result<&mut Game, CardGameError> {
+        self.game
+            .as_mut()
+            .ok_or(GameError::GameNotInitialized.into())
+    }
+
+    fn name(&self, player_id: u8) -> Result<String, CardGameError> {
+        let game = self.game()?;
+        Ok(game
+            .players
+            .iter()
+            .find(|p| p.id == player_id)
+            .map(|p| p.name.clone())
+            .unwrap_or_else(|| player_id.to_string()))
+    }
+}
+
+/// カードを空白区切りで並べる
+fn cards_text(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .map(Card::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `card`サブコマンド（引数があれば1回だけ実行し、なければ対話ループで1行ずつ実行）
+pub fn run(args: &[String]) -> Result<(), CardGameError> {
+    let mut session = CardSession::open()?;
+    if !args.is_empty() {
+        let words: Vec<&str> = args.iter().map(String::as_str).collect();
+        return execute_line(&mut session, &words);
+    }
+
+    println!("{}", session.greeting()?);
+    loop {
+        print!("{} ", session.i18n.get_message("card_prompt")?);
+        io::stdout().flush()?;
+        let mut input = String::new();
+        if io::stdin().read_line(&mut input)? == 0 {
+            return Ok(());
+        }
+        let words: Vec<&str> = input.split_whitespace().collect();
+        if words.is_empty() {
+            continue;
+        }
+        if parse_command(&words) == Some(CardCommand::Quit) {
+            return Ok(());
+        }
+        // 対話ループでは操作の誤りを表示して続ける
+        if let Err(e) = execute_line(&mut session, &words) {
+            match e {
+                CardGameError::GameError(e) => println!("❌ {}", e),
+                e => return Err(e),
+            }
+        }
+    }
+}
+
+/// 1行を解釈して実行し、結果を表示する
+fn execute_line<S: Storage>(
+    session: &mut CardSession<S>,
+    words: &[&str],
+) -> Result<(), CardGameError> {
+    let Some(command) = parse_command(words) else {
+        let command = words.join(" ");
+        println!(
+            "{}",
+            session
+                .i18n
+                .get_message_with("card_unknown_command", &[("command", command)])?
+        );
+        return Ok(());
+    };
+    match session.execute(command) {
+        Ok(lines) => lines.iter().for_each(|line| println!("{}", line)),
+        Err(CardGameError::GameError(GameError::GameNotInitialized)) => {
+            println!("{}", session.i18n.get_message("card_no_game")?);
+        }
+        Err(e) => return Err(e),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use liars_table::MemoryStorage;
+
+    fn session(storage: MemoryStorage) -> CardSession<MemoryStorage> {
+        let i18n = I18nManager::bundled().unwrap();
+        CardSession::with_storage(i18n, GameConfig::default(), storage).unwrap()
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            parse_command(&["play", "2", "1,3", "Queen"]),
+            Some(CardCommand::Play(2, vec![0, 2], CardType::Queen))
+        );
+        assert_eq!(parse_command(&["play", "2", "0", "queen"]), None);
+        assert_eq!(parse_command(&["play", "2", "1", "joker"]), None);
+        assert_eq!(
+            parse_command(&["accept", "2"]),
+            Some(CardCommand::Accept(2))
+        );
+        assert_eq!(
+            parse_command(&["challenge", "3"]),
+            Some(CardCommand::Challenge(3))
+        );
+        assert_eq!(parse_command(&["known"]), Some(CardCommand::Known(None)));
+        assert_eq!(
+            parse_command(&["known", "2"]),
+            Some(CardCommand::Known(Some(2)))
+        );
+        assert_eq!(parse_command(&["view"]), None);
+        assert_eq!(parse_command(&["dance"]), None);
+    }
+
+    #[test]
+    fn test_autosave_and_resume() {
+        let mut first = session(MemoryStorage::default());
+        assert!(first.execute(CardCommand::Deal).is_err());
+        first.execute(CardCommand::New { force: false }).unwrap();
+        first.execute(CardCommand::Deal).unwrap();
+        first
+            .execute(CardCommand::Play(1, vec![0], CardType::King))
+            .unwrap();
+
+        // 保存された内容から同じ状態で再開できる
+        let resumed = session(first.storage.clone());
+        let (before, after) = (first.game().unwrap(), resumed.game().unwrap());
+        assert_eq!(after.history, before.history);
+        assert_eq!(after.players[0].hand, before.players[0].hand);
+        assert_eq!(after.players[0].name, "プレイヤー1");
+        assert_eq!(after.current_player, 2);
+    }
+
+    #[test]
+    fn test_known_panel_and_view() {
+        let mut session = session(MemoryStorage::default());
+        session.execute(CardCommand::New { force: false }).unwrap();
+        session.execute(CardCommand::Deal).unwrap();
+        let played = session.game().unwrap().players[0].hand[..2].to_vec();
+        session
+            .execute(CardCommand::Play(1, vec![0, 1], CardType::Ace))
+            .unwrap();
+        session.execute(CardCommand::Challenge(2)).unwrap();
+
+        // 公開されたカードは捨て札として残る
+        let panel = session.execute(CardCommand::Known(Some(2))).unwrap();
+        assert!(panel.iter().any(|line| line.contains(&cards_text(&played))));
+        assert_eq!(panel.len(), 1 + 4 + 1 + 3);
+
+        let json = session.execute(CardCommand::View(2)).unwrap();
+        let view: PublicView = serde_json::from_str(&json[0]).unwrap();
+        assert_eq!(view.discard, played);
+        assert_eq!(view.hand, session.game().unwrap().players[1].hand);
+        assert!(session.execute(CardCommand::View(9)).is_err());
+    }
+
+    #[test]
+    fn test_accept_and_new_round() {
+        let mut session = session(MemoryStorage::default());
+        session.execute(CardCommand::New { force: false }).unwrap();
+        session.execute(CardCommand::Deal).unwrap();
+        session
+            .execute(CardCommand::Play(1, vec![0], CardType::Queen))
+            .unwrap();
+        session.execute(CardCommand::Accept(2)).unwrap();
+        let status = session.execute(CardCommand::Status).unwrap();
+        let pile = session
+            .i18n
+            .get_message_with("card_pile", &[("count", "1".to_string())])
+            .unwrap();
+        assert!(status.contains(&pile));
+
+        session
+            .execute(CardCommand::Play(2, vec![0], CardType::Queen))
+            .unwrap();
+        session.execute(CardCommand::Challenge(3)).unwrap();
+        let loser = session.game().unwrap().pending_roulette.unwrap();
+        let lines = session.execute(CardCommand::Roulette(loser)).unwrap();
+        let new_round = session.i18n.get_message("card_new_round").unwrap();
+        assert!(lines.contains(&new_round));
+        assert!(session.game().unwrap().pile.is_empty());
+    }
+
+    #[test]
+    fn test_corrupt_save_is_not_resumed() {
+        let mut storage = MemoryStorage::default();
+        let mut game = Game::new(2, 1);
+        game.deal_cards().unwrap();
+        game.players[0].hand.clear();
+        game.save_to_storage(&mut storage).unwrap();
+
+        let session = session(storage);
+        assert!(session.game.is_none());
+        assert!(session.corrupt.is_some());
+    }
+
+    #[test]
+    fn test_new_keeps_unfinished_game_unless_forced() {
+        let mut session = session(MemoryStorage::default());
+        session.execute(CardCommand::New { force: false }).unwrap();
+        session.execute(CardCommand::Deal).unwrap();
+        let dealt = session.game().unwrap().history.clone();
+
+        // 終わっていないゲームは置き換えない
+        let lines = session.execute(CardCommand::New { force: false }).unwrap();
+        let warning = session.i18n.get_message("card_unfinished_game").unwrap();
+        assert_eq!(lines, vec![warning]);
+        assert_eq!(session.game().unwrap().history, dealt);
+
+        session.execute(CardCommand::New { force: true }).unwrap();
+        assert!(session.game().unwrap().history.is_empty());
+        assert_eq!(
+            parse_command(&["new", "--force"]),
+            Some(CardCommand::New { force: true })
+        );
+        assert_eq!(parse_command(&["new", "now"]), None);
+    }
+
+    #[test]
+    fn test_corrupt_save_is_backed_up() {
+        let dir = std::env::temp_dir().join(format!("liars_table_card_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("game.json");
+        std::fs::write(&path, "{ not a game").unwrap();
+
+        // 上書きする前に元の内容を残す
+        let i18n = I18nManager::bundled().unwrap();
+        let mut session = CardSession::with_file(i18n, GameConfig::default(), &path).unwrap();
+        session.execute(CardCommand::New { force: false }).unwrap();
+        let backups: Vec<String> = std::fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .filter(|name| name.starts_with("game.json.bak-"))
+            .collect();
+        assert_eq!(backups.len(), 1);
+        assert_eq!(
+            std::fs::read_to_string(dir.join(&backups[0])).unwrap(),
+            "{ not a game"
+        );
+        assert_ne!(std::fs::read_to_string(&path).unwrap(), "{ not a game");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
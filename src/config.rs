@@ -1,9 +1,23 @@
+use crate::card::{Card, CardType, Deck, is_honest_play};
+use crate::migration::{self, CURRENT_CONFIG_VERSION, MigrationFailure, MigrationReport};
+#[cfg(feature = "native")]
+use crate::paths::{self, ValueSource};
+use crate::preset::Preset;
+use crate::profile::Profile;
+use crate::roulette::{RouletteConfig, RouletteResult};
+use crate::storage::{self, Storage};
+#[cfg(feature = "native")]
+use crate::storage::{FileLock, FileStorage};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "native")]
 use std::fs;
-use std::path::PathBuf;
+#[cfg(feature = "native")]
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
+/// 設定の読み込み・保存・変更のエラー
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum ConfigError {
     #[error("Configuration file not found")]
     #[allow(dead_code)] // 将来のバージョンで使用予定
@@ -13,6 +27,10 @@ pub enum ConfigError {
     ParseError(String),
     #[error("Invalid configuration value: {0}")]
     InvalidValue(String),
+    #[error("Configuration was changed by another process; reload before saving")]
+    StaleConfig,
+    #[error("Configuration version {0} is newer than this build supports")]
+    UnsupportedVersion(u32),
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
     #[error("TOML parsing error: {0}")]
@@ -21,131 +39,470 @@ pub enum ConfigError {
     TomlSerializeError(#[from] toml::ser::Error),
 }
 
+/// 参加人数の下限
+pub const MIN_PLAYERS: u8 = 2;
+/// 参加人数・座席数の上限
+pub const MAX_PLAYERS: u8 = 8;
+/// デフォルトの参加人数
+pub const DEFAULT_PLAYERS: u8 = 4;
+
+/// 卓の座席に座るプレイヤー
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Player {
     pub id: u8,
     pub name: String,
     pub is_active: bool,
+    #[serde(default)]
+    pub profile_id: Option<u32>, // 紐づけられた保存済みプロフィール
 }
 
+/// 卓の設定と進行状況（座席・ターン・装弾数・除外の記録など）
+///
+/// ロシアンルーレットの結果の反映（[`GameConfig::resolve_roulette`]）や
+/// 回復・蘇生もこの型で行い、TOMLとして保存する
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct GameConfig {
+    #[serde(default = "default_version")]
+    pub version: u32, // 設定ファイルのスキーマバージョン
     pub language: String,
-    pub bullet_capacity: u8, // 装弾数（シリンダー容量）
-    pub players: Vec<Player>,
-    pub current_turn: u8, // 現在のターンのプレイヤーID
+    pub bullet_capacity: u8,  // 装弾数（シリンダー容量）
+    pub players: Vec<Player>, // 座席順のプレイヤー一覧
+    pub current_turn: u8,     // 現在のターンのプレイヤーID
     #[serde(default = "default_participant_count")]
-    pub participant_count: u8, // 参加人数（2-4人）
+    pub participant_count: u8, // 参加人数（先頭から数えた参加席の数）
     #[serde(default)]
     pub game_completed: bool, // ゲーム完了フラグ
+    #[serde(default = "default_live_rounds")]
+    pub live_rounds: u8, // 実弾数
+    #[serde(default)]
+    pub deck: DeckComposition, // 物理デッキの構成
+    #[serde(default)]
+    pub rules: RuleToggles, // ルールの切り替え
+    #[serde(default)]
+    pub animation: AnimationSettings, // ロシアンルーレットの演出
+    #[serde(default)]
+    pub sound: SoundSettings, // 効果音
+    #[serde(default = "default_round")]
+    pub round: u32, // 現在のゲームのラウンド番号（ロシアンルーレットごとに進む）
+    #[serde(default)]
+    pub game_number: u32, // 現在のゲームの通し番号（新しいゲームごとに進む）
+    #[serde(default)]
+    pub eliminations: Vec<Elimination>, // 現在のゲームで除外された順の記録
+    #[serde(skip)]
+    disk_fingerprint: Option<u64>, // 最後に読み書きした時点のファイル内容の指紋
+    #[serde(skip)]
+    env_overrides: Vec<EnvOverride>, // 環境変数で上書きした項目
 }
 
 /// participant_countのデフォルト値
 fn default_participant_count() -> u8 {
-    4
+    DEFAULT_PLAYERS
+}
+
+/// roundのデフォルト値
+fn default_round() -> u32 {
+    1
+}
+
+/// プレイヤーが除外された理由
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EliminationCause {
+    /// ロシアンルーレットで実弾に当たった
+    Roulette,
+    /// 手動で除外された
+    Manual,
+    /// 理由の記録がない（移行前の設定ファイル）
+    #[default]
+    Unknown,
+}
+
+/// 座席の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeatStatus {
+    /// 参加席で生存中
+    Alive,
+    /// 参加席で除外済み
+    Out,
+    /// 控え席
+    Benched,
+}
+
+/// 1人のプレイヤーが除外された記録
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Elimination {
+    pub player_id: u8,
+    #[serde(default)]
+    pub round: Option<u32>, // 除外されたラウンド（記録がない場合は`None`）
+    #[serde(default)]
+    pub cause: EliminationCause,
+}
+
+/// 参加者1人の最終順位
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+    pub place: u8, // 1から始まる順位
+    pub player_id: u8,
+    pub elimination: Option<Elimination>, // 除外されていない場合は`None`
+}
+
+/// 物理デッキの構成（種類ごとの枚数）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DeckComposition {
+    pub aces: u8,
+    pub queens: u8,
+    pub kings: u8,
+    pub jokers: u8,
+}
+
+impl Default for DeckComposition {
+    fn default() -> Self {
+        // 標準のデッキ: 各4枚の計16枚
+        Self {
+            aces: 4,
+            queens: 4,
+            kings: 4,
+            jokers: 4,
+        }
+    }
+}
+
+impl DeckComposition {
+    /// 指定した種類の枚数
+    pub fn count(&self, card_type: CardType) -> u8 {
+        match card_type {
+            CardType::Ace => self.aces,
+            CardType::Queen => self.queens,
+            CardType::King => self.kings,
+            CardType::Joker => self.jokers,
+        }
+    }
+
+    /// デッキの合計枚数
+    pub fn total(&self) -> u32 {
+        [self.aces, self.queens, self.kings, self.jokers]
+            .iter()
+            .map(|&count| count as u32)
+            .sum()
+    }
+
+    /// この構成の山札（種類ごとに1から番号を振る）
+    pub fn deck(&self) -> Deck {
+        let cards = [
+            CardType::Ace,
+            CardType::Queen,
+            CardType::King,
+            CardType::Joker,
+        ]
+        .into_iter()
+        .flat_map(|card_type| (1..=self.count(card_type)).map(move |id| Card { card_type, id }))
+        .collect();
+        Deck { cards }
+    }
+
+    /// デッキに含まれるカードの種類
+    pub fn card_types(&self) -> Vec<CardType> {
+        [
+            CardType::Ace,
+            CardType::Queen,
+            CardType::King,
+            CardType::Joker,
+        ]
+        .into_iter()
+        .filter(|&card_type| self.count(card_type) > 0)
+        .collect()
+    }
+}
+
+/// ゲームモードごとに切り替えられるルール
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RuleToggles {
+    /// JOKERを宣言したどの種類としても扱う
+    #[serde(default = "default_true")]
+    pub jokers_wild: bool,
+}
+
+impl Default for RuleToggles {
+    fn default() -> Self {
+        Self { jokers_wild: true }
+    }
+}
+
+impl RuleToggles {
+    /// 出されたカードがすべて宣言どおりか判定する（`jokers_wild`が無効ならJOKERは嘘）
+    pub fn is_honest_play(
+        &self,
+        cards: impl IntoIterator<Item = CardType>,
+        declared: CardType,
+    ) -> bool {
+        if self.jokers_wild {
+            is_honest_play(cards, declared)
+        } else {
+            cards.into_iter().all(|card_type| card_type == declared)
+        }
+    }
+}
+
+/// ロシアンルーレットの演出の長さの上限（ミリ秒）
+pub const MAX_ANIMATION_MS: u32 = 10_000;
+
+/// ロシアンルーレットの演出の設定
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AnimationSettings {
+    /// シリンダーの回転を表示する（`--no-animation`で一時的に無効化できる）
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// 回転から結果の表示までの長さ（ミリ秒）
+    #[serde(default = "default_animation_duration")]
+    pub duration_ms: u32,
+}
+
+impl Default for AnimationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            duration_ms: default_animation_duration(),
+        }
+    }
+}
+
+/// 効果音の設定
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SoundSettings {
+    /// 効果音を鳴らさない
+    #[serde(default)]
+    pub muted: bool,
+}
+
+/// 演出の長さのデフォルト値（従来の待ち時間と同じ）
+fn default_animation_duration() -> u32 {
+    1500
+}
+
+/// live_roundsのデフォルト値
+fn default_live_rounds() -> u8 {
+    1
+}
+
+/// 有効がデフォルトのルール用
+fn default_true() -> bool {
+    true
+}
+
+/// versionのデフォルト値（スナップショットなどバージョンを持たないデータ用）
+fn default_version() -> u32 {
+    CURRENT_CONFIG_VERSION
+}
+
+/// 環境変数で上書きできる設定項目（環境変数名とキー）
+///
+/// 装弾数を実弾数より先に適用するため、この順に処理する
+const ENV_OVERRIDES: [(&str, &str); 3] = [
+    ("LIARS_TABLE_LANGUAGE", "language"),
+    ("LIARS_TABLE_BULLET_CAPACITY", "bullet_capacity"),
+    ("LIARS_TABLE_LIVE_ROUNDS", "live_rounds"),
+];
+
+/// 環境変数による上書きの記録（保存時に設定ファイルの値を書き戻すため）
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct EnvOverride {
+    var: &'static str,
+    key: &'static str,
+    env_value: String,
+    file_value: String,
+}
+
+/// `config show`で表示する実際に使われる設定値
+#[cfg(feature = "native")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectiveValue {
+    pub key: &'static str,
+    pub value: String,
+    pub source: ValueSource,
+}
+
+impl Player {
+    /// デフォルト名で新しいプレイヤーを作成
+    fn with_default_name(id: u8) -> Self {
+        Self {
+            id,
+            name: format!("プレイヤー{}", id),
+            is_active: true,
+            profile_id: None,
+        }
+    }
 }
 
 impl Default for GameConfig {
     fn default() -> Self {
         Self {
+            version: CURRENT_CONFIG_VERSION,
             language: "ja".to_string(),
             bullet_capacity: 6,
-            participant_count: 4,  // デフォルトは4人参加
-            game_completed: false, // 初期状態はゲーム進行中
-            players: vec![
-                Player {
-                    id: 1,
-                    name: "プレイヤー1".to_string(),
-                    is_active: true,
-                },
-                Player {
-                    id: 2,
-                    name: "プレイヤー2".to_string(),
-                    is_active: true,
-                },
-                Player {
-                    id: 3,
-                    name: "プレイヤー3".to_string(),
-                    is_active: true,
-                },
-                Player {
-                    id: 4,
-                    name: "プレイヤー4".to_string(),
-                    is_active: true,
-                },
-            ],
+            participant_count: DEFAULT_PLAYERS, // デフォルトは4人参加
+            game_completed: false,              // 初期状態はゲーム進行中
+            players: (1..=DEFAULT_PLAYERS)
+                .map(Player::with_default_name)
+                .collect(),
             current_turn: 1,
+            live_rounds: 1,
+            deck: DeckComposition::default(),
+            rules: RuleToggles::default(),
+            animation: AnimationSettings::default(),
+            sound: SoundSettings::default(),
+            round: 1,
+            game_number: 0,
+            eliminations: Vec::new(),
+            disk_fingerprint: None,
+            env_overrides: Vec::new(),
         }
     }
 }
 
 impl GameConfig {
-    /// 設定ファイルから読み込む
-    pub fn load() -> Result<Self, ConfigError> {
-        let config_path = Self::get_config_path();
-
-        if !config_path.exists() {
-            // 設定ファイルが存在しない場合、デフォルト設定を作成して保存
-            let default_config = Self::default();
-            default_config.save()?;
-            return Ok(default_config);
-        }
-
-        let content = fs::read_to_string(&config_path)?;
-        let config_result: Result<GameConfig, toml::de::Error> = toml::from_str(&content);
-
-        match config_result {
-            Ok(config) => {
-                // 設定値の検証
-                config.validate()?;
-                Ok(config)
+    /// TOMLの内容から読み込む
+    ///
+    /// 古い形式は現在の形式に移行し、読み取れない内容は復旧できた値だけを引き継ぐ。
+    /// 移行・復旧した場合はその報告も返す
+    pub fn from_toml(content: &str) -> Result<(Self, Option<MigrationReport>), ConfigError> {
+        match migration::migrate(content) {
+            Ok((mut config, report)) if !report.has_changes() => {
+                config.disk_fingerprint = Some(storage::fingerprint(content));
+                Ok((config, None))
+            }
+            Ok((config, report)) => Ok((config, Some(report))),
+            Err(MigrationFailure::UnsupportedVersion(version)) => {
+                Err(ConfigError::UnsupportedVersion(version))
             }
-            Err(e) => {
-                // パースエラーが発生した場合（古い形式など）、設定ファイルを削除してデフォルト設定を使用
-                eprintln!(
-                    "⚠️ 設定ファイルの形式が古いため、デフォルト設定にリセットします: {}",
-                    e
-                );
-                if let Err(remove_err) = fs::remove_file(&config_path) {
-                    eprintln!("警告: 古い設定ファイルの削除に失敗しました: {}", remove_err);
-                }
-                let default_config = Self::default();
-                default_config.save()?;
-                Ok(default_config)
+            Err(MigrationFailure::Unreadable(reason)) => {
+                let (config, report) = migration::salvage(content, &reason);
+                Ok((config, Some(report)))
             }
         }
     }
 
-    /// 設定ファイルに保存する
-    pub fn save(&self) -> Result<(), ConfigError> {
-        // 保存前に検証
+    /// 保存するTOMLの内容（保存前に検証する）
+    pub fn to_toml(&self) -> Result<String, ConfigError> {
         self.validate()?;
+        Ok(toml::to_string_pretty(&self.file_view())?)
+    }
 
-        let config_path = Self::get_config_path();
+    /// 保存先から読み込む（まだ保存されていない場合はデフォルト設定）
+    pub fn load_from_storage(
+        storage: &dyn Storage,
+    ) -> Result<(Self, Option<MigrationReport>), ConfigError> {
+        match storage.read()? {
+            Some(content) => Self::from_toml(&content),
+            None => Ok((Self::default(), None)),
+        }
+    }
 
-        // 親ディレクトリが存在しない場合は作成
-        if let Some(parent) = config_path.parent() {
-            fs::create_dir_all(parent)?;
+    /// 保存先に保存する
+    ///
+    /// 読み込んだ後に保存先の内容が書き換えられていた場合は、
+    /// 上書きせずに`ConfigError::StaleConfig`を返す
+    pub fn save_to_storage(&mut self, storage: &mut dyn Storage) -> Result<(), ConfigError> {
+        self.validate()?;
+        if let Some(expected) = self.disk_fingerprint
+            && let Some(current) = storage.read()?
+            && storage::fingerprint(&current) != expected
+        {
+            return Err(ConfigError::StaleConfig);
         }
 
-        let content = toml::to_string_pretty(self)?;
-        fs::write(&config_path, content)?;
+        self.overwrite_storage(storage)
+    }
 
+    /// 書き換えの確認をせずに保存し、指紋を更新する
+    fn overwrite_storage(&mut self, storage: &mut dyn Storage) -> Result<(), ConfigError> {
+        let content = self.to_toml()?;
+        storage.write(&content)?;
+        self.disk_fingerprint = Some(storage::fingerprint(&content));
         Ok(())
     }
 
-    /// 設定ファイルのパスを取得
-    fn get_config_path() -> PathBuf {
-        // ホームディレクトリまたは現在のディレクトリに設定ファイルを配置
-        if let Some(home_dir) = dirs::home_dir() {
-            home_dir.join(".liars_table").join("config.toml")
+    /// 別の設定（取り消し履歴のスナップショットなど）の内容に置き換える
+    ///
+    /// ファイルの指紋は引き継がないため、置き換え後もそのまま保存できる
+    pub fn restore_from(&mut self, snapshot: &GameConfig) {
+        let disk_fingerprint = self.disk_fingerprint;
+        let env_overrides = std::mem::take(&mut self.env_overrides);
+        *self = snapshot.clone();
+        self.disk_fingerprint = disk_fingerprint;
+        self.env_overrides = env_overrides;
+    }
+
+    /// 環境変数による上書きを適用する（`lookup`は環境変数名から値を返す）
+    pub fn apply_env_overrides(
+        &mut self,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<(), ConfigError> {
+        for (var, key) in ENV_OVERRIDES {
+            let Some(value) = lookup(var) else {
+                continue;
+            };
+            let file_value = self.field_value(key);
+            self.set_field(key, value.trim())
+                .map_err(|e| ConfigError::InvalidValue(format!("{}: {}", var, e)))?;
+            self.env_overrides.push(EnvOverride {
+                var,
+                key,
+                env_value: self.field_value(key),
+                file_value,
+            });
+        }
+
+        self.validate()
+            .map_err(|e| ConfigError::InvalidValue(format!("Environment override: {}", e)))
+    }
+
+    /// 設定ファイルに書き込む内容（環境変数で上書きしたままの項目は元の値に戻す）
+    fn file_view(&self) -> GameConfig {
+        let mut file = self.clone();
+        for env_override in &self.env_overrides {
+            if file.field_value(env_override.key) == env_override.env_value {
+                let _ = file.set_field(env_override.key, &env_override.file_value);
+            }
+        }
+
+        // 戻した値の組み合わせが不正になる場合は現在の値をそのまま保存する
+        if file.validate().is_ok() {
+            file
         } else {
-            PathBuf::from(".liars_table_config.toml")
+            self.clone()
         }
     }
 
+    /// 環境変数で上書きできる項目の現在の値
+    fn field_value(&self, key: &str) -> String {
+        match key {
+            "language" => self.language.clone(),
+            "bullet_capacity" => self.bullet_capacity.to_string(),
+            "live_rounds" => self.live_rounds.to_string(),
+            _ => String::new(),
+        }
+    }
+
+    /// 環境変数で上書きできる項目に文字列の値を設定する
+    fn set_field(&mut self, key: &str, value: &str) -> Result<(), ConfigError> {
+        let parse_u8 = |value: &str| {
+            value
+                .parse::<u8>()
+                .map_err(|_| ConfigError::InvalidValue(format!("Not a number: {}", value)))
+        };
+        match key {
+            "language" => self.language = value.to_string(),
+            "bullet_capacity" => self.bullet_capacity = parse_u8(value)?,
+            "live_rounds" => self.live_rounds = parse_u8(value)?,
+            _ => {
+                return Err(ConfigError::InvalidValue(format!("Unknown key: {}", key)));
+            }
+        }
+        Ok(())
+    }
+
     /// 設定値の検証
-    fn validate(&self) -> Result<(), ConfigError> {
+    pub fn validate(&self) -> Result<(), ConfigError> {
         // 装弾数の検証
         if self.bullet_capacity == 0 {
             return Err(ConfigError::InvalidValue(
@@ -158,21 +515,77 @@ impl GameConfig {
             ));
         }
 
-        // プレイヤーの検証
-        if self.players.is_empty() {
+        // 実弾数の検証
+        if self.live_rounds == 0 || self.live_rounds > self.bullet_capacity {
             return Err(ConfigError::InvalidValue(
-                "At least one player is required".to_string(),
+                "Live rounds must be between 1 and the bullet capacity".to_string(),
             ));
         }
-        if self.players.len() > 6 {
+
+        // 演出の長さの検証
+        if self.animation.duration_ms > MAX_ANIMATION_MS {
+            return Err(ConfigError::InvalidValue(format!(
+                "Animation duration cannot exceed {} ms",
+                MAX_ANIMATION_MS
+            )));
+        }
+
+        // デッキ構成の検証（宣言できる種類が1枚以上必要）
+        if CardType::DECLARABLE
+            .iter()
+            .all(|&card_type| self.deck.count(card_type) == 0)
+        {
             return Err(ConfigError::InvalidValue(
-                "Maximum 6 players allowed".to_string(),
+                "Deck must contain at least one ACE, QUEEN or KING".to_string(),
             ));
         }
 
-        // プレイヤーIDの重複チェック
+        // プレイヤー（座席）数の検証
+        if self.players.len() < MIN_PLAYERS as usize {
+            return Err(ConfigError::InvalidValue(format!(
+                "At least {} players are required",
+                MIN_PLAYERS
+            )));
+        }
+        if self.players.len() > MAX_PLAYERS as usize {
+            return Err(ConfigError::InvalidValue(format!(
+                "Maximum {} players allowed",
+                MAX_PLAYERS
+            )));
+        }
+
+        // 参加人数の検証
+        if self.participant_count < MIN_PLAYERS
+            || self.participant_count as usize > self.players.len()
+        {
+            return Err(ConfigError::InvalidValue(format!(
+                "Participant count must be between {} and {}",
+                MIN_PLAYERS,
+                self.players.len()
+            )));
+        }
+
+        // 参加者全員に1枚以上配れるデッキが必要
+        if self.deck.total() < self.participant_count as u32 {
+            return Err(ConfigError::InvalidValue(format!(
+                "Deck must contain at least {} cards (one per participant)",
+                self.participant_count
+            )));
+        }
+
+        // プレイヤーID・プロフィールの重複チェック
         let mut ids = std::collections::HashSet::new();
+        let mut profile_ids = std::collections::HashSet::new();
         for player in &self.players {
+            if let Some(profile_id) = player.profile_id
+                && !profile_ids.insert(profile_id)
+            {
+                return Err(ConfigError::InvalidValue(format!(
+                    "Profile {} is assigned to more than one player",
+                    profile_id
+                )));
+            }
+
             if !ids.insert(player.id) {
                 return Err(ConfigError::InvalidValue(format!(
                     "Duplicate player ID: {}",
@@ -197,9 +610,9 @@ impl GameConfig {
 
         // 現在のターンの検証
         if !self
-            .players
+            .active_players()
             .iter()
-            .any(|p| p.id == self.current_turn && p.is_active)
+            .any(|p| p.id == self.current_turn)
         {
             return Err(ConfigError::InvalidValue(
                 "Current turn player is not active".to_string(),
@@ -211,44 +624,193 @@ impl GameConfig {
 
     /// アクティブなプレイヤー数を取得
     pub fn active_player_count(&self) -> usize {
-        self.players.iter().filter(|p| p.is_active).count()
+        self.active_players().len()
+    }
+
+    /// 参加席（座席順の先頭から参加人数分）のプレイヤーを取得
+    pub fn participants(&self) -> &[Player] {
+        let count = (self.participant_count as usize).min(self.players.len());
+        &self.players[..count]
+    }
+
+    /// 参加席以外（控え席）のプレイヤーを取得
+    pub fn benched_players(&self) -> &[Player] {
+        let count = (self.participant_count as usize).min(self.players.len());
+        &self.players[count..]
     }
 
     /// アクティブなプレイヤーのリストを取得（参加人数に基づく）
     pub fn active_players(&self) -> Vec<&Player> {
-        self.players
-            .iter()
-            .filter(|p| p.is_active && p.id <= self.participant_count)
-            .collect()
+        self.participants().iter().filter(|p| p.is_active).collect()
+    }
+
+    /// プレイヤーの座席位置（0始まり）を取得
+    pub fn seat_index(&self, id: u8) -> Option<usize> {
+        self.players.iter().position(|p| p.id == id)
+    }
+
+    /// プレイヤーが参加席に座っているか
+    pub fn is_participant(&self, id: u8) -> bool {
+        self.participants().iter().any(|p| p.id == id)
+    }
+
+    /// 座席の状態（参加席で生存中・除外済み・控え席）
+    pub fn seat_status(&self, player: &Player) -> SeatStatus {
+        match (self.is_participant(player.id), player.is_active) {
+            (true, true) => SeatStatus::Alive,
+            (true, false) => SeatStatus::Out,
+            (false, _) => SeatStatus::Benched,
+        }
     }
 
-    /// 参加人数を設定（2-4人）
-    #[allow(dead_code)] // interactive.rsで使用される
+    /// 参加人数を設定（MIN_PLAYERS-MAX_PLAYERS人）
+    ///
+    /// 座席が足りない場合はデフォルト名のプレイヤーを追加する。
+    /// 参加席に残ったプレイヤーの除外の状態はそのまま
     pub fn set_participant_count(&mut self, count: u8) -> Result<(), ConfigError> {
-        if count < 2 || count > 4 {
-            return Err(ConfigError::InvalidValue(
-                "Participant count must be between 2 and 4".to_string(),
-            ));
+        if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&count) {
+            return Err(ConfigError::InvalidValue(format!(
+                "Participant count must be between {} and {}",
+                MIN_PLAYERS, MAX_PLAYERS
+            )));
         }
 
+        while self.players.len() < count as usize {
+            let id = self.next_player_id()?;
+            self.players.push(Player::with_default_name(id));
+        }
+
+        let before: Vec<u8> = self.participants().iter().map(|p| p.id).collect();
         self.participant_count = count;
+        self.sync_seats(&before);
+
+        Ok(())
+    }
+
+    /// 未使用の最小プレイヤーIDを取得
+    fn next_player_id(&self) -> Result<u8, ConfigError> {
+        (1..=u8::MAX)
+            .find(|id| self.get_player(*id).is_none())
+            .ok_or_else(|| ConfigError::InvalidValue("No player ID available".to_string()))
+    }
+
+    /// 参加席の末尾に新しいプレイヤーを追加し、そのIDを返す
+    pub fn add_player(&mut self, name: Option<String>) -> Result<u8, ConfigError> {
+        if self.players.len() >= MAX_PLAYERS as usize {
+            return Err(ConfigError::InvalidValue(format!(
+                "Maximum {} players allowed",
+                MAX_PLAYERS
+            )));
+        }
+
+        let id = self.next_player_id()?;
+        let mut player = Player::with_default_name(id);
+        if let Some(name) = name {
+            player.name = Self::validate_player_name(&name)?;
+        }
+
+        let seat = (self.participant_count as usize).min(self.players.len());
+        self.players.insert(seat, player);
+        self.participant_count += 1;
+
+        Ok(id)
+    }
 
-        // 参加人数を超えるプレイヤーを非アクティブ化
-        for player in &mut self.players {
-            if player.id > count {
+    /// プレイヤーを座席から取り除く
+    pub fn remove_player(&mut self, id: u8) -> Result<Player, ConfigError> {
+        let index = self
+            .seat_index(id)
+            .ok_or_else(|| ConfigError::InvalidValue(format!("Player {} not found", id)))?;
+
+        if self.players.len() <= MIN_PLAYERS as usize {
+            return Err(ConfigError::InvalidValue(format!(
+                "At least {} players are required",
+                MIN_PLAYERS
+            )));
+        }
+
+        let is_participant = index < self.participant_count as usize;
+        if is_participant && self.participant_count <= MIN_PLAYERS {
+            return Err(ConfigError::InvalidValue(format!(
+                "Participant count cannot be less than {}",
+                MIN_PLAYERS
+            )));
+        }
+
+        // 取り除くプレイヤーが現在のターンの場合、先に次のプレイヤーへ移す
+        if self.current_turn == id {
+            self.next_turn();
+        }
+
+        let removed = self.players.remove(index);
+        if is_participant {
+            self.participant_count -= 1;
+        }
+
+        self.ensure_active_turn();
+
+        Ok(removed)
+    }
+
+    /// プレイヤーを指定した座席位置（0始まり）へ移動する
+    ///
+    /// 参加席は座席順の先頭から数えるため、参加席と控え席をまたぐ移動は
+    /// 参加者の入れ替えになる
+    pub fn move_player(&mut self, id: u8, new_index: usize) -> Result<(), ConfigError> {
+        let index = self
+            .seat_index(id)
+            .ok_or_else(|| ConfigError::InvalidValue(format!("Player {} not found", id)))?;
+
+        if new_index >= self.players.len() {
+            return Err(ConfigError::InvalidValue(format!(
+                "Seat {} does not exist",
+                new_index + 1
+            )));
+        }
+
+        let before: Vec<u8> = self.participants().iter().map(|p| p.id).collect();
+        let player = self.players.remove(index);
+        self.players.insert(new_index, player);
+        self.sync_seats(&before);
+
+        Ok(())
+    }
+
+    /// 参加席と控え席の境界をまたいだプレイヤーの状態を更新する
+    ///
+    /// `before`は変更前に参加席に座っていたプレイヤーのID。新しく参加席に入った
+    /// プレイヤーはアクティブ化し、控え席に移ったプレイヤーは非アクティブ化して
+    /// 除外の記録を取り除く。参加席に残ったプレイヤーの状態は変えない
+    fn sync_seats(&mut self, before: &[u8]) {
+        let count = self.participant_count as usize;
+        for (seat, player) in self.players.iter_mut().enumerate() {
+            if seat >= count {
                 player.is_active = false;
-            } else if player.id <= count {
-                // 参加範囲内のプレイヤーをアクティブ化（ゲーム中でない限り）
+            } else if !before.contains(&player.id) {
                 player.is_active = true;
             }
         }
 
-        // 現在のターンが参加範囲外の場合、最初のプレイヤーに設定
-        if self.current_turn > count {
-            self.current_turn = 1;
-        }
+        let participants: Vec<u8> = self.participants().iter().map(|p| p.id).collect();
+        self.eliminations
+            .retain(|e| participants.contains(&e.player_id));
+        self.ensure_active_turn();
+    }
 
-        Ok(())
+    /// 現在のターンのプレイヤーがアクティブでない場合、座席順で次のアクティブプレイヤーに移す
+    fn ensure_active_turn(&mut self) {
+        if self
+            .active_players()
+            .iter()
+            .any(|p| p.id == self.current_turn)
+        {
+            return;
+        }
+        if let Some(next) = self.next_active_after(self.current_turn) {
+            self.current_turn = next;
+        } else if let Some(first) = self.participants().first() {
+            self.current_turn = first.id;
+        }
     }
 
     /// プレイヤーをIDで検索
@@ -263,8 +825,60 @@ impl GameConfig {
 
     /// プレイヤー名を変更
     pub fn change_player_name(&mut self, id: u8, new_name: String) -> Result<(), ConfigError> {
-        // 名前の検証
-        let trimmed_name = new_name.trim();
+        let name = Self::validate_player_name(&new_name)?;
+
+        if let Some(player) = self.get_player_mut(id) {
+            player.name = name;
+            Ok(())
+        } else {
+            Err(ConfigError::InvalidValue(format!(
+                "Player {} not found",
+                id
+            )))
+        }
+    }
+
+    /// 保存済みプロフィールを座席に割り当てる（名前はプロフィールから設定）
+    pub fn assign_profile(&mut self, id: u8, profile: &Profile) -> Result<(), ConfigError> {
+        if let Some(other) = self
+            .players
+            .iter()
+            .find(|p| p.profile_id == Some(profile.id) && p.id != id)
+        {
+            return Err(ConfigError::InvalidValue(format!(
+                "Profile {} is already seated as player {}",
+                profile.name, other.id
+            )));
+        }
+
+        let name = Self::validate_player_name(&profile.name)?;
+        let player = self
+            .get_player_mut(id)
+            .ok_or_else(|| ConfigError::InvalidValue(format!("Player {} not found", id)))?;
+        player.name = name;
+        player.profile_id = Some(profile.id);
+        Ok(())
+    }
+
+    /// 座席からプロフィールの紐づけを外す
+    pub fn unassign_profile(&mut self, id: u8) -> Result<(), ConfigError> {
+        let player = self
+            .get_player_mut(id)
+            .ok_or_else(|| ConfigError::InvalidValue(format!("Player {} not found", id)))?;
+        player.profile_id = None;
+        Ok(())
+    }
+
+    /// プロフィールIDから座席のプレイヤーを検索
+    pub fn player_by_profile(&self, profile_id: u32) -> Option<&Player> {
+        self.players
+            .iter()
+            .find(|p| p.profile_id == Some(profile_id))
+    }
+
+    /// プレイヤー名を検証し、前後の空白を除いた名前を返す
+    fn validate_player_name(name: &str) -> Result<String, ConfigError> {
+        let trimmed_name = name.trim();
         if trimmed_name.is_empty() {
             return Err(ConfigError::InvalidValue(
                 "Player name cannot be empty".to_string(),
@@ -275,22 +889,31 @@ impl GameConfig {
                 "Player name too long".to_string(),
             ));
         }
-
-        if let Some(player) = self.get_player_mut(id) {
-            player.name = trimmed_name.to_string();
-            Ok(())
-        } else {
-            Err(ConfigError::InvalidValue(format!(
-                "Player {} not found",
-                id
-            )))
-        }
+        Ok(trimmed_name.to_string())
     }
 
-    /// プレイヤーを除外（非アクティブ化）
+    /// プレイヤーを手動で除外（非アクティブ化）
+    #[allow(dead_code)] // 現在はロシアンルーレット経由でのみ除外しているため未使用
     pub fn eliminate_player(&mut self, id: u8) -> Result<(), ConfigError> {
+        self.eliminate_player_by(id, EliminationCause::Manual)
+    }
+
+    /// プレイヤーを除外し、現在のラウンドと理由を記録する
+    pub fn eliminate_player_by(
+        &mut self,
+        id: u8,
+        cause: EliminationCause,
+    ) -> Result<(), ConfigError> {
+        let round = self.round;
         if let Some(player) = self.get_player_mut(id) {
             player.is_active = false;
+            if !self.eliminations.iter().any(|e| e.player_id == id) {
+                self.eliminations.push(Elimination {
+                    player_id: id,
+                    round: Some(round),
+                    cause,
+                });
+            }
 
             // 除外されたプレイヤーが現在のターンの場合、次のプレイヤーに移す
             if self.current_turn == id {
@@ -306,25 +929,103 @@ impl GameConfig {
         }
     }
 
+    /// 除外された参加席のプレイヤーを回復（アクティブ化）
+    pub fn recover_player(&mut self, id: u8) -> Result<(), ConfigError> {
+        if !self.is_participant(id) {
+            return Err(ConfigError::InvalidValue(format!(
+                "Player {} is not a participant",
+                id
+            )));
+        }
+
+        if let Some(player) = self.get_player_mut(id) {
+            player.is_active = true;
+        }
+        self.eliminations.retain(|e| e.player_id != id);
+        Ok(())
+    }
+
+    /// 控え席のプレイヤーを蘇生し、参加席の末尾に加える
+    pub fn revive_player(&mut self, id: u8) -> Result<(), ConfigError> {
+        let index = self
+            .seat_index(id)
+            .ok_or_else(|| ConfigError::InvalidValue(format!("Player {} not found", id)))?;
+
+        let count = self.participant_count as usize;
+        if index < count {
+            return Err(ConfigError::InvalidValue(format!(
+                "Player {} is already a participant",
+                id
+            )));
+        }
+
+        // 控え席の先頭（参加席の直後）へ移動して参加人数を増やす
+        let mut player = self.players.remove(index);
+        player.is_active = true;
+        self.players.insert(count, player);
+        self.participant_count += 1;
+        self.eliminations.retain(|e| e.player_id != id);
+
+        Ok(())
+    }
+
     /// 次のプレイヤーのターンに移す
     pub fn next_turn(&mut self) {
-        let active_players: Vec<_> = self.active_players().into_iter().map(|p| p.id).collect();
+        if let Some(next) = self.next_active_after(self.current_turn) {
+            self.current_turn = next;
+        }
+    }
 
-        if active_players.is_empty() {
-            return;
+    /// 指定プレイヤーの次の席に座るアクティブプレイヤーのIDを取得
+    ///
+    /// 除外済みの席は飛ばす。指定プレイヤー自身が除外済みでも座席順で次を探す
+    pub fn next_active_after(&self, id: u8) -> Option<u8> {
+        let seats = self.participants();
+        match seats.iter().position(|p| p.id == id) {
+            Some(index) => (1..=seats.len())
+                .map(|offset| &seats[(index + offset) % seats.len()])
+                .find(|p| p.is_active)
+                .map(|p| p.id),
+            // 参加席にいない場合は最初のアクティブプレイヤー
+            None => seats.iter().find(|p| p.is_active).map(|p| p.id),
         }
+    }
 
-        // 現在のプレイヤーの次のアクティブプレイヤーを見つける
-        if let Some(current_index) = active_players
-            .iter()
-            .position(|&id| id == self.current_turn)
-        {
-            let next_index = (current_index + 1) % active_players.len();
-            self.current_turn = active_players[next_index];
-        } else {
-            // 現在のプレイヤーがアクティブでない場合、最初のアクティブプレイヤーに設定
-            self.current_turn = active_players[0];
+    /// 指定プレイヤーの前の席に座るアクティブプレイヤーのIDを取得
+    ///
+    /// 直前にカードを出したプレイヤー（チャレンジの対象）を求めるのに使う
+    pub fn previous_active_before(&self, id: u8) -> Option<u8> {
+        let seats = self.participants();
+        let index = seats.iter().position(|p| p.id == id)?;
+        (1..seats.len())
+            .map(|offset| &seats[(index + seats.len() - offset) % seats.len()])
+            .find(|p| p.is_active)
+            .map(|p| p.id)
+    }
+
+    /// ロシアンルーレットの結果を反映し、次のターンのプレイヤーIDを返す
+    ///
+    /// セーフなら撃ったプレイヤーから次のラウンドを始め、アウトなら除外して
+    /// 座席順で次のアクティブプレイヤーに移る
+    pub fn resolve_roulette(
+        &mut self,
+        shooter: u8,
+        result: &RouletteResult,
+    ) -> Result<u8, ConfigError> {
+        if !self.active_players().iter().any(|p| p.id == shooter) {
+            return Err(ConfigError::InvalidValue(format!(
+                "Player {} is not active",
+                shooter
+            )));
         }
+
+        self.current_turn = shooter;
+        if *result == RouletteResult::Out {
+            self.eliminate_player_by(shooter, EliminationCause::Roulette)?;
+        }
+        self.round += 1;
+
+        Ok(self.current_turn)
     }
 
     /// 現在のターンのプレイヤーを取得
@@ -333,24 +1034,62 @@ impl GameConfig {
     }
 
     /// ゲームをリセット（新しいゲームを開始）
-    #[allow(dead_code)] // interactive.rsで使用される
     pub fn reset_game(&mut self) {
         // 全プレイヤーをアクティブ状態に戻す（参加人数の範囲内で）
-        for player in &mut self.players {
-            if player.id <= self.participant_count {
-                player.is_active = true;
-            }
+        let count = self.participant_count as usize;
+        for player in self.players.iter_mut().take(count) {
+            player.is_active = true;
         }
 
-        // 最初のプレイヤーからスタート
-        self.current_turn = 1;
+        // 最初の席のプレイヤーからスタート
+        if let Some(first) = self.players.first() {
+            self.current_turn = first.id;
+        }
 
-        // ゲーム完了フラグをリセット
+        // ゲーム完了フラグ・ラウンド・除外の記録をリセット
         self.game_completed = false;
+        self.round = 1;
+        self.eliminations.clear();
+        self.game_number = self.game_number.wrapping_add(1);
+    }
+
+    /// 参加者全員の順位（1位から）
+    ///
+    /// 残っているプレイヤー、最後に除外されたプレイヤー、…の順。
+    /// 除外の記録がない参加者は末尾に席順で並べる
+    pub fn placements(&self) -> Vec<Placement> {
+        let mut placements: Vec<Placement> = Vec::new();
+        let active = self
+            .participants()
+            .iter()
+            .filter(|p| p.is_active)
+            .map(|p| (p.id, None));
+        let eliminated = self
+            .eliminations
+            .iter()
+            .rev()
+            .map(|e| (e.player_id, Some(*e)));
+        let seats = self.participants().iter().map(|p| (p.id, None));
+        for (player_id, elimination) in active.chain(eliminated).chain(seats) {
+            if self.is_participant(player_id)
+                && !placements.iter().any(|p| p.player_id == player_id)
+            {
+                placements.push(Placement {
+                    place: placements.len() as u8 + 1,
+                    player_id,
+                    elimination,
+                });
+            }
+        }
+        placements
+    }
+
+    /// 最終順位の順（1位から）に並べた参加者のID
+    pub fn finishing_order(&self) -> Vec<u8> {
+        self.placements().iter().map(|p| p.player_id).collect()
     }
 
     /// ゲーム完了をマーク
-    #[allow(dead_code)] // interactive.rsで使用される
     pub fn mark_game_completed(&mut self) {
         self.game_completed = true;
     }
@@ -365,9 +1104,161 @@ impl GameConfig {
         }
     }
 
+    /// 現在の装弾数・実弾数のロシアンルーレット設定
+    pub fn roulette_config(&self) -> RouletteConfig {
+        RouletteConfig {
+            chambers: self.bullet_capacity,
+            loaded_bullets: self.live_rounds,
+        }
+    }
+
     /// ロシアンルーレットの確率を計算（パーセンテージ）
     pub fn roulette_probability_percentage(&self) -> f64 {
-        (100.0 / self.bullet_capacity as f64).round()
+        (self.roulette_config().probability() * 100.0).round()
+    }
+
+    /// プリセットのルールをまとめて適用し、新しいゲームとして開始する
+    ///
+    /// 検証に失敗した場合は何も変更しない
+    pub fn apply_preset(&mut self, preset: &Preset) -> Result<(), ConfigError> {
+        let mut candidate = self.clone();
+        candidate.bullet_capacity = preset.bullet_capacity;
+        candidate.live_rounds = preset.live_rounds;
+        candidate.deck = preset.deck;
+        candidate.rules = preset.rules;
+        candidate.set_participant_count(preset.participant_count)?;
+        candidate.reset_game();
+        candidate.validate()?;
+
+        *self = candidate;
+        Ok(())
+    }
+}
+
+/// ファイルへの保存（`native`）
+#[cfg(feature = "native")]
+impl GameConfig {
+    /// 設定ファイルから読み込む
+    ///
+    /// 古い形式は現在の形式に移行し、読み取れないファイルは削除せずにバックアップしてから
+    /// 復旧できた値だけを引き継ぐ。移行・復旧した場合はその内容を表示する
+    pub fn load() -> Result<Self, ConfigError> {
+        let (config, report) = Self::load_with_report()?;
+        if let Some(report) = report {
+            eprint!("{}", report);
+        }
+        Ok(config)
+    }
+
+    /// 設定ファイルから読み込み、移行・復旧が発生した場合はその報告も返す
+    ///
+    /// 環境変数による上書き（`LIARS_TABLE_LANGUAGE`など）もここで適用する
+    pub fn load_with_report() -> Result<(Self, Option<MigrationReport>), ConfigError> {
+        let (mut config, report) = Self::load_from(&Self::get_config_path())?;
+        config.apply_env_overrides(|var| std::env::var(var).ok())?;
+        Ok((config, report))
+    }
+
+    /// 設定ファイルに保存する
+    ///
+    /// 読み込んだ後に別のプロセスがファイルを書き換えていた場合は、
+    /// 上書きせずに`ConfigError::StaleConfig`を返す
+    pub fn save(&mut self) -> Result<(), ConfigError> {
+        self.save_to(&Self::get_config_path())
+    }
+
+    /// 指定したパスから読み込む（ロック中に移行・復旧の書き込みまで行う）
+    pub fn load_from(path: &Path) -> Result<(Self, Option<MigrationReport>), ConfigError> {
+        let _lock = FileLock::acquire(path)?;
+        let mut file = FileStorage::new(path);
+
+        let Some(content) = file.read()? else {
+            // 設定ファイルが存在しない場合、デフォルト設定を作成して保存
+            let mut default_config = Self::default();
+            default_config.overwrite_storage(&mut file)?;
+            return Ok((default_config, None));
+        };
+
+        let (mut config, report) = Self::from_toml(&content)?;
+        let Some(mut report) = report else {
+            return Ok((config, None));
+        };
+
+        // 書き換える前に元のファイルを残す
+        report.backup_path = Some(storage::backup_file(path)?);
+        config.overwrite_storage(&mut file)?;
+        Ok((config, Some(report)))
+    }
+
+    /// 指定したパスに保存する
+    pub fn save_to(&mut self, path: &Path) -> Result<(), ConfigError> {
+        // 保存前に検証
+        self.validate()?;
+
+        // 別のプロセスとの同時書き込みを防ぐ
+        let _lock = FileLock::acquire(path)?;
+        self.save_to_storage(&mut FileStorage::new(path))
+    }
+
+    /// 実際に使われる設定値とその出どころ
+    pub fn effective_values(&self) -> Vec<EffectiveValue> {
+        let path = Self::get_config_path();
+        let file_keys: Vec<String> = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| toml::from_str::<toml::Table>(&content).ok())
+            .map(|table| table.keys().cloned().collect())
+            .unwrap_or_default();
+
+        let deck = format!(
+            "ACE {} / QUEEN {} / KING {} / JOKER {}",
+            self.deck.aces, self.deck.queens, self.deck.kings, self.deck.jokers
+        );
+        let players = self
+            .players
+            .iter()
+            .map(|player| player.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let values = [
+            ("version", self.version.to_string()),
+            ("language", self.language.clone()),
+            ("bullet_capacity", self.bullet_capacity.to_string()),
+            ("live_rounds", self.live_rounds.to_string()),
+            ("participant_count", self.participant_count.to_string()),
+            ("current_turn", self.current_turn.to_string()),
+            ("players", players),
+            ("deck", deck),
+            ("rules", format!("jokers_wild = {}", self.rules.jokers_wild)),
+            (
+                "animation",
+                format!(
+                    "enabled = {}, duration_ms = {}",
+                    self.animation.enabled, self.animation.duration_ms
+                ),
+            ),
+            ("sound", format!("muted = {}", self.sound.muted)),
+        ];
+
+        values
+            .into_iter()
+            .map(|(key, value)| {
+                let env_override = self
+                    .env_overrides
+                    .iter()
+                    .find(|o| o.key == key && o.env_value == value);
+                let source = match env_override {
+                    Some(env_override) => ValueSource::Env(env_override.var),
+                    None if file_keys.iter().any(|k| k == key) => ValueSource::File(path.clone()),
+                    None => ValueSource::Default,
+                };
+                EffectiveValue { key, value, source }
+            })
+            .collect()
+    }
+
+    /// 設定ファイルのパスを取得（`--config`・環境変数・使用中の卓の順に決まる）
+    fn get_config_path() -> PathBuf {
+        paths::config_path()
     }
 }
 
@@ -380,7 +1271,8 @@ mod tests {
         let config = GameConfig::default();
         assert_eq!(config.language, "ja");
         assert_eq!(config.bullet_capacity, 6);
-        assert_eq!(config.players.len(), 3);
+        assert_eq!(config.players.len(), DEFAULT_PLAYERS as usize);
+        assert_eq!(config.participant_count, DEFAULT_PLAYERS);
         assert_eq!(config.current_turn, 1);
     }
 
@@ -398,6 +1290,40 @@ mod tests {
         // 装弾数が大きすぎる
         config.bullet_capacity = 15;
         assert!(config.validate().is_err());
+
+        // 実弾数は装弾数以下
+        config.bullet_capacity = 6;
+        config.live_rounds = 7;
+        assert!(config.validate().is_err());
+        config.live_rounds = 2;
+        assert!(config.validate().is_ok());
+        assert_eq!(config.roulette_probability_percentage(), 33.0);
+
+        // 演出の長さには上限がある
+        config.animation.duration_ms = MAX_ANIMATION_MS + 1;
+        assert!(config.validate().is_err());
+        config.animation.duration_ms = MAX_ANIMATION_MS;
+        assert!(config.validate().is_ok());
+
+        // 宣言できる種類のカードがないデッキは不正
+        config.deck = DeckComposition {
+            aces: 0,
+            queens: 0,
+            kings: 0,
+            jokers: 4,
+        };
+        assert!(config.validate().is_err());
+
+        // 参加者全員に1枚ずつ配れないデッキは不正
+        config.deck = DeckComposition {
+            aces: 1,
+            queens: 1,
+            kings: 1,
+            jokers: 0,
+        };
+        assert!(config.validate().is_err());
+        config.deck.jokers = 1;
+        assert!(config.validate().is_ok());
     }
 
     #[test]
@@ -417,7 +1343,7 @@ mod tests {
         assert!(!config.get_player(1).unwrap().is_active);
 
         // アクティブプレイヤー数
-        assert_eq!(config.active_player_count(), 2);
+        assert_eq!(config.active_player_count(), DEFAULT_PLAYERS as usize - 1);
     }
 
     #[test]
@@ -434,7 +1360,310 @@ mod tests {
         config.next_turn();
         assert_eq!(config.current_turn, 3);
 
+        config.next_turn();
+        assert_eq!(config.current_turn, 4);
+
         config.next_turn();
         assert_eq!(config.current_turn, 1); // 最初に戻る
     }
+
+    #[test]
+    fn test_next_turn_skips_eliminated_seats() {
+        let mut config = GameConfig::default();
+        config.eliminate_player(3).unwrap();
+
+        config.current_turn = 2;
+        config.next_turn();
+        assert_eq!(config.current_turn, 4);
+
+        // 現在のターンのプレイヤーが除外されたら座席順で次へ
+        config.current_turn = 1;
+        config.eliminate_player(2).unwrap();
+        config.eliminate_player(1).unwrap();
+        assert_eq!(config.current_turn, 4);
+    }
+
+    #[test]
+    fn test_previous_active_before() {
+        let mut config = GameConfig::default();
+        assert_eq!(config.previous_active_before(1), Some(4));
+        assert_eq!(config.previous_active_before(3), Some(2));
+
+        config.eliminate_player(2).unwrap();
+        assert_eq!(config.previous_active_before(3), Some(1));
+        assert_eq!(config.previous_active_before(9), None);
+    }
+
+    #[test]
+    fn test_resolve_roulette_advances_turn() {
+        let mut config = GameConfig::default();
+        config.eliminate_player(3).unwrap();
+
+        // セーフなら撃ったプレイヤーのターン
+        let next = config.resolve_roulette(4, &RouletteResult::Safe).unwrap();
+        assert_eq!(next, 4);
+        assert_eq!(config.current_turn, 4);
+
+        // アウトなら除外済みの席を飛ばして次のプレイヤーへ
+        config.current_turn = 1;
+        let next = config.resolve_roulette(2, &RouletteResult::Out).unwrap();
+        assert_eq!(next, 4);
+        assert!(!config.get_player(2).unwrap().is_active);
+
+        // 除外済みのプレイヤーは撃てない
+        assert!(config.resolve_roulette(3, &RouletteResult::Safe).is_err());
+    }
+
+    #[test]
+    fn test_placements_record_round_and_cause() {
+        let mut config = GameConfig::default();
+        config.resolve_roulette(1, &RouletteResult::Safe).unwrap();
+        config.resolve_roulette(2, &RouletteResult::Out).unwrap();
+        assert_eq!(config.round, 3);
+        config.eliminate_player(4).unwrap();
+        config.resolve_roulette(1, &RouletteResult::Out).unwrap();
+
+        let placements = config.placements();
+        let order: Vec<(u8, u8)> = placements.iter().map(|p| (p.place, p.player_id)).collect();
+        assert_eq!(order, vec![(1, 3), (2, 1), (3, 4), (4, 2)]);
+        assert!(placements[0].elimination.is_none());
+
+        let last_out = placements[1].elimination.unwrap();
+        assert_eq!(last_out.round, Some(3));
+        assert_eq!(last_out.cause, EliminationCause::Roulette);
+        assert_eq!(
+            placements[2].elimination.unwrap().cause,
+            EliminationCause::Manual
+        );
+        assert_eq!(placements[3].elimination.unwrap().round, Some(2));
+
+        // 新しいゲームでは記録とラウンドがリセットされる
+        config.reset_game();
+        assert_eq!(config.round, 1);
+        assert!(config.eliminations.is_empty());
+    }
+
+    #[test]
+    fn test_participant_count_limits() {
+        let mut config = GameConfig::default();
+
+        assert!(config.set_participant_count(MIN_PLAYERS - 1).is_err());
+        assert!(config.set_participant_count(MAX_PLAYERS + 1).is_err());
+
+        // 座席が足りない場合は追加される
+        config.set_participant_count(MAX_PLAYERS).unwrap();
+        assert_eq!(config.players.len(), MAX_PLAYERS as usize);
+        assert_eq!(config.active_player_count(), MAX_PLAYERS as usize);
+        assert!(config.validate().is_ok());
+
+        // 減らした分は控え席になる
+        config.current_turn = 8;
+        config.set_participant_count(MIN_PLAYERS).unwrap();
+        assert_eq!(config.active_player_count(), MIN_PLAYERS as usize);
+        assert_eq!(config.benched_players().len(), 6);
+        assert_eq!(config.current_turn, 1);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_add_and_remove_players() {
+        let mut config = GameConfig::default();
+
+        let id = config.add_player(Some(" 新人 ".to_string())).unwrap();
+        assert_eq!(id, 5);
+        assert_eq!(config.participant_count, 5);
+        assert_eq!(config.get_player(id).unwrap().name, "新人");
+
+        while config.players.len() < MAX_PLAYERS as usize {
+            config.add_player(None).unwrap();
+        }
+        assert!(config.add_player(None).is_err());
+
+        // 現在のターンのプレイヤーを取り除くとターンが次へ移る
+        config.remove_player(1).unwrap();
+        assert_eq!(config.current_turn, 2);
+        assert_eq!(config.participant_count, MAX_PLAYERS - 1);
+
+        // 空いたIDは再利用される
+        assert_eq!(config.add_player(None).unwrap(), 1);
+
+        while config.players.len() > MIN_PLAYERS as usize {
+            let id = config.players.last().unwrap().id;
+            config.remove_player(id).unwrap();
+        }
+        assert!(config.remove_player(config.players[0].id).is_err());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_move_player() {
+        let mut config = GameConfig::default();
+        config.set_participant_count(3).unwrap();
+
+        config.move_player(4, 0).unwrap();
+        let order: Vec<u8> = config.players.iter().map(|p| p.id).collect();
+        assert_eq!(order, vec![4, 1, 2, 3]);
+
+        // 参加席からあふれたプレイヤーは控え席になる
+        assert!(config.get_player(4).unwrap().is_active);
+        assert!(!config.get_player(3).unwrap().is_active);
+        assert!(!config.is_participant(3));
+        assert!(config.move_player(4, 4).is_err());
+    }
+
+    #[test]
+    fn test_move_player_across_bench() {
+        let mut config = GameConfig::default();
+        config.set_participant_count(3).unwrap();
+        config.eliminate_player(1).unwrap();
+        assert_eq!(config.current_turn, 2);
+
+        // 参加席から控え席へ移ると、押し出された控え席のプレイヤーが参加する
+        config.move_player(1, 3).unwrap();
+        let order: Vec<u8> = config.players.iter().map(|p| p.id).collect();
+        assert_eq!(order, vec![2, 3, 4, 1]);
+        assert!(config.get_player(4).unwrap().is_active);
+        assert!(!config.get_player(1).unwrap().is_active);
+        assert!(config.eliminations.is_empty());
+        assert_eq!(config.active_player_count(), 3);
+
+        // 除外済みの参加者は参加席の中で移動しても除外のまま
+        config.eliminate_player(3).unwrap();
+        config.move_player(3, 0).unwrap();
+        assert!(!config.get_player(3).unwrap().is_active);
+        assert_eq!(config.eliminations.len(), 1);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_turn_fallback_skips_inactive_seats() {
+        let mut config = GameConfig::default();
+        config.set_participant_count(3).unwrap();
+        config.eliminate_player(1).unwrap();
+        config.eliminate_player(3).unwrap();
+        assert_eq!(config.current_turn, 2);
+
+        // 控え席に移した席の除外記録は消え、ターンは除外済みの先頭の席に戻らない
+        config.current_turn = 3;
+        config.set_participant_count(2).unwrap();
+        assert_eq!(config.eliminations.len(), 1);
+        assert!(!config.get_player(1).unwrap().is_active);
+        assert_eq!(config.current_turn, 2);
+
+        // 参加席に戻ったプレイヤーはアクティブになる
+        config.set_participant_count(4).unwrap();
+        assert!(config.get_player(3).unwrap().is_active);
+        assert!(config.get_player(4).unwrap().is_active);
+
+        config.current_turn = 4;
+        config.remove_player(4).unwrap();
+        assert_eq!(config.current_turn, 2);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_assign_profile() {
+        let mut config = GameConfig::default();
+        let profile = Profile {
+            id: 7,
+            name: "常連".to_string(),
+            color: "red".to_string(),
+            avatar: "🦊".to_string(),
+            language: None,
+        };
+
+        config.assign_profile(2, &profile).unwrap();
+        assert_eq!(config.get_player(2).unwrap().name, "常連");
+        assert_eq!(config.player_by_profile(7).unwrap().id, 2);
+
+        // 同じプロフィールは複数の席に座れない
+        assert!(config.assign_profile(3, &profile).is_err());
+
+        // 席を移動してもプロフィールは同じプレイヤーに紐づく
+        config.move_player(2, 3).unwrap();
+        assert_eq!(config.player_by_profile(7).unwrap().id, 2);
+
+        config.unassign_profile(2).unwrap();
+        assert!(config.player_by_profile(7).is_none());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_recover_and_revive() {
+        let mut config = GameConfig::default();
+        config.set_participant_count(3).unwrap();
+        config.eliminate_player(2).unwrap();
+
+        config.recover_player(2).unwrap();
+        assert!(config.get_player(2).unwrap().is_active);
+        assert!(config.recover_player(4).is_err());
+
+        config.revive_player(4).unwrap();
+        assert!(config.is_participant(4));
+        assert_eq!(config.participant_count, 4);
+        assert!(config.revive_player(4).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "native")]
+    fn test_save_detects_stale_read() {
+        let dir = std::env::temp_dir().join(format!("liars_table_config_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("config.toml");
+
+        // 同じファイルを2つのプロセスが読み込んだ状態
+        let (mut first, _) = GameConfig::load_from(&path).unwrap();
+        let (mut second, _) = GameConfig::load_from(&path).unwrap();
+
+        first.change_player_name(1, "太郎".to_string()).unwrap();
+        first.save_to(&path).unwrap();
+
+        // 古い内容を元にした上書きは拒否される
+        second.change_player_name(2, "花子".to_string()).unwrap();
+        assert!(matches!(
+            second.save_to(&path),
+            Err(ConfigError::StaleConfig)
+        ));
+
+        // 読み込み直せば保存できる
+        let (mut reloaded, _) = GameConfig::load_from(&path).unwrap();
+        assert_eq!(reloaded.get_player(1).unwrap().name, "太郎");
+        reloaded.change_player_name(2, "花子".to_string()).unwrap();
+        reloaded.save_to(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_env_overrides_are_not_persisted() {
+        let mut config = GameConfig::default();
+        config
+            .apply_env_overrides(|var| match var {
+                "LIARS_TABLE_LANGUAGE" => Some("en".to_string()),
+                "LIARS_TABLE_BULLET_CAPACITY" => Some(" 8 ".to_string()),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(config.language, "en");
+        assert_eq!(config.bullet_capacity, 8);
+
+        // 保存内容には設定ファイルの値が残る
+        let file = config.file_view();
+        assert_eq!(file.language, "ja");
+        assert_eq!(file.bullet_capacity, 6);
+
+        // 上書き後に変更した値は保存される
+        config.language = "ja".to_string();
+        config.bullet_capacity = 10;
+        assert_eq!(config.file_view().bullet_capacity, 10);
+
+        // 不正な値はエラー
+        let mut config = GameConfig::default();
+        assert!(
+            config
+                .apply_env_overrides(
+                    |var| (var == "LIARS_TABLE_LIVE_ROUNDS").then(|| "many".to_string())
+                )
+                .is_err()
+        );
+    }
 }
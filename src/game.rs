@@ -1,10 +1,18 @@
 use crate::card::{Card, CardType, Deck};
+use crate::config::{DeckComposition, MAX_PLAYERS, MIN_PLAYERS, RuleToggles};
 use crate::player::Player;
-use crate::roulette::{RouletteResult, execute_roulette};
+use crate::rng::RandomSource;
+use crate::roulette::{RouletteConfig, RouletteResult};
+use crate::storage::Storage;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// 1人に配る手札の枚数
+pub const HAND_SIZE: usize = 5;
+
+/// カードゲームの操作のエラー
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum GameError {
     InvalidPlayerCount,
     InvalidBulletCount,
@@ -17,12 +25,20 @@ pub enum GameError {
     GameAlreadyStarted,
     NotEnoughCards,
     NoLastPlay,
+    /// 自分のプレイは受け入れることも疑うこともできない
+    OwnPlay,
+    /// 状態が壊れている（カードの重複・紛失、脱落者の手番など）
+    CorruptState(String),
 }
 
 impl fmt::Display for GameError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            GameError::InvalidPlayerCount => write!(f, "Invalid player count. Must be 2-3."),
+            GameError::InvalidPlayerCount => write!(
+                f,
+                "Invalid player count. Must be {}-{}.",
+                MIN_PLAYERS, MAX_PLAYERS
+            ),
             GameError::InvalidBulletCount => write!(f, "Invalid bullet count. Must be 1-3."),
             GameError::InvalidCardPosition => write!(f, "Invalid card position."),
             GameError::InvalidCardType => write!(f, "Invalid card type. Use 'ace', 'queen', or 'king'."),
@@ -33,10 +49,14 @@ impl fmt::Display for GameError {
             GameError::GameAlreadyStarted => write!(f, "Game already started."),
             GameError::NotEnoughCards => write!(f, "Not enough cards in deck."),
             GameError::NoLastPlay => write!(f, "No previous play to challenge."),
+            GameError::OwnPlay => write!(f, "Players cannot accept or challenge their own play."),
+            GameError::CorruptState(msg) => write!(f, "Game state is corrupt: {}", msg),
         }
     }
 }
 
+impl std::error::Error for GameError {}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LastPlay {
     pub player_id: u8,
@@ -44,6 +64,22 @@ pub struct LastPlay {
     pub declared_type: CardType,
 }
 
+/// 記録されたカードゲームの出来事（リプレイ用、当時は秘密だった手札も含む）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum GameEvent {
+    /// 手札の配布（プレイヤーIDと配られたカード）
+    Dealt { hands: Vec<(u8, Vec<Card>)> },
+    /// カードを伏せて出し、種類を宣言した
+    Played { player_id: u8, cards: Vec<Card>, declared_type: CardType },
+    /// 直前のプレイを受け入れ、出されたカードを伏せたまま場に積んだ
+    Accepted { player_id: u8, target_player: u8, cards: Vec<Card> },
+    /// 直前のプレイを疑い、出されたカードを公開した
+    Challenged { challenger_id: u8, target_player: u8, revealed: Vec<Card>, declared_type: CardType, is_liar: bool },
+    /// ロシアンルーレットの結果
+    Roulette { player_id: u8, result: RouletteResult },
+}
+
 #[derive(Debug, Clone)]
 pub struct ChallengeResult {
     pub target_player: u8,
@@ -52,14 +88,38 @@ pub struct ChallengeResult {
     pub is_liar: bool,
 }
 
+/// カードゲーム（手札の配布・宣言・チャレンジ・ロシアンルーレット）の進行
+///
+/// 手番のプレイヤーは直前のプレイを受け入れる（カードを出すと自動的に受け入れる）か、
+/// 疑ってチャレンジする。受け入れたカードは伏せたまま場（`pile`）に積まれ、
+/// チャレンジでは直前のプレイだけを公開する。負けたプレイヤーのロシアンルーレットで
+/// ラウンドが終わり、すべてのカードを集めてシャッフルし、配り直す
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Game {
     pub players: Vec<Player>,
     pub current_player: u8,
     pub roulette_config: RouletteConfig,
+    /// 使うデッキの構成（卓の設定から、配る前に`set_deck_composition`で変更する）
+    #[serde(default)]
+    pub deck_composition: DeckComposition,
     pub deck: Deck,
+    /// チャレンジの判定に使うルール（卓の設定・プリセットから）
+    #[serde(default)]
+    pub rules: RuleToggles,
     pub last_play: Option<LastPlay>,
     pub is_started: bool,
+    /// 受け入れられ、伏せたまま場に積まれたカード
+    #[serde(default)]
+    pub pile: Vec<Card>,
+    /// チャレンジで公開され、全員が中身を知っているカード（捨て札）
+    #[serde(default)]
+    pub discard: Vec<Card>,
+    /// チャレンジに負け、ロシアンルーレットでラウンドを終えるプレイヤー
+    #[serde(default)]
+    pub pending_roulette: Option<u8>,
+    /// これまでの出来事（`replay`で見返すために記録する）
+    #[serde(default)]
+    pub history: Vec<GameEvent>,
 }
 
 impl Game {
@@ -73,32 +133,84 @@ impl Game {
             players,
             current_player: 1,
             roulette_config: RouletteConfig::new(bullet_count),
+            deck_composition: DeckComposition::default(),
             deck: Deck::new(),
+            rules: RuleToggles::default(),
             last_play: None,
             is_started: false,
+            pile: Vec::new(),
+            discard: Vec::new(),
+            pending_roulette: None,
+            history: Vec::new(),
         }
     }
     
-    pub fn deal_cards(&mut self) {
+    /// 参加人数を検証してゲームを作成
+    pub fn try_new(player_count: u8, bullet_count: u8) -> Result<Self, GameError> {
+        if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&player_count) {
+            return Err(GameError::InvalidPlayerCount);
+        }
+        Ok(Self::new(player_count, bullet_count))
+    }
+
+    /// デッキの構成を変更する（手札を配った後や、参加者全員に1枚ずつ配れない構成は不可）
+    pub fn set_deck_composition(&mut self, composition: DeckComposition) -> Result<(), GameError> {
         if self.is_started {
-            return; // Already dealt
+            return Err(GameError::GameAlreadyStarted);
+        }
+        let active_count = self.players.iter().filter(|p| p.is_active).count();
+        if (composition.total() as usize) < active_count {
+            return Err(GameError::NotEnoughCards);
+        }
+        self.deck_composition = composition;
+        self.deck = composition.deck();
+        Ok(())
+    }
+
+    pub fn deal_cards(&mut self) -> Result<(), GameError> {
+        self.deal_cards_with(&mut crate::rng::default_rng())
+    }
+
+    /// 指定した乱数でシャッフルして配る
+    ///
+    /// 全員に`HAND_SIZE`枚ずつ配れない人数（4人で16枚など）では、
+    /// 山札を均等に分けられる枚数ずつ配る。1枚ずつも配れなければ`NotEnoughCards`
+    pub fn deal_cards_with<R: RandomSource + ?Sized>(&mut self, rng: &mut R) -> Result<(), GameError> {
+        if self.is_started {
+            return Ok(()); // Already dealt
         }
         
-        self.deck.shuffle();
+        self.deal_round(rng)
+    }
+    
+    /// 場・捨て札・手札のカードをすべて山札に戻し、シャッフルして配る
+    fn deal_round<R: RandomSource + ?Sized>(&mut self, rng: &mut R) -> Result<(), GameError> {
+        self.collect_cards();
         
-        // Deal 5 cards to each player
+        let active_count = self.players.iter().filter(|p| p.is_active).count();
+        let hand_size = HAND_SIZE.min(self.deck.remaining() / active_count.max(1));
+        if hand_size == 0 {
+            return Err(GameError::NotEnoughCards);
+        }
+        self.deck.shuffle_with(rng);
+        let mut hands = Vec::new();
         for player in &mut self.players {
             if player.is_active {
-                let cards = self.deck.deal(5);
+                let cards = self.deck.deal(hand_size).expect("hand size fits the deck");
+                hands.push((player.id, cards.clone()));
                 player.add_cards(cards);
             }
         }
         
         self.is_started = true;
+        self.history.push(GameEvent::Dealt { hands });
+        self.debug_check_invariants();
+        Ok(())
     }
     
+    /// 手番のプレイヤーがカードを伏せて出す（直前のプレイがあれば受け入れてから出す）
     pub fn play_cards(&mut self, player_id: u8, card_positions: Vec<usize>, declared_type: CardType) -> Result<(), GameError> {
-        if player_id != self.current_player {
+        if player_id != self.current_player || self.pending_roulette.is_some() {
             return Err(GameError::InvalidCommand);
         }
         
@@ -107,31 +219,63 @@ impl Game {
         if !player.is_active {
             return Err(GameError::PlayerNotFound);
         }
+        if card_positions.is_empty() {
+            return Err(GameError::InvalidCardPosition);
+        }
 
         let cards = player.remove_cards(&card_positions)
             .map_err(|_| GameError::InvalidCardPosition)?;
         
-        // Validate that cards match declaration or are jokers
-        let _is_valid = cards.iter().all(|card| {
-            card.card_type == declared_type || card.card_type == CardType::Joker
-        });
-        
+        self.accept_last_play(player_id);
         self.last_play = Some(LastPlay {
             player_id,
             cards: cards.clone(),
             declared_type,
         });
+        self.history.push(GameEvent::Played { player_id, cards, declared_type });
         
         // Move to next active player
         self.advance_turn();
+        self.debug_check_invariants();
+        
+        Ok(())
+    }
+    
+    /// 手番のプレイヤーが直前のプレイを受け入れる（出されたカードは伏せたまま場に積む）
+    pub fn accept(&mut self, player_id: u8) -> Result<(), GameError> {
+        self.check_response(player_id)?;
         
+        self.accept_last_play(player_id);
+        self.debug_check_invariants();
         Ok(())
     }
     
+    /// 直前のプレイに応じられるのは、出したプレイヤー以外の手番のプレイヤーだけ
+    fn check_response(&self, player_id: u8) -> Result<&LastPlay, GameError> {
+        let last_play = self.last_play.as_ref().ok_or(GameError::NoLastPlay)?;
+        if player_id == last_play.player_id {
+            return Err(GameError::OwnPlay);
+        }
+        if player_id != self.current_player {
+            return Err(GameError::InvalidCommand);
+        }
+        Ok(last_play)
+    }
+    
+    fn accept_last_play(&mut self, player_id: u8) {
+        if let Some(last_play) = self.last_play.take() {
+            self.pile.extend(last_play.cards.iter().cloned());
+            self.history.push(GameEvent::Accepted {
+                player_id,
+                target_player: last_play.player_id,
+                cards: last_play.cards,
+            });
+        }
+    }
+    
+    /// 直前のプレイだけを公開し、負けたプレイヤーをロシアンルーレットの対象にする
     pub fn challenge(&mut self, challenger_id: u8) -> Result<ChallengeResult, GameError> {
-        let last_play = self.last_play.as_ref()
-            .ok_or(GameError::NoLastPlay)?
-            .clone();
+        let last_play = self.check_response(challenger_id)?.clone();
         
         let challenger = self.find_player(challenger_id)?;
         if !challenger.is_active {
@@ -139,9 +283,10 @@ impl Game {
         }
         
         // Check if the last play was a lie
-        let is_liar = !last_play.cards.iter().all(|card| {
-            card.card_type == last_play.declared_type || card.card_type == CardType::Joker
-        });
+        let is_liar = !self.rules.is_honest_play(
+            last_play.cards.iter().map(|card| card.card_type),
+            last_play.declared_type,
+        );
         
         let result = ChallengeResult {
             target_player: last_play.player_id,
@@ -152,25 +297,58 @@ impl Game {
         
         // Clear last play after challenge
         self.last_play = None;
+        self.discard.extend(result.actual_cards.iter().cloned());
+        self.pending_roulette = Some(if is_liar { result.target_player } else { challenger_id });
+        self.history.push(GameEvent::Challenged {
+            challenger_id,
+            target_player: result.target_player,
+            revealed: result.actual_cards.clone(),
+            declared_type: result.declared_type,
+            is_liar,
+        });
+        self.debug_check_invariants();
         
         Ok(result)
     }
     
     pub fn execute_roulette(&mut self, target_id: u8) -> Result<RouletteResult, GameError> {
+        self.execute_roulette_with(target_id, &mut crate::rng::default_rng())
+    }
+
+    /// 指定した乱数でロシアンルーレットを実行
+    ///
+    /// チャレンジに負けたプレイヤーの場合はラウンドを終え、2人以上残っていれば配り直す
+    pub fn execute_roulette_with<R: RandomSource + ?Sized>(&mut self, target_id: u8, rng: &mut R) -> Result<RouletteResult, GameError> {
+        let ends_round = self.pending_roulette == Some(target_id);
+        if self.pending_roulette.is_some() && !ends_round {
+            return Err(GameError::InvalidCommand);
+        }
         let roulette_config = self.roulette_config.clone();
+        let active_count = self.players.iter().filter(|p| p.is_active).count();
         
         let player = self.find_player_mut(target_id)?;
         
         if !player.is_active {
             return Err(GameError::PlayerNotFound);
         }
+        // 最後の1人は勝者なので撃たない
+        if active_count <= 1 {
+            return Err(GameError::InvalidCommand);
+        }
         
-        let engine = RouletteEngine::new(roulette_config);
-        let result = engine.spin();
+        let result = crate::roulette::spin_chambers_with(&roulette_config, rng).result;
         
-        if result.outcome == crate::roulette::RouletteOutcome::Out {
-            player.eliminate();
+        if result == RouletteResult::Out {
+            self.eliminate(target_id);
+        }
+        self.history.push(GameEvent::Roulette { player_id: target_id, result: result.clone() });
+        if ends_round {
+            self.end_round(target_id);
+            if self.players.iter().filter(|p| p.is_active).count() > 1 {
+                self.deal_round(rng)?;
+            }
         }
+        self.debug_check_invariants();
         
         Ok(result)
     }
@@ -190,6 +368,193 @@ impl Game {
         }
     }
     
+    /// 保存先から読み込み、カードの整合性を検証する（保存されていない場合は`None`）
+    pub fn load_from_storage(storage: &dyn Storage) -> Result<Option<Game>, GameError> {
+        let Some(content) = storage.read().map_err(|e| GameError::IoError(e.to_string()))? else {
+            return Ok(None);
+        };
+        let game: Game = serde_json::from_str(&content)
+            .map_err(|e| GameError::CorruptState(e.to_string()))?;
+        if let Err(e) = game.check_invariants() {
+            // 受け入れ（`Accepted`）を記録する前の保存は、上書きされたプレイのカードが
+            // 状態から失われているため、記録から作り直す（手札が食い違う場合は壊れている）
+            let legacy = !game.history.iter().any(|event| matches!(event, GameEvent::Accepted { .. }));
+            return match game.rebuild_from_history() {
+                Some(rebuilt) if legacy && rebuilt.players.iter().zip(&game.players).all(|(a, b)| a.hand == b.hand) => Ok(Some(rebuilt)),
+                _ => Err(e),
+            };
+        }
+        Ok(Some(game))
+    }
+
+    /// 記録を始める前の状態から出来事を順に反映して作り直す（記録が壊れている場合は`None`）
+    fn rebuild_from_history(&self) -> Option<Game> {
+        let mut game = self.initial_state();
+        for event in &self.history {
+            game.apply_event(event).ok()?;
+        }
+        game.check_invariants().ok()?;
+        Some(game)
+    }
+
+    /// 保存先に書き込む
+    pub fn save_to_storage(&self, storage: &mut dyn Storage) -> Result<(), GameError> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| GameError::IoError(e.to_string()))?;
+        storage.write(&content).map_err(|e| GameError::IoError(e.to_string()))
+    }
+
+    /// カードの整合性を検証する
+    ///
+    /// 手札・直前のプレイ・場・捨て札・山札を合わせると、デッキの構成にちょうど一致する
+    /// （重複も紛失もない）こと
+    pub fn validate(&self) -> Result<(), GameError> {
+        let last_play = self.last_play.iter().flat_map(|play| &play.cards);
+        let mut seen: Vec<&Card> = Vec::new();
+        for card in self.players.iter().flat_map(|p| &p.hand)
+            .chain(last_play)
+            .chain(&self.pile)
+            .chain(&self.discard)
+            .chain(&self.deck.cards)
+        {
+            if seen.contains(&card) {
+                return Err(GameError::CorruptState(format!("duplicate card {}", card)));
+            }
+            seen.push(card);
+        }
+        let expected = self.deck_composition.deck();
+        if let Some(missing) = expected.cards.iter().find(|card| !seen.contains(card)) {
+            return Err(GameError::CorruptState(format!("missing card {}", missing)));
+        }
+        if let Some(unknown) = seen.iter().find(|card| !expected.cards.contains(card)) {
+            return Err(GameError::CorruptState(format!("unknown card {}", unknown)));
+        }
+        Ok(())
+    }
+
+    /// ゲームの不変条件を検証する
+    ///
+    /// - カードの整合性（[`Game::validate`]）
+    /// - プレイヤーIDが重複せず、少なくとも1人は脱落していない
+    /// - 手番のプレイヤーが脱落していない
+    /// - 直前のプレイが実在するプレイヤーのもの
+    /// - ロシアンルーレットを待つ間は直前のプレイがない
+    pub fn check_invariants(&self) -> Result<(), GameError> {
+        self.validate()?;
+        for (index, player) in self.players.iter().enumerate() {
+            if self.players[..index].iter().any(|p| p.id == player.id) {
+                return Err(GameError::CorruptState(format!("duplicate player {}", player.id)));
+            }
+        }
+        if self.players.is_empty() {
+            return Ok(());
+        }
+        if !self.players.iter().any(|p| p.is_active) {
+            return Err(GameError::CorruptState("no active players".to_string()));
+        }
+        match self.find_player(self.current_player) {
+            Ok(player) if player.is_active => {}
+            _ => return Err(GameError::CorruptState(format!("player {} cannot take a turn", self.current_player))),
+        }
+        if let Some(last_play) = &self.last_play {
+            self.find_player(last_play.player_id)
+                .map_err(|_| GameError::CorruptState(format!("unknown player {} in last play", last_play.player_id)))?;
+        }
+        if self.pending_roulette.is_some() && self.last_play.is_some() {
+            return Err(GameError::CorruptState("play during a pending roulette".to_string()));
+        }
+        Ok(())
+    }
+
+    /// デバッグビルドでは操作による変更のたびに不変条件を確認する
+    /// （ファイルから読み込んだ状態・記録は`check_invariants`でエラーとして扱う）
+    fn debug_check_invariants(&self) {
+        if cfg!(debug_assertions) && let Err(e) = self.check_invariants() {
+            panic!("game invariant violated: {}", e);
+        }
+    }
+
+    /// 記録を始める前の状態（同じ席・名前・ロシアンルーレット設定で、手札を配る前）
+    pub fn initial_state(&self) -> Game {
+        let mut game = Game::new(0, 0);
+        game.players = self.players.iter()
+            .map(|p| Player { name: p.name.clone(), ..Player::new(p.id) })
+            .collect();
+        game.current_player = self.players.first().map_or(1, |p| p.id);
+        game.roulette_config = self.roulette_config.clone();
+        game.deck_composition = self.deck_composition;
+        game.rules = self.rules;
+        game.deck = self.deck_composition.deck();
+        game
+    }
+
+    /// 記録された出来事を反映する（リプレイで各時点の状態を再現する）
+    ///
+    /// 記録はファイルから読み込むため信用せず、反映できない出来事や
+    /// 不変条件を満たさない結果は`GameError::CorruptState`として返す
+    pub fn apply_event(&mut self, event: &GameEvent) -> Result<(), GameError> {
+        self.apply_recorded(event).map_err(|e| match e {
+            GameError::CorruptState(_) => e,
+            e => GameError::CorruptState(format!("cannot replay {:?}: {}", event, e)),
+        })?;
+        self.history.push(event.clone());
+        self.check_invariants()
+    }
+
+    fn apply_recorded(&mut self, event: &GameEvent) -> Result<(), GameError> {
+        match event {
+            GameEvent::Dealt { hands } => {
+                self.collect_cards();
+                for (player_id, cards) in hands {
+                    self.deck.cards.retain(|card| !cards.contains(card));
+                    self.find_player_mut(*player_id)?.add_cards(cards.clone());
+                }
+                self.is_started = true;
+            }
+            GameEvent::Played { player_id, cards, declared_type } => {
+                // `Accepted`を記録する前の記録では、カードを出すと直前のプレイを受け入れていた
+                // （チャレンジ後もロシアンルーレットを待たずに出せた）
+                if let Some(last_play) = self.last_play.take() {
+                    self.pile.extend(last_play.cards);
+                }
+                self.pending_roulette = None;
+                let player = self.find_player_mut(*player_id)?;
+                for card in cards {
+                    let position = player.hand.iter()
+                        .position(|c| c == card)
+                        .ok_or(GameError::InvalidCardPosition)?;
+                    player.hand.remove(position);
+                }
+                self.last_play = Some(LastPlay {
+                    player_id: *player_id,
+                    cards: cards.clone(),
+                    declared_type: *declared_type,
+                });
+                self.current_player = *player_id;
+                self.advance_turn();
+            }
+            GameEvent::Accepted { cards, .. } => {
+                self.last_play = None;
+                self.pile.extend(cards.iter().cloned());
+            }
+            GameEvent::Challenged { challenger_id, target_player, revealed, is_liar, .. } => {
+                self.last_play = None;
+                self.discard.extend(revealed.iter().cloned());
+                self.pending_roulette = Some(if *is_liar { *target_player } else { *challenger_id });
+            }
+            GameEvent::Roulette { player_id, result } => {
+                self.find_player(*player_id)?;
+                if *result == RouletteResult::Out {
+                    self.eliminate(*player_id);
+                }
+                if self.pending_roulette == Some(*player_id) {
+                    self.end_round(*player_id);
+                }
+            }
+        }
+        Ok(())
+    }
+    
     fn find_player(&self, player_id: u8) -> Result<&Player, GameError> {
         self.players.iter()
             .find(|p| p.id == player_id)
@@ -202,22 +567,51 @@ impl Game {
             .ok_or(GameError::PlayerNotFound)
     }
     
+    /// 手札・直前のプレイ・場・捨て札のカードをすべて山札に戻す
+    fn collect_cards(&mut self) {
+        if let Some(last_play) = self.last_play.take() {
+            self.deck.cards.extend(last_play.cards);
+        }
+        self.deck.cards.append(&mut self.pile);
+        self.deck.cards.append(&mut self.discard);
+        for player in &mut self.players {
+            self.deck.cards.append(&mut player.hand);
+        }
+    }
+    
+    /// チャレンジに負けたプレイヤーのロシアンルーレットでラウンドを終える
+    /// （次のラウンドはそのプレイヤーから、脱落した場合は次の席から始める）
+    fn end_round(&mut self, loser_id: u8) {
+        self.pending_roulette = None;
+        self.current_player = loser_id;
+        if self.find_player(loser_id).is_ok_and(|p| !p.is_active) {
+            self.advance_turn();
+        }
+    }
+    
+    /// 脱落させる（手番のプレイヤーなら次の席に手番を渡す）
+    fn eliminate(&mut self, player_id: u8) {
+        if let Ok(player) = self.find_player_mut(player_id) {
+            player.eliminate();
+        }
+        if player_id == self.current_player {
+            self.advance_turn();
+        }
+    }
+    
+    /// 席順で次の脱落していないプレイヤーに手番を渡す
     fn advance_turn(&mut self) {
-        let active_players: Vec<u8> = self.players.iter()
-            .filter(|p| p.is_active)
-            .map(|p| p.id)
-            .collect();
+        let count = self.players.len();
+        let current_index = self.players.iter()
+            .position(|p| p.id == self.current_player)
+            .unwrap_or(count.saturating_sub(1));
         
-        if active_players.is_empty() {
-            return;
+        if let Some(next) = (1..=count)
+            .map(|offset| &self.players[(current_index + offset) % count])
+            .find(|p| p.is_active)
+        {
+            self.current_player = next.id;
         }
-        
-        let current_index = active_players.iter()
-            .position(|&id| id == self.current_player)
-            .unwrap_or(0);
-        
-        let next_index = (current_index + 1) % active_players.len();
-        self.current_player = active_players[next_index];
     }
 }
 
@@ -233,10 +627,17 @@ mod tests {
         assert!(!game.is_started);
     }
     
+    #[test]
+    fn test_try_new_player_limits() {
+        assert!(Game::try_new(MIN_PLAYERS - 1, 1).is_err());
+        assert!(Game::try_new(MAX_PLAYERS + 1, 1).is_err());
+        assert!(Game::try_new(MIN_PLAYERS, 1).is_ok());
+    }
+
     #[test]
     fn test_deal_cards() {
         let mut game = Game::new(3, 1);
-        game.deal_cards();
+        game.deal_cards().unwrap();
         
         assert!(game.is_started);
         for player in &game.players {
@@ -248,7 +649,7 @@ mod tests {
     #[test]
     fn test_play_cards() {
         let mut game = Game::new(2, 1);
-        game.deal_cards();
+        game.deal_cards().unwrap();
         
         let result = game.play_cards(1, vec![0, 1], CardType::Ace);
         assert!(result.is_ok());
@@ -261,29 +662,130 @@ mod tests {
     #[test]
     fn test_challenge() {
         let mut game = Game::new(2, 1);
-        game.deal_cards();
+        game.deal_cards().unwrap();
         
-        // Manually set up a scenario
+        // Manually set up a scenario (the cards leave wherever they were dealt)
         let cards = vec![
             Card { card_type: CardType::Queen, id: 1 },
             Card { card_type: CardType::King, id: 2 },
         ];
+        for player in &mut game.players {
+            player.hand.retain(|card| !cards.contains(card));
+        }
+        game.deck.cards.retain(|card| !cards.contains(card));
         game.last_play = Some(LastPlay {
             player_id: 1,
             cards: cards.clone(),
             declared_type: CardType::Ace, // Lying!
         });
+        game.current_player = 2; // Player 1 has just played
         
         let result = game.challenge(2).unwrap();
         assert!(result.is_liar);
         assert_eq!(result.actual_cards, cards);
         assert!(game.last_play.is_none()); // Should be cleared
+        assert_eq!(game.discard, cards); // Revealed cards stay known
+        assert_eq!(game.pending_roulette, Some(1)); // The liar spins
+    }
+    
+    #[test]
+    fn test_accepted_plays_stay_face_down() {
+        let mut game = Game::new(3, 1);
+        game.deal_cards_with(&mut crate::rng::seeded(1)).unwrap();
+        
+        game.play_cards(1, vec![0], CardType::Ace).unwrap();
+        assert!(matches!(game.accept(3), Err(GameError::InvalidCommand))); // Not their turn
+        game.accept(2).unwrap();
+        assert_eq!(game.pile.len(), 1);
+        assert!(matches!(game.accept(2), Err(GameError::NoLastPlay)));
+        
+        // Playing on top of a play accepts it first
+        game.play_cards(2, vec![0, 1], CardType::Ace).unwrap();
+        let latest = game.players[2].hand[..1].to_vec();
+        game.play_cards(3, vec![0], CardType::Ace).unwrap();
+        assert_eq!(game.pile.len(), 3);
+        
+        // Only the most recent play is revealed
+        let result = game.challenge(1).unwrap();
+        assert_eq!(result.actual_cards, latest);
+        assert_eq!(game.pile.len(), 3);
+        let loser = if result.is_liar { 3 } else { 1 };
+        assert_eq!(game.pending_roulette, Some(loser));
+        assert!(matches!(game.play_cards(game.current_player, vec![0], CardType::Ace), Err(GameError::InvalidCommand)));
+        assert!(matches!(game.execute_roulette(2), Err(GameError::InvalidCommand)));
+    }
+    
+    #[test]
+    fn test_round_ends_with_redeal() {
+        let mut game = Game::new(3, 6); // All bullets loaded for guaranteed result
+        let mut rng = crate::rng::seeded(2);
+        game.deal_cards_with(&mut rng).unwrap();
+        game.play_cards(1, vec![0, 1], CardType::Queen).unwrap();
+        game.play_cards(2, vec![0], CardType::Queen).unwrap();
+        let result = game.challenge(3).unwrap();
+        let loser = if result.is_liar { 2 } else { 3 };
+        
+        assert_eq!(game.execute_roulette_with(loser, &mut rng).unwrap(), RouletteResult::Out);
+        
+        // Every card is collected, reshuffled and dealt to the survivors
+        assert_eq!(game.pending_roulette, None);
+        assert!(game.pile.is_empty() && game.discard.is_empty() && game.last_play.is_none());
+        for player in &game.players {
+            assert_eq!(player.hand.len(), if player.is_active { 5 } else { 0 });
+        }
+        assert_eq!(game.deck.remaining(), 16 - 10);
+        assert_eq!(game.current_player, if loser == 2 { 3 } else { 1 });
+        assert!(game.check_invariants().is_ok());
+    }
+    
+    #[test]
+    fn test_challenge_follows_joker_rule() {
+        let joker = Card { card_type: CardType::Joker, id: 1 };
+        for (jokers_wild, expect_liar) in [(true, false), (false, true)] {
+            let mut game = Game::new(2, 1);
+            game.rules = RuleToggles { jokers_wild };
+            game.deck.cards.retain(|card| *card != joker);
+            game.last_play = Some(LastPlay {
+                player_id: 1,
+                cards: vec![joker.clone()],
+                declared_type: CardType::Ace,
+            });
+            game.current_player = 2;
+            
+            assert_eq!(game.challenge(2).unwrap().is_liar, expect_liar);
+        }
+    }
+    
+    #[test]
+    fn test_challenge_requires_turn() {
+        let mut game = Game::new(3, 1);
+        game.deal_cards().unwrap();
+        game.play_cards(1, vec![0], CardType::Ace).unwrap();
+        
+        // Only the next player may challenge, and never their own play
+        assert!(matches!(game.challenge(3), Err(GameError::InvalidCommand)));
+        assert!(matches!(game.challenge(1), Err(GameError::OwnPlay)));
+        assert!(game.last_play.is_some());
+        assert!(game.challenge(2).is_ok());
+    }
+    
+    #[test]
+    fn test_own_play_cannot_be_answered() {
+        let mut game = Game::new(2, 6); // All bullets loaded for guaranteed result
+        game.deal_cards().unwrap();
+        game.play_cards(1, vec![0], CardType::Ace).unwrap();
+        
+        // Player 2 is eliminated outside a challenge, so the turn returns to player 1
+        game.execute_roulette(2).unwrap();
+        assert_eq!(game.current_player, 1);
+        assert!(matches!(game.challenge(1), Err(GameError::OwnPlay)));
+        assert!(matches!(game.accept(1), Err(GameError::OwnPlay)));
     }
     
     #[test]
     fn test_roulette() {
         let mut game = Game::new(2, 6); // All bullets loaded for guaranteed result
-        game.deal_cards();
+        game.deal_cards().unwrap();
         
         let result = game.execute_roulette(1);
         assert!(result.is_ok());
@@ -296,7 +798,7 @@ mod tests {
     #[test]
     fn test_winner_detection() {
         let mut game = Game::new(2, 1);
-        game.deal_cards();
+        game.deal_cards().unwrap();
         
         // No winner initially
         assert!(game.get_winner().is_none());
@@ -308,6 +810,115 @@ mod tests {
         assert_eq!(game.get_winner(), Some(2));
     }
     
+    #[test]
+    fn test_history_replays_to_same_state() {
+        let mut game = Game::new(3, 6);
+        game.deal_cards_with(&mut crate::rng::seeded(3)).unwrap();
+        game.play_cards(1, vec![0, 1], CardType::King).unwrap();
+        let result = game.challenge(2).unwrap();
+        let loser = if result.is_liar { 1 } else { 2 };
+        game.execute_roulette(loser).unwrap();
+        // 負けたプレイヤーのロシアンルーレットでラウンドが終わり、配り直される
+        assert_eq!(game.history.len(), 5);
+        assert!(matches!(game.history.last(), Some(GameEvent::Dealt { .. })));
+
+        let mut replayed = game.initial_state();
+        assert!(replayed.players.iter().all(|p| p.hand.is_empty()));
+        for event in &game.history {
+            replayed.apply_event(event).unwrap();
+        }
+        for (original, replay) in game.players.iter().zip(&replayed.players) {
+            assert_eq!(original.hand, replay.hand);
+            assert_eq!(original.is_active, replay.is_active);
+        }
+        assert_eq!(replayed.current_player, game.current_player);
+        assert_eq!(replayed.deck.remaining(), game.deck.remaining());
+        assert!(replayed.discard.is_empty());
+    }
+    
+    #[test]
+    fn test_history_without_accepted_events() {
+        let mut game = Game::new(3, 1);
+        game.deal_cards_with(&mut crate::rng::seeded(4)).unwrap();
+        game.play_cards(1, vec![0, 1], CardType::King).unwrap();
+        game.play_cards(2, vec![0], CardType::King).unwrap();
+        game.play_cards(3, vec![0], CardType::King).unwrap();
+        
+        // 受け入れを記録する前の形式（出したカードは上書きされ、場に積まれていない）
+        let mut old = game.clone();
+        old.history.retain(|event| !matches!(event, GameEvent::Accepted { .. }));
+        old.pile.clear();
+        assert!(old.check_invariants().is_err());
+        
+        let replay = crate::replay::Replay::new(&old);
+        let last = replay.state_at(replay.len()).unwrap();
+        assert_eq!(last.pile.len(), 3);
+        assert_eq!(last.players[0].hand, game.players[0].hand);
+        assert!(last.check_invariants().is_ok());
+        
+        // 古い自動保存も記録から作り直して再開できる
+        let mut storage = crate::storage::MemoryStorage::default();
+        old.save_to_storage(&mut storage).unwrap();
+        let loaded = Game::load_from_storage(&storage).unwrap().unwrap();
+        assert_eq!(loaded.pile.len(), 3);
+        assert_eq!(loaded.current_player, game.current_player);
+        assert_eq!(loaded.last_play.unwrap().cards, game.last_play.unwrap().cards);
+    }
+    
+    #[test]
+    fn test_corrupt_history_is_an_error() {
+        let mut game = Game::new(2, 1);
+        game.deal_cards_with(&mut crate::rng::seeded(6)).unwrap();
+        game.play_cards(1, vec![0], CardType::Ace).unwrap();
+        
+        // 手で編集され、同じカードが2人に配られた記録
+        let mut edited = game.clone();
+        if let GameEvent::Dealt { hands } = &mut edited.history[0] {
+            let card = hands[0].1[0].clone();
+            hands[1].1[0] = card;
+        }
+        let replay = crate::replay::Replay::new(&edited);
+        assert!(matches!(replay.state_at(1), Err(GameError::CorruptState(_))));
+        
+        // 持っていないカードを出した記録
+        let mut edited = game.clone();
+        if let GameEvent::Played { cards, .. } = &mut edited.history[1] {
+            cards[0] = game.players[1].hand[0].clone();
+        }
+        let replay = crate::replay::Replay::new(&edited);
+        assert!(replay.state_at(1).is_ok());
+        assert!(matches!(replay.state_at(2), Err(GameError::CorruptState(_))));
+    }
+    
+    #[test]
+    fn test_save_and_validate() {
+        let mut storage = crate::storage::MemoryStorage::default();
+        assert!(Game::load_from_storage(&storage).unwrap().is_none());
+
+        let mut game = Game::new(3, 1);
+        game.deal_cards().unwrap();
+        game.play_cards(1, vec![0, 1], CardType::Ace).unwrap();
+        game.challenge(2).unwrap();
+        game.save_to_storage(&mut storage).unwrap();
+        let loaded = Game::load_from_storage(&storage).unwrap().unwrap();
+        assert_eq!(loaded.players[0].hand, game.players[0].hand);
+        assert_eq!(loaded.history, game.history);
+
+        // 複製されたカードや失われたカードは検出する
+        let mut duplicated = game.clone();
+        let card = duplicated.players[1].hand[0].clone();
+        duplicated.players[2].hand.push(card);
+        assert!(matches!(duplicated.validate(), Err(GameError::CorruptState(_))));
+        let mut lost = game.clone();
+        lost.deck.cards.clear();
+        assert!(matches!(lost.validate(), Err(GameError::CorruptState(_))));
+
+        let mut corrupt = crate::storage::MemoryStorage::new(Some("{".to_string()));
+        assert!(Game::load_from_storage(&corrupt).is_err());
+        game.save_to_storage(&mut corrupt).unwrap();
+        assert!(Game::load_from_storage(&corrupt).is_ok());
+    }
+    
     #[test]
     fn test_advance_turn() {
         let mut game = Game::new(3, 1);
@@ -322,4 +933,153 @@ mod tests {
         game.advance_turn();
         assert_eq!(game.current_player, 1); // Wrap around
     }
+    
+    #[test]
+    fn test_deal_splits_deck_evenly() {
+        let mut game = Game::new(4, 1);
+        game.deal_cards().unwrap();
+        
+        // 16枚を4人に配ると4枚ずつ（最後の1人だけ少なくならない）
+        for player in &game.players {
+            assert_eq!(player.hand.len(), 4);
+        }
+        assert_eq!(game.deck.remaining(), 0);
+    }
+    
+    #[test]
+    fn test_deck_smaller_than_table_is_rejected() {
+        let mut game = Game::new(4, 1);
+        let small = DeckComposition { aces: 1, queens: 1, kings: 1, jokers: 0 };
+        assert!(matches!(game.set_deck_composition(small), Err(GameError::NotEnoughCards)));
+        
+        // 検証を経ずに小さいデッキになっても、0枚ずつ配って勝者を決めたりしない
+        game.deck = small.deck();
+        assert!(matches!(game.deal_cards(), Err(GameError::NotEnoughCards)));
+        assert!(!game.is_started);
+        assert_eq!(game.get_winner(), None);
+    }
+    
+    #[test]
+    fn test_invalid_plays_keep_cards() {
+        let mut game = Game::new(2, 1);
+        game.deal_cards().unwrap();
+        
+        assert!(matches!(game.play_cards(1, vec![0, 0], CardType::Ace), Err(GameError::InvalidCardPosition)));
+        assert!(matches!(game.play_cards(1, vec![], CardType::Ace), Err(GameError::InvalidCardPosition)));
+        assert_eq!(game.players[0].hand.len(), 5);
+        assert!(game.check_invariants().is_ok());
+    }
+    
+    #[test]
+    fn test_eliminated_player_loses_turn() {
+        let mut game = Game::new(3, 6);
+        game.deal_cards().unwrap();
+        
+        game.execute_roulette(1).unwrap();
+        assert_eq!(game.current_player, 2);
+        game.execute_roulette(2).unwrap();
+        assert_eq!(game.current_player, 3);
+        
+        // 最後の1人は勝者なので撃たない
+        assert!(matches!(game.execute_roulette(3), Err(GameError::InvalidCommand)));
+        assert!(game.check_invariants().is_ok());
+    }
+    
+    #[test]
+    fn test_check_invariants() {
+        let mut game = Game::new(3, 1);
+        game.deal_cards().unwrap();
+        assert!(game.check_invariants().is_ok());
+        
+        let mut eliminated_turn = game.clone();
+        eliminated_turn.players[0].eliminate();
+        assert!(matches!(eliminated_turn.check_invariants(), Err(GameError::CorruptState(_))));
+        
+        let mut duplicate_seat = game.clone();
+        duplicate_seat.players[2].id = 2;
+        assert!(matches!(duplicate_seat.check_invariants(), Err(GameError::CorruptState(_))));
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// ランダムに試す操作（席は人数で割った余りで選ぶ）
+    #[derive(Debug, Clone)]
+    enum Action {
+        /// 手番のプレイヤーがカードを出す
+        Play { positions: Vec<usize>, declared: usize },
+        Accept { seat: usize },
+        Challenge { seat: usize },
+        Roulette { seat: usize },
+    }
+
+    fn action() -> impl Strategy<Value = Action> {
+        prop_oneof![
+            (prop::collection::vec(0..HAND_SIZE + 1, 0..4), 0..CardType::DECLARABLE.len())
+                .prop_map(|(positions, declared)| Action::Play { positions, declared }),
+            (0..MAX_PLAYERS as usize).prop_map(|seat| Action::Accept { seat }),
+            (0..MAX_PLAYERS as usize).prop_map(|seat| Action::Challenge { seat }),
+            (0..MAX_PLAYERS as usize).prop_map(|seat| Action::Roulette { seat }),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn random_actions_keep_invariants(
+            player_count in MIN_PLAYERS..=MAX_PLAYERS,
+            bullet_count in 1u8..=3,
+            seed: u64,
+            actions in prop::collection::vec(action(), 0..40),
+        ) {
+            let mut rng = crate::rng::seeded(seed);
+            let mut game = Game::new(player_count, bullet_count);
+            game.deal_cards_with(&mut rng).unwrap();
+            prop_assert!(game.check_invariants().is_ok());
+
+            for action in actions {
+                let before = serde_json::to_string(&game).unwrap();
+                let seat_id = |seat: usize| game.players[seat % game.players.len()].id;
+                let accepted = match action {
+                    Action::Play { positions, declared } => game
+                        .play_cards(game.current_player, positions, CardType::DECLARABLE[declared])
+                        .is_ok(),
+                    Action::Accept { seat } => {
+                        let id = seat_id(seat);
+                        game.accept(id).is_ok()
+                    }
+                    Action::Challenge { seat } => {
+                        let id = seat_id(seat);
+                        game.challenge(id).is_ok()
+                    }
+                    Action::Roulette { seat } => {
+                        let id = seat_id(seat);
+                        game.execute_roulette_with(id, &mut rng).is_ok()
+                    }
+                };
+
+                prop_assert!(game.check_invariants().is_ok());
+                // 拒否された操作は状態を変えない
+                if !accepted {
+                    prop_assert_eq!(serde_json::to_string(&game).unwrap(), before);
+                }
+            }
+
+            // 記録から同じ状態を再現できる
+            let mut replayed = game.initial_state();
+            for event in &game.history {
+                prop_assert!(replayed.apply_event(event).is_ok());
+            }
+            for (original, replay) in game.players.iter().zip(&replayed.players) {
+                prop_assert_eq!(&original.hand, &replay.hand);
+                prop_assert_eq!(original.is_active, replay.is_active);
+            }
+            prop_assert_eq!(replayed.current_player, game.current_player);
+            prop_assert_eq!(&replayed.discard, &game.discard);
+            prop_assert_eq!(&replayed.pile, &game.pile);
+            prop_assert_eq!(replayed.pending_roulette, game.pending_roulette);
+        }
+    }
 }
@@ -4,7 +4,15 @@ use std::fs;
 use std::path::Path;
 use thiserror::Error;
 
+/// バイナリに同梱される言語ファイル（WebAssemblyなどファイルを読めない環境用）
+const BUNDLED_LANGUAGES: [(&str, &str); 2] = [
+    ("ja", include_str!("../languages/ja.toml")),
+    ("en", include_str!("../languages/en.toml")),
+];
+
+/// 言語ファイルの読み込み・メッセージ取得のエラー
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum I18nError {
     #[error("Language file not found: {0}")]
     LanguageNotFound(String),
@@ -30,20 +38,26 @@ pub struct LanguageData {
     pub messages: HashMap<String, String>,
 }
 
+/// 言語ごとのメッセージ（`{name}`などの引数を置き換えて取得する）
 #[derive(Debug, Clone)]
 pub struct I18nManager {
     current_language: String,
     messages: HashMap<String, String>,
     available_languages: Vec<LanguageInfo>,
+    bundled: bool, // `languages/`ではなく同梱の言語ファイルを使うか
 }
 
 impl I18nManager {
     /// 新しいI18nManagerを作成し、利用可能な言語を読み込む
+    ///
+    /// 言語ファイルは現在のディレクトリの`languages/`から読み込む（`liars_table`バイナリ用）。
+    /// ライブラリとして使う場合は、作業ディレクトリに依存しない[`I18nManager::bundled`]を使う
     pub fn new() -> Result<Self, I18nError> {
         let mut manager = I18nManager {
             current_language: "ja".to_string(), // デフォルトは日本語
             messages: HashMap::new(),
             available_languages: Vec::new(),
+            bundled: false,
         };
 
         // 利用可能な言語を読み込む
@@ -55,6 +69,24 @@ impl I18nManager {
         Ok(manager)
     }
 
+    /// 同梱の言語ファイルを使うI18nManagerを作成（ファイルシステムを使わない）
+    pub fn bundled() -> Result<Self, I18nError> {
+        let mut manager = I18nManager {
+            current_language: "ja".to_string(),
+            messages: HashMap::new(),
+            available_languages: Vec::new(),
+            bundled: true,
+        };
+
+        for (code, _) in BUNDLED_LANGUAGES {
+            let lang_data = manager.load_language_file(code)?;
+            manager.available_languages.push(lang_data.language);
+        }
+        manager.load_language("ja")?;
+
+        Ok(manager)
+    }
+
     /// 利用可能な言語一覧を取得
     fn load_available_languages(&mut self) -> Result<(), I18nError> {
         let languages_dir = Path::new("languages");
@@ -69,11 +101,11 @@ impl I18nManager {
             let entry = entry?;
             let path = entry.path();
 
-            if path.extension().and_then(|s| s.to_str()) == Some("toml") {
-                if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
-                    let lang_data = self.load_language_file(stem)?;
-                    self.available_languages.push(lang_data.language);
-                }
+            if path.extension().and_then(|s| s.to_str()) == Some("toml")
+                && let Some(stem) = path.file_stem().and_then(|s| s.to_str())
+            {
+                let lang_data = self.load_language_file(stem)?;
+                self.available_languages.push(lang_data.language);
             }
         }
 
@@ -82,6 +114,15 @@ impl I18nManager {
 
     /// 指定された言語ファイルを読み込む（内部用）
     fn load_language_file(&self, language_code: &str) -> Result<LanguageData, I18nError> {
+        if self.bundled {
+            let (_, content) = BUNDLED_LANGUAGES
+                .iter()
+                .find(|(code, _)| *code == language_code)
+                .ok_or_else(|| I18nError::LanguageNotFound(language_code.to_string()))?;
+            return toml::from_str(content)
+                .map_err(|e| I18nError::ParseError(format!("{}: {}", language_code, e)));
+        }
+
         let file_path = format!("languages/{}.toml", language_code);
 
         if !Path::new(&file_path).exists() {
@@ -128,8 +169,22 @@ impl I18nManager {
         Ok(message)
     }
 
+    /// プレースホルダーを`(名前, 値)`の組で置換してメッセージを取得する
+    pub fn get_message_with<V: AsRef<str>>(
+        &self,
+        key: &str,
+        args: &[(&str, V)],
+    ) -> Result<String, I18nError> {
+        let mut message = self.get_message(key)?;
+
+        for (placeholder, value) in args {
+            message = message.replace(&format!("{{{}}}", placeholder), value.as_ref());
+        }
+
+        Ok(message)
+    }
+
     /// 現在の言語コードを取得
-    #[allow(dead_code)] // 将来のバージョンで使用予定
     pub fn current_language(&self) -> &str {
         &self.current_language
     }
@@ -160,12 +215,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bundled_languages() {
+        let mut manager = I18nManager::bundled().unwrap();
+        assert_eq!(manager.available_languages().len(), BUNDLED_LANGUAGES.len());
+        manager.load_language("en").unwrap();
+        assert_eq!(manager.current_language(), "en");
+        assert!(manager.get_message("game_reset").is_ok());
+        assert!(manager.load_language("xx").is_err());
+    }
+
     #[test]
     fn test_message_placeholder_replacement() {
         let mut manager = I18nManager {
             current_language: "test".to_string(),
             messages: HashMap::new(),
             available_languages: Vec::new(),
+            bundled: false,
         };
 
         manager.messages.insert(
@@ -179,5 +245,8 @@ mod tests {
 
         let result = manager.get_message_with_args("test_message", &args);
         assert_eq!(result.unwrap(), "Hello Alice, you have 5 items");
+
+        let result = manager.get_message_with("test_message", &[("name", "Bob"), ("count", "2")]);
+        assert_eq!(result.unwrap(), "Hello Bob, you have 2 items");
     }
 }
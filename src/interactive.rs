@@ -1,17 +1,38 @@
-use crate::config::{ConfigError, GameConfig};
-use crate::i18n::{I18nError, I18nManager};
-use crate::roulette::RouletteResult;
+use crate::animation::{self, SpinLabels};
+use crate::journal::{self, ActionKind, Journal, JournalError, SaveOutcome};
+use crate::round::{Challenge, ChallengeOutcome, DeclaredPlay};
+use crate::sound::{SoundEffect, SoundPlayer};
+use crate::spectator;
+use crate::tournament::{Bracket, Tournament, TournamentError, TournamentGoal};
+use dialoguer::console::{Color, style};
 use dialoguer::{Confirm, Input, Select, theme::ColorfulTheme};
+use liars_table::{
+    CardType, ConfigError, Elimination, EliminationCause, GameConfig, HAND_SIZE, I18nError,
+    I18nManager, MAX_ANIMATION_MS, MAX_PLAYERS, MIN_PLAYERS, PROFILE_COLORS, Player, Preset,
+    Profile, ProfileError, ProfileStore, RouletteResult, SpinOutcome, TableError, active_table,
+    create_table, has_explicit_config, is_table_pinned, list_tables, spin_chambers, switch_table,
+    table_exists, validate_table_name,
+};
 use std::collections::HashMap;
 use std::io;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum InteractiveError {
     #[error("I18n error: {0}")]
     I18nError(#[from] I18nError),
     #[error("Config error: {0}")]
     ConfigError(#[from] ConfigError),
+    #[error("Profile error: {0}")]
+    ProfileError(#[from] ProfileError),
+    #[error("Journal error: {0}")]
+    JournalError(#[from] JournalError),
+    #[error("Table error: {0}")]
+    TableError(#[from] TableError),
+    #[error("Tournament error: {0}")]
+    TournamentError(#[from] TournamentError),
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
     #[error("Dialog error: {0}")]
@@ -21,6 +42,12 @@ pub enum InteractiveError {
 pub struct InteractiveUI {
     i18n: I18nManager,
     config: GameConfig,
+    profiles: ProfileStore,
+    journal: Journal,
+    saved: GameConfig,              // 最後に読み書きした時点の設定
+    unsaved: Vec<String>,           // 保存していない操作の説明
+    tournament: Option<Tournament>, // 開催中のトーナメント
+    sound: SoundPlayer,             // 効果音
     theme: ColorfulTheme,
 }
 
@@ -28,12 +55,16 @@ pub struct InteractiveUI {
 #[derive(Debug)]
 enum MainMenuChoice {
     ExecuteRoulette,
+    ChallengeAssistant,
     PlayerSettings,
     ParticipantCountSettings,
     PlayerRecovery,
     PlayerRevive,
+    UndoRedo,
     LanguageSettings,
     RouletteSettings,
+    Presets,
+    Tournament,
     Exit,
 }
 
@@ -42,15 +73,26 @@ impl InteractiveUI {
     pub fn new() -> Result<Self, InteractiveError> {
         let mut i18n = I18nManager::new()?;
         let config = GameConfig::load()?;
+        let profiles = ProfileStore::load()?;
+        let journal = Journal::load()?;
+        let tournament = Tournament::load()?;
+        let sound = SoundPlayer::new(&config.sound);
 
         // 設定された言語に切り替え
         if i18n.is_language_available(&config.language) {
             i18n.load_language(&config.language)?;
         }
+        spectator::snapshot(&config);
 
         Ok(InteractiveUI {
             i18n,
+            saved: config.clone(),
             config,
+            profiles,
+            journal,
+            unsaved: Vec::new(),
+            tournament,
+            sound,
             theme: ColorfulTheme::default(),
         })
     }
@@ -60,6 +102,11 @@ impl InteractiveUI {
         // ウェルカムメッセージ
         self.show_welcome()?;
 
+        // 使用する卓の選択（--table や --config で指定済みの場合は省略）
+        if !is_table_pinned() && !has_explicit_config() {
+            self.select_table()?;
+        }
+
         // 初期言語選択（必要に応じて）
         if self.should_select_language()? {
             self.select_language()?;
@@ -76,11 +123,16 @@ impl InteractiveUI {
             if let Some(winner) = self.config.check_winner() {
                 self.show_winner(winner)?;
                 self.config.mark_game_completed();
-                self.config.save()?;
+                // 保存できなかった場合は勝者が決まる前の設定に戻っている
+                if !self.save_config()? {
+                    continue;
+                }
+                self.record_tournament_game()?;
 
                 // 新しいゲームを開始するか確認
                 if self.ask_new_game()? {
-                    self.config.reset_game();
+                    self.handle_game_reset()?;
+                    self.save_config()?;
                     continue; // ゲームを続行
                 } else {
                     break; // ゲーム終了
@@ -92,6 +144,9 @@ impl InteractiveUI {
                 MainMenuChoice::ExecuteRoulette => {
                     self.execute_roulette()?;
                 }
+                MainMenuChoice::ChallengeAssistant => {
+                    self.challenge_assistant()?;
+                }
                 MainMenuChoice::PlayerSettings => {
                     self.player_settings_menu()?;
                 }
@@ -104,24 +159,78 @@ impl InteractiveUI {
                 MainMenuChoice::PlayerRevive => {
                     self.player_revive_menu()?;
                 }
+                MainMenuChoice::UndoRedo => {
+                    self.undo_redo_menu()?;
+                }
                 MainMenuChoice::LanguageSettings => {
                     self.select_language()?;
                 }
                 MainMenuChoice::RouletteSettings => {
                     self.roulette_settings_menu()?;
                 }
+                MainMenuChoice::Presets => {
+                    self.preset_menu()?;
+                }
+                MainMenuChoice::Tournament => {
+                    self.tournament_menu()?;
+                }
                 MainMenuChoice::Exit => {
                     break;
                 }
             }
 
             // 設定を保存
-            self.config.save()?;
+            self.save_config()?;
         }
 
         Ok(())
     }
 
+    /// 設定と取り消し履歴を保存し、変更を保存できたかを返す
+    ///
+    /// 別のプロセスが先に設定を書き換えていた場合は、最新の設定に変更を反映し直す。
+    /// 反映できなかった場合は保存されなかった操作を表示し、やり直しを促す
+    fn save_config(&mut self) -> Result<bool, InteractiveError> {
+        let outcome = journal::save_changes(
+            &mut self.config,
+            &self.saved,
+            GameConfig::save,
+            GameConfig::load,
+        )?;
+        self.saved = self.config.clone();
+        let unsaved = std::mem::take(&mut self.unsaved);
+        if outcome != SaveOutcome::Saved {
+            // 観戦画面も保存された状態に合わせる
+            spectator::snapshot(&self.config);
+        }
+        match outcome {
+            SaveOutcome::Saved => {}
+            SaveOutcome::Rebased => {
+                println!(
+                    "{}",
+                    style(self.i18n.get_message("error_config_stale")?).yellow()
+                );
+            }
+            SaveOutcome::Lost => {
+                let mut args = HashMap::new();
+                args.insert("actions".to_string(), unsaved.join(" / "));
+                println!(
+                    "{}",
+                    style(
+                        self.i18n
+                            .get_message_with_args("error_config_stale_lost", &args)?
+                    )
+                    .yellow()
+                );
+                // 保存されなかった操作は取り消し履歴にも残さない
+                self.journal = Journal::load()?;
+                return Ok(false);
+            }
+        }
+        self.journal.save()?;
+        Ok(true)
+    }
+
     /// ウェルカムメッセージを表示
     fn show_welcome(&self) -> Result<(), InteractiveError> {
         println!();
@@ -172,6 +281,65 @@ impl InteractiveUI {
         Ok(())
     }
 
+    /// 使用する卓を選択する（新しい卓の作成も可能）
+    fn select_table(&mut self) -> Result<(), InteractiveError> {
+        let tables = list_tables()?;
+        let active = active_table();
+        let mut items = tables.clone();
+        items.push(self.i18n.get_message("table_new")?);
+
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("select_table")?)
+            .items(&items)
+            .default(tables.iter().position(|name| *name == active).unwrap_or(0))
+            .interact()
+            .map_err(|e| InteractiveError::DialogError(format!("Table selection failed: {}", e)))?;
+
+        let name = if selection == tables.len() {
+            let name: String = Input::with_theme(&self.theme)
+                .with_prompt(self.i18n.get_message("input_table_name")?)
+                .validate_with(|input: &String| -> Result<(), String> {
+                    let name = input.trim();
+                    validate_table_name(name).map_err(|e| e.to_string())?;
+                    if table_exists(name) {
+                        Err(TableError::AlreadyExists(name.to_string()).to_string())
+                    } else {
+                        Ok(())
+                    }
+                })
+                .interact()
+                .map_err(|e| {
+                    InteractiveError::DialogError(format!("Table name input failed: {}", e))
+                })?;
+            let name = name.trim().to_string();
+            create_table(&name)?;
+            name
+        } else {
+            tables[selection].clone()
+        };
+
+        if name != active {
+            // 選択した卓の設定と取り消し履歴を読み込み直す
+            switch_table(&name)?;
+            self.config = GameConfig::load()?;
+            self.saved = self.config.clone();
+            self.unsaved.clear();
+            self.journal = Journal::load()?;
+            self.tournament = Tournament::load()?;
+            if self.i18n.is_language_available(&self.config.language) {
+                self.i18n.load_language(&self.config.language)?;
+            }
+        }
+
+        let mut args = HashMap::new();
+        args.insert("name".to_string(), name);
+        println!(
+            "{}",
+            style(self.i18n.get_message_with_args("table_selected", &args)?).cyan()
+        );
+        Ok(())
+    }
+
     /// メインメニューを表示
     fn show_main_menu(&self) -> Result<MainMenuChoice, InteractiveError> {
         println!("\n{}", self.i18n.get_message("main_menu")?);
@@ -225,12 +393,16 @@ impl InteractiveUI {
         // メニュー選択肢
         let menu_items = vec![
             self.i18n.get_message("menu_roulette")?,
+            self.i18n.get_message("menu_challenge_assistant")?,
             self.i18n.get_message("menu_player_settings")?,
             self.i18n.get_message("menu_participant_count")?,
             self.i18n.get_message("menu_player_recovery")?,
             self.i18n.get_message("menu_player_revive")?,
+            self.i18n.get_message("menu_undo_redo")?,
             self.i18n.get_message("menu_language")?,
             self.i18n.get_message("menu_roulette_settings")?,
+            self.i18n.get_message("menu_presets")?,
+            self.i18n.get_message("menu_tournament")?,
             self.i18n.get_message("menu_exit")?,
         ];
 
@@ -243,24 +415,136 @@ impl InteractiveUI {
 
         match selection {
             0 => Ok(MainMenuChoice::ExecuteRoulette),
-            1 => Ok(MainMenuChoice::PlayerSettings),
-            2 => Ok(MainMenuChoice::ParticipantCountSettings),
-            3 => Ok(MainMenuChoice::PlayerRecovery),
-            4 => Ok(MainMenuChoice::PlayerRevive),
-            5 => Ok(MainMenuChoice::LanguageSettings),
-            6 => Ok(MainMenuChoice::RouletteSettings),
-            7 => Ok(MainMenuChoice::Exit),
+            1 => Ok(MainMenuChoice::ChallengeAssistant),
+            2 => Ok(MainMenuChoice::PlayerSettings),
+            3 => Ok(MainMenuChoice::ParticipantCountSettings),
+            4 => Ok(MainMenuChoice::PlayerRecovery),
+            5 => Ok(MainMenuChoice::PlayerRevive),
+            6 => Ok(MainMenuChoice::UndoRedo),
+            7 => Ok(MainMenuChoice::LanguageSettings),
+            8 => Ok(MainMenuChoice::RouletteSettings),
+            9 => Ok(MainMenuChoice::Presets),
+            10 => Ok(MainMenuChoice::Tournament),
+            11 => Ok(MainMenuChoice::Exit),
             _ => unreachable!(),
         }
     }
 
-    /// ロシアンルーレット実行
+    /// ロシアンルーレット実行（現在のターンのプレイヤーの行動からラウンドを進める）
     fn execute_roulette(&mut self) -> Result<(), InteractiveError> {
         println!("\n{}", self.i18n.get_message("roulette_execution")?);
 
+        let (accuser_id, accuser_name) = match self.config.current_player() {
+            Some(player) => (player.id, player.name.clone()),
+            None => {
+                return Err(InteractiveError::DialogError(
+                    "No current turn player found".to_string(),
+                ));
+            }
+        };
+
+        // 現在のターンのプレイヤーが直前のプレイを信じるか疑うか
+        let mut args = HashMap::new();
+        args.insert("name".to_string(), accuser_name.clone());
+        let actions = vec![
+            self.i18n.get_message("round_action_accept")?,
+            self.i18n.get_message("round_action_challenge")?,
+        ];
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt(
+                self.i18n
+                    .get_message_with_args("round_action_prompt", &args)?,
+            )
+            .items(&actions)
+            .default(1)
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Action selection failed: {}", e))
+            })?;
+
+        if selection == 0 {
+            // Accept: 次のプレイヤーへ
+            let before = self.config.clone();
+            self.config.next_turn();
+            self.announce_next_turn()?;
+            self.record_turn_change(before)?;
+            return Ok(());
+        }
+
+        // 疑う相手（既定は直前にカードを出した前の席のプレイヤー）
+        let candidates: Vec<(u8, String)> = self
+            .config
+            .active_players()
+            .iter()
+            .filter(|p| p.id != accuser_id)
+            .map(|p| (p.id, p.name.clone()))
+            .collect();
+        if candidates.is_empty() {
+            return Err(InteractiveError::DialogError(
+                "No players to challenge".to_string(),
+            ));
+        }
+        let previous = self.config.previous_active_before(accuser_id);
+        let default_index = candidates
+            .iter()
+            .position(|(id, _)| Some(*id) == previous)
+            .unwrap_or(0);
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("select_accused")?)
+            .items(
+                &candidates
+                    .iter()
+                    .map(|(id, name)| format!("{}: {}", id, name))
+                    .collect::<Vec<_>>(),
+            )
+            .default(default_index)
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Accused selection failed: {}", e))
+            })?;
+        let (accused_id, accused_name) = candidates[selection].clone();
+
+        args.clear();
+        args.insert("accuser".to_string(), accuser_name);
+        args.insert("accused".to_string(), accused_name);
+        println!(
+            "\n{}",
+            self.i18n
+                .get_message_with_args("challenge_declared", &args)?
+        );
+
+        // 公開されたカードの判定
+        let outcomes = vec![
+            self.i18n.get_message("outcome_liar")?,
+            self.i18n.get_message("outcome_honest")?,
+        ];
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("challenge_outcome_prompt")?)
+            .items(&outcomes)
+            .default(0)
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Outcome selection failed: {}", e))
+            })?;
+        let outcome = if selection == 0 {
+            ChallengeOutcome::Liar
+        } else {
+            ChallengeOutcome::Honest
+        };
+
+        let challenge = Challenge::new(accuser_id, accused_id);
+        self.run_roulette_round(challenge.loser(outcome))
+    }
+
+    /// 推奨対象を初期選択にしてロシアンルーレットを実行し、ターンを進める
+    fn run_roulette_round(&mut self, suggested_target: u8) -> Result<(), InteractiveError> {
         // アクティブなプレイヤーを取得
-        let active_players: Vec<&crate::config::Player> =
-            self.config.players.iter().filter(|p| p.is_active).collect();
+        let active_players: Vec<(u8, String)> = self
+            .config
+            .active_players()
+            .iter()
+            .map(|p| (p.id, p.name.clone()))
+            .collect();
 
         if active_players.is_empty() {
             return Err(InteractiveError::DialogError(
@@ -268,26 +552,48 @@ impl InteractiveUI {
             ));
         }
 
-        // プレイヤー選択
+        // プレイヤー選択（判定結果に基づく推奨対象を初期選択）
         let player_names: Vec<String> = active_players
             .iter()
-            .map(|p| format!("{}: {}", p.id, p.name))
+            .map(|(id, name)| format!("{}: {}", id, name))
             .collect();
+        let default_index = active_players
+            .iter()
+            .position(|(id, _)| *id == suggested_target)
+            .unwrap_or(0);
 
         let selection = Select::with_theme(&self.theme)
             .with_prompt(self.i18n.get_message("select_target_player")?)
             .items(&player_names)
-            .default(0)
+            .default(default_index)
             .interact()
             .map_err(|e| {
                 InteractiveError::DialogError(format!("Player selection failed: {}", e))
             })?;
 
-        let target_player = active_players[selection];
+        let (target_id, target_name) = active_players[selection].clone();
+
+        self.show_roulette_target(&target_name)?;
+
+        // 実行確認
+        let confirmed = Confirm::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("confirm_execution")?)
+            .default(false)
+            .interact()
+            .map_err(|e| InteractiveError::DialogError(format!("Confirmation failed: {}", e)))?;
+
+        if !confirmed {
+            // 実行しない場合はそのまま戻る
+            return Ok(());
+        }
+
+        self.spin_roulette_for(target_id, target_name)
+    }
 
-        // 対象プレイヤー表示
+    /// ロシアンルーレットの対象と確率を表示
+    fn show_roulette_target(&self, target_name: &str) -> Result<(), InteractiveError> {
         let mut args = HashMap::new();
-        args.insert("name".to_string(), target_player.name.clone());
+        args.insert("name".to_string(), target_name.to_string());
         println!(
             "\n{}",
             self.i18n.get_message_with_args("target_player", &args)?
@@ -298,6 +604,7 @@ impl InteractiveUI {
             "capacity".to_string(),
             self.config.bullet_capacity.to_string(),
         );
+        args.insert("live".to_string(), self.config.live_rounds.to_string());
         args.insert(
             "percentage".to_string(),
             format!("{:.0}", self.config.roulette_probability_percentage()),
@@ -307,226 +614,1382 @@ impl InteractiveUI {
             self.i18n
                 .get_message_with_args("roulette_probability", &args)?
         );
+        Ok(())
+    }
 
-        // 実行確認
-        let confirmed = Confirm::with_theme(&self.theme)
-            .with_prompt(self.i18n.get_message("confirm_execution")?)
-            .default(false)
-            .interact()
-            .map_err(|e| InteractiveError::DialogError(format!("Confirmation failed: {}", e)))?;
-
-        if !confirmed {
-            // 実行しない場合はそのまま戻る
-            return Ok(());
-        }
-
+    /// 対象プレイヤーのロシアンルーレットを実行し、結果を反映してターンを進める
+    fn spin_roulette_for(
+        &mut self,
+        target_id: u8,
+        target_name: String,
+    ) -> Result<(), InteractiveError> {
         // ロシアンルーレット実行
-        println!("\n{}", self.i18n.get_message("roulette_spinning")?);
-        std::thread::sleep(std::time::Duration::from_millis(1500)); // 演出
-
-        let result = crate::roulette::execute_roulette(self.config.bullet_capacity);
+        let outcome = spin_chambers(&self.config.roulette_config());
+        spectator::spin_started(&self.config, target_id);
+        self.sound.play(SoundEffect::Spin);
+        self.play_spin_animation(&outcome)?;
+        let result = outcome.result;
+        spectator::spin_result(&self.config, target_id, &result);
+        self.sound.play(match result {
+            RouletteResult::Safe => SoundEffect::Click,
+            RouletteResult::Out => SoundEffect::Bang,
+        });
 
         let mut args = HashMap::new();
-        args.insert("name".to_string(), target_player.name.clone());
+        args.insert("name".to_string(), target_name);
+
+        let result_key = match result {
+            RouletteResult::Safe => "roulette_result_safe",
+            RouletteResult::Out => "roulette_result_out",
+        };
+        let result_message = self.i18n.get_message_with_args(result_key, &args)?;
+        println!("{}", result_message);
 
         match result {
-            RouletteResult::Safe => {
-                println!(
-                    "{}",
-                    self.i18n
-                        .get_message_with_args("roulette_result_safe", &args)?
-                );
-            }
+            RouletteResult::Safe => {}
             RouletteResult::Out => {
-                println!(
-                    "{}",
-                    self.i18n
-                        .get_message_with_args("roulette_result_out", &args)?
-                );
                 println!(
                     "{}",
                     self.i18n
                         .get_message_with_args("player_eliminated", &args)?
                 );
-                // プレイヤーを除外
-                self.config.eliminate_player(target_player.id)?;
             }
         }
 
+        // 結果を反映してターンを進める（アウトなら除外）
+        let before = self.config.clone();
+        self.config.resolve_roulette(target_id, &result)?;
+        self.record_action(ActionKind::Roulette, result_message, before);
+        if self.config.check_winner().is_none() {
+            self.announce_next_turn()?;
+        }
+
         self.wait_for_continue()?;
         Ok(())
     }
 
-    /// プレイヤー設定メニュー
-    fn player_settings_menu(&mut self) -> Result<(), InteractiveError> {
-        loop {
-            println!("\n{}", self.i18n.get_message("current_players")?);
-            for player in &self.config.players {
-                let status = if player.is_active { "🟢" } else { "🔴" };
-                println!("  {}: {} {}", player.id, player.name, status);
-            }
-
-            let confirmed = Confirm::with_theme(&self.theme)
-                .with_prompt(self.i18n.get_message("change_player_name")?)
-                .default(false)
-                .interact()
-                .map_err(|e| {
-                    InteractiveError::DialogError(format!("Confirmation failed: {}", e))
-                })?;
-
-            if !confirmed {
-                break;
-            }
-
-            // プレイヤー番号入力
-            let player_id: u8 = Input::with_theme(&self.theme)
-                .with_prompt(self.i18n.get_message("which_player")?)
-                .validate_with(|input: &u8| -> Result<(), &str> {
-                    if *input >= 1 && *input <= self.config.players.len() as u8 {
-                        Ok(())
-                    } else {
-                        Err("Invalid player ID")
-                    }
-                })
-                .interact()
-                .map_err(|e| {
-                    InteractiveError::DialogError(format!("Player ID input failed: {}", e))
-                })?;
-
-            // 新しい名前入力
-            let new_name: String = Input::with_theme(&self.theme)
-                .with_prompt(self.i18n.get_message("new_name")?)
-                .validate_with(|input: &String| -> Result<(), &str> {
-                    let trimmed = input.trim();
-                    if trimmed.is_empty() {
-                        Err("Name cannot be empty")
-                    } else if trimmed.len() > 20 {
-                        Err("Name too long")
-                    } else {
-                        Ok(())
-                    }
-                })
-                .interact()
-                .map_err(|e| InteractiveError::DialogError(format!("Name input failed: {}", e)))?;
-
-            // 名前変更
-            self.config.change_player_name(player_id, new_name)?;
-            println!("✓ 名前を変更しました");
+    /// シリンダーの回転の演出（無効の場合は回転中のメッセージだけ表示）
+    fn play_spin_animation(&self, outcome: &SpinOutcome) -> Result<(), InteractiveError> {
+        let spinning = self.i18n.get_message("roulette_spinning")?;
+        if !animation::is_enabled(&self.config.animation) {
+            println!("\n{}", spinning);
+            return Ok(());
         }
 
+        let labels = SpinLabels {
+            spinning,
+            click: self.i18n.get_message("animation_click")?,
+            bang: self.i18n.get_message("animation_bang")?,
+        };
+        let frames = animation::spin_frames(
+            outcome,
+            self.config.bullet_capacity,
+            Duration::from_millis(self.config.animation.duration_ms as u64),
+            &labels,
+        );
+        println!();
+        animation::play(&frames)?;
         Ok(())
     }
 
-    /// ロシアンルーレット設定メニュー
-    fn roulette_settings_menu(&mut self) -> Result<(), InteractiveError> {
-        println!("\n{}", self.i18n.get_message("roulette_config")?);
+    /// チャレンジアシスタント（物理カードのプレイを記録し、公開カードから判定）
+    fn challenge_assistant(&mut self) -> Result<(), InteractiveError> {
+        println!("\n{}", self.i18n.get_message("challenge_assistant")?);
 
-        let mut args = HashMap::new();
-        args.insert(
-            "capacity".to_string(),
-            self.config.bullet_capacity.to_string(),
-        );
-        args.insert(
-            "percentage".to_string(),
-            format!("{:.0}", self.config.roulette_probability_percentage()),
-        );
-        println!(
-            "{}",
-            self.i18n
-                .get_message_with_args("current_probability", &args)?
-        );
+        let active_players: Vec<(u8, String)> = self
+            .config
+            .active_players()
+            .iter()
+            .map(|p| (p.id, p.name.clone()))
+            .collect();
+        let player_names: Vec<String> = active_players
+            .iter()
+            .map(|(id, name)| format!("{}: {}", id, name))
+            .collect();
 
-        let new_capacity: u8 = Input::with_theme(&self.theme)
-            .with_prompt(self.i18n.get_message("bullet_capacity_prompt")?)
-            .default(self.config.bullet_capacity)
-            .validate_with(|input: &u8| -> Result<(), &str> {
-                if *input >= 1 && *input <= 12 {
+        // カードを出したプレイヤー（既定は現在のターン）
+        let default_index = active_players
+            .iter()
+            .position(|(id, _)| *id == self.config.current_turn)
+            .unwrap_or(0);
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("select_playing_player")?)
+            .items(&player_names)
+            .default(default_index)
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Player selection failed: {}", e))
+            })?;
+        let (player_id, player_name) = active_players[selection].clone();
+
+        // 出した枚数と宣言
+        let count: usize = Input::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("played_card_count")?)
+            .default(1)
+            .validate_with(|input: &usize| -> Result<(), &str> {
+                if (1..=HAND_SIZE).contains(input) {
                     Ok(())
                 } else {
-                    Err("Capacity must be between 1 and 12")
+                    Err("Invalid card count")
                 }
             })
             .interact()
-            .map_err(|e| InteractiveError::DialogError(format!("Capacity input failed: {}", e)))?;
-
-        self.config.bullet_capacity = new_capacity;
-
-        println!("✓ 装弾数を{}に設定しました", new_capacity);
-        Ok(())
-    }
-
-    /// 勝者表示
-    fn show_winner(&self, winner: &crate::config::Player) -> Result<(), InteractiveError> {
-        println!("\n🎉 ゲーム終了！ 🎉");
-
-        let mut args = HashMap::new();
-        args.insert("name".to_string(), winner.name.clone());
-        println!("{}", self.i18n.get_message_with_args("game_winner", &args)?);
-
-        self.wait_for_continue()?;
-        Ok(())
-    }
-
-    /// 続行待ち
-    fn wait_for_continue(&self) -> Result<(), InteractiveError> {
-        println!("\n{}", self.i18n.get_message("continue_prompt")?);
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        Ok(())
-    }
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Card count input failed: {}", e))
+            })?;
 
-    /// 参加人数設定メニュー
-    fn participant_count_settings_menu(&mut self) -> Result<(), InteractiveError> {
-        println!("\n{}", self.i18n.get_message("participant_count_setting")?);
+        let declarable: Vec<String> = CardType::DECLARABLE
+            .iter()
+            .map(|card_type| card_type.to_string())
+            .collect();
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("declared_rank_prompt")?)
+            .items(&declarable)
+            .default(0)
+            .interact()
+            .map_err(|e| InteractiveError::DialogError(format!("Rank selection failed: {}", e)))?;
+        let play = DeclaredPlay::new(player_id, count, CardType::DECLARABLE[selection]);
 
         let mut args = HashMap::new();
-        args.insert(
-            "count".to_string(),
-            self.config.participant_count.to_string(),
-        );
+        args.insert("name".to_string(), player_name.clone());
+        args.insert("count".to_string(), count.to_string());
+        args.insert("rank".to_string(), play.declared.to_string());
         println!(
-            "{}",
-            self.i18n
-                .get_message_with_args("current_participant_count", &args)?
+            "→ {}",
+            self.i18n.get_message_with_args("play_recorded", &args)?
         );
 
-        let new_count: u8 = Input::with_theme(&self.theme)
-            .with_prompt(self.i18n.get_message("set_participant_count")?)
-            .default(self.config.participant_count)
-            .validate_with(|input: &u8| -> Result<(), &str> {
-                if *input >= 2 && *input <= 4 {
-                    Ok(())
-                } else {
-                    Err("Participant count must be between 2 and 4")
-                }
-            })
+        // 次のプレイヤーが信じるか疑うか
+        let next_id = self
+            .config
+            .next_active_after(player_id)
+            .unwrap_or(player_id);
+        let next_name = self
+            .config
+            .get_player(next_id)
+            .map(|p| p.name.clone())
+            .unwrap_or_default();
+        args.clear();
+        args.insert("name".to_string(), next_name);
+        let actions = vec![
+            self.i18n.get_message("round_action_challenge")?,
+            self.i18n.get_message("round_action_accept")?,
+        ];
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt(
+                self.i18n
+                    .get_message_with_args("round_action_prompt", &args)?,
+            )
+            .items(&actions)
+            .default(0)
             .interact()
             .map_err(|e| {
-                InteractiveError::DialogError(format!("Participant count input failed: {}", e))
+                InteractiveError::DialogError(format!("Action selection failed: {}", e))
             })?;
 
-        self.config.set_participant_count(new_count)?;
+        if selection == 1 {
+            // Accept: 次のプレイヤーのターンへ
+            let before = self.config.clone();
+            self.config.current_turn = next_id;
+            self.announce_next_turn()?;
+            self.record_turn_change(before)?;
+            return Ok(());
+        }
 
-        let mut args = HashMap::new();
-        args.insert("count".to_string(), new_count.to_string());
-        println!(
-            "✓ {}",
-            self.i18n
-                .get_message_with_args("participant_count_updated", &args)?
+        // 指摘したプレイヤー（既定は次の席のプレイヤー）
+        let challengers: Vec<(u8, String)> = active_players
+            .iter()
+            .filter(|(id, _)| *id != player_id)
+            .cloned()
+            .collect();
+        let default_index = challengers
+            .iter()
+            .position(|(id, _)| *id == next_id)
+            .unwrap_or(0);
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("select_challenger")?)
+            .items(
+                &challengers
+                    .iter()
+                    .map(|(id, name)| format!("{}: {}", id, name))
+                    .collect::<Vec<_>>(),
+            )
+            .default(default_index)
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Challenger selection failed: {}", e))
+            })?;
+        let (challenger_id, challenger_name) = challengers[selection].clone();
+
+        args.clear();
+        args.insert("accuser".to_string(), challenger_name);
+        args.insert("accused".to_string(), player_name);
+        println!(
+            "\n{}",
+            self.i18n
+                .get_message_with_args("challenge_declared", &args)?
         );
 
+        // 公開されたカードを1枚ずつ入力（デッキに含まれる種類のみ）
+        let all_types = self.config.deck.card_types();
+        let type_names: Vec<String> = all_types.iter().map(|t| t.to_string()).collect();
+        let mut revealed = Vec::with_capacity(count);
+        for index in 1..=count {
+            args.clear();
+            args.insert("index".to_string(), index.to_string());
+            args.insert("count".to_string(), count.to_string());
+            let selection = Select::with_theme(&self.theme)
+                .with_prompt(
+                    self.i18n
+                        .get_message_with_args("revealed_card_prompt", &args)?,
+                )
+                .items(&type_names)
+                .default(0)
+                .interact()
+                .map_err(|e| {
+                    InteractiveError::DialogError(format!("Card selection failed: {}", e))
+                })?;
+            revealed.push(all_types[selection]);
+        }
+
+        let outcome = play
+            .judge(&revealed, self.config.rules.jokers_wild)
+            .ok_or_else(|| {
+                InteractiveError::DialogError("Revealed card count mismatch".to_string())
+            })?;
+        let verdict_key = match outcome {
+            ChallengeOutcome::Liar => "verdict_liar",
+            ChallengeOutcome::Honest => "verdict_honest",
+        };
+        println!("\n{}", self.i18n.get_message(verdict_key)?);
+
+        // 敗者はすぐにロシアンルーレット
+        let loser_id = Challenge::new(challenger_id, player_id).loser(outcome);
+        let loser_name = self
+            .config
+            .get_player(loser_id)
+            .map(|p| p.name.clone())
+            .unwrap_or_default();
+        self.show_roulette_target(&loser_name)?;
+        self.spin_roulette_for(loser_id, loser_name)
+    }
+
+    /// 次のターンのプレイヤーを表示
+    fn announce_next_turn(&self) -> Result<(), InteractiveError> {
+        if let Some(player) = self.config.current_player() {
+            let mut args = HashMap::new();
+            args.insert("name".to_string(), player.name.clone());
+            println!(
+                "➡️ {}",
+                self.i18n.get_message_with_args("next_turn", &args)?
+            );
+        }
+        Ok(())
+    }
+
+    /// プレイヤー設定メニュー（名前変更・座席管理）
+    fn player_settings_menu(&mut self) -> Result<(), InteractiveError> {
+        loop {
+            self.show_seats()?;
+
+            let actions = vec![
+                self.i18n.get_message("player_action_rename")?,
+                self.i18n.get_message("player_action_assign_profile")?,
+                self.i18n.get_message("player_action_create_profile")?,
+                self.i18n.get_message("player_action_add")?,
+                self.i18n.get_message("player_action_remove")?,
+                self.i18n.get_message("player_action_move")?,
+                self.i18n.get_message("player_action_back")?,
+            ];
+
+            let selection = Select::with_theme(&self.theme)
+                .with_prompt(self.i18n.get_message("player_settings_action")?)
+                .items(&actions)
+                .default(0)
+                .interact()
+                .map_err(|e| {
+                    InteractiveError::DialogError(format!("Player action selection failed: {}", e))
+                })?;
+
+            let result = match selection {
+                0 => self.rename_player(),
+                1 => self.assign_profile(),
+                2 => self.create_profile().map(|_| ()),
+                3 => self.add_seat(),
+                4 => self.remove_seat(),
+                5 => self.move_seat(),
+                _ => break,
+            };
+
+            // 人数制限などの入力値エラーはメニューを抜けずに表示する
+            match result {
+                Err(InteractiveError::ConfigError(ConfigError::InvalidValue(message)))
+                | Err(InteractiveError::ProfileError(ProfileError::InvalidValue(message))) => {
+                    println!("⚠️ {}", message);
+                }
+                other => other?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 座席順にプレイヤー一覧を表示
+    fn show_seats(&self) -> Result<(), InteractiveError> {
+        println!("\n{}", self.i18n.get_message("current_players")?);
+        let benched_label = self.i18n.get_message("seat_label_benched")?;
+        for (index, player) in self.config.players.iter().enumerate() {
+            let name = match player.profile_id.and_then(|id| self.profiles.get(id)) {
+                Some(profile) => style(profile.display_name())
+                    .fg(profile_color(&profile.color))
+                    .to_string(),
+                None => player.name.clone(),
+            };
+            let status = if !self.config.is_participant(player.id) {
+                format!("⚪ {}", benched_label)
+            } else if player.is_active {
+                "🟢".to_string()
+            } else {
+                "🔴".to_string()
+            };
+            println!("  {}. {} (ID {}) {}", index + 1, name, player.id, status);
+        }
+        Ok(())
+    }
+
+    /// 座席順のプレイヤーから1人選択
+    fn select_seated_player(&self, prompt_key: &str) -> Result<u8, InteractiveError> {
+        let player_names: Vec<String> = self
+            .config
+            .players
+            .iter()
+            .enumerate()
+            .map(|(index, p)| format!("{}. {}", index + 1, p.name))
+            .collect();
+
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message(prompt_key)?)
+            .items(&player_names)
+            .default(0)
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Player selection failed: {}", e))
+            })?;
+
+        Ok(self.config.players[selection].id)
+    }
+
+    /// 名前入力
+    fn input_player_name(&self) -> Result<String, InteractiveError> {
+        Input::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("new_name")?)
+            .validate_with(|input: &String| -> Result<(), &str> {
+                let trimmed = input.trim();
+                if trimmed.is_empty() {
+                    Err("Name cannot be empty")
+                } else if trimmed.len() > 20 {
+                    Err("Name too long")
+                } else {
+                    Ok(())
+                }
+            })
+            .interact()
+            .map_err(|e| InteractiveError::DialogError(format!("Name input failed: {}", e)))
+    }
+
+    /// プレイヤー名変更
+    fn rename_player(&mut self) -> Result<(), InteractiveError> {
+        let player_id = self.select_seated_player("which_player")?;
+        let new_name = self.input_player_name()?;
+
+        // 名前変更
+        let before = self.config.clone();
+        self.config.change_player_name(player_id, new_name)?;
+        println!("✓ 名前を変更しました");
+        self.record_rename(player_id, before)?;
+        Ok(())
+    }
+
+    /// 保存済みプロフィールを座席に割り当てる
+    fn assign_profile(&mut self) -> Result<(), InteractiveError> {
+        let player_id = self.select_seated_player("which_player")?;
+
+        let mut items: Vec<String> = self
+            .profiles
+            .profiles
+            .iter()
+            .map(|profile| match self.config.player_by_profile(profile.id) {
+                Some(seated) if seated.id != player_id => {
+                    format!("{} ({})", profile.display_name(), seated.name)
+                }
+                _ => profile.display_name(),
+            })
+            .collect();
+        items.push(self.i18n.get_message("profile_create_new")?);
+        items.push(self.i18n.get_message("profile_unassign")?);
+
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("select_profile")?)
+            .items(&items)
+            .default(0)
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Profile selection failed: {}", e))
+            })?;
+
+        let profile_count = self.profiles.profiles.len();
+        let profile = if selection < profile_count {
+            self.profiles.profiles[selection].clone()
+        } else if selection == profile_count {
+            self.create_profile()?
+        } else {
+            self.config.unassign_profile(player_id)?;
+            println!("✓ {}", self.i18n.get_message("profile_unassigned")?);
+            return Ok(());
+        };
+
+        let before = self.config.clone();
+        self.config.assign_profile(player_id, &profile)?;
+        self.record_rename(player_id, before)?;
+
+        let mut args = HashMap::new();
+        args.insert("name".to_string(), profile.display_name());
+        println!(
+            "✓ {}",
+            self.i18n.get_message_with_args("profile_assigned", &args)?
+        );
+        Ok(())
+    }
+
+    /// 新しいプロフィールを作成して保存
+    fn create_profile(&mut self) -> Result<Profile, InteractiveError> {
+        let name = self.input_player_name()?;
+
+        let color_index = Select::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("profile_color_prompt")?)
+            .items(
+                &PROFILE_COLORS
+                    .iter()
+                    .map(|color| style(*color).fg(profile_color(color)).to_string())
+                    .collect::<Vec<_>>(),
+            )
+            .default(0)
+            .interact()
+            .map_err(|e| InteractiveError::DialogError(format!("Color selection failed: {}", e)))?;
+
+        let avatar: String = Input::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("profile_avatar_prompt")?)
+            .default("🙂".to_string())
+            .interact()
+            .map_err(|e| InteractiveError::DialogError(format!("Avatar input failed: {}", e)))?;
+
+        let languages = self.i18n.available_languages().to_vec();
+        let mut language_names: Vec<String> =
+            languages.iter().map(|lang| lang.name.clone()).collect();
+        language_names.push(self.i18n.get_message("profile_language_none")?);
+        let language_index = Select::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("profile_language_prompt")?)
+            .items(&language_names)
+            .default(language_names.len() - 1)
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Language selection failed: {}", e))
+            })?;
+        let language = languages.get(language_index).map(|lang| lang.code.clone());
+
+        let id =
+            self.profiles
+                .add_profile(&name, PROFILE_COLORS[color_index], &avatar, language)?;
+        self.profiles.save()?;
+
+        let profile = self
+            .profiles
+            .get(id)
+            .cloned()
+            .ok_or(ProfileError::ProfileNotFound(id))?;
+
+        let mut args = HashMap::new();
+        args.insert("name".to_string(), profile.display_name());
+        println!(
+            "✓ {}",
+            self.i18n.get_message_with_args("profile_created", &args)?
+        );
+        Ok(profile)
+    }
+
+    /// 座席を追加
+    fn add_seat(&mut self) -> Result<(), InteractiveError> {
+        if self.config.players.len() >= MAX_PLAYERS as usize {
+            return Err(ConfigError::InvalidValue(format!(
+                "Maximum {} players allowed",
+                MAX_PLAYERS
+            ))
+            .into());
+        }
+
+        let name = self.input_player_name()?;
+        let before = self.config.clone();
+        let id = self.config.add_player(Some(name))?;
+
+        let mut args = HashMap::new();
+        if let Some(player) = self.config.get_player(id) {
+            args.insert("name".to_string(), player.name.clone());
+        }
+        let message = self.i18n.get_message_with_args("player_added", &args)?;
+        println!("✓ {}", message);
+        self.record_action(ActionKind::Participants, message, before);
+        Ok(())
+    }
+
+    /// 座席を削除
+    fn remove_seat(&mut self) -> Result<(), InteractiveError> {
+        let player_id = self.select_seated_player("select_player_to_remove")?;
+        let before = self.config.clone();
+        let removed = self.config.remove_player(player_id)?;
+
+        let mut args = HashMap::new();
+        args.insert("name".to_string(), removed.name);
+        let message = self.i18n.get_message_with_args("player_removed", &args)?;
+        println!("✓ {}", message);
+        self.record_action(ActionKind::Participants, message, before);
+        Ok(())
+    }
+
+    /// 席を移動
+    fn move_seat(&mut self) -> Result<(), InteractiveError> {
+        let player_id = self.select_seated_player("select_player_to_move")?;
+        let seat_count = self.config.players.len();
+
+        let mut args = HashMap::new();
+        args.insert("max".to_string(), seat_count.to_string());
+        let seat: usize = Input::with_theme(&self.theme)
+            .with_prompt(
+                self.i18n
+                    .get_message_with_args("new_seat_position", &args)?,
+            )
+            .validate_with(|input: &usize| -> Result<(), &str> {
+                if (1..=seat_count).contains(input) {
+                    Ok(())
+                } else {
+                    Err("Invalid seat number")
+                }
+            })
+            .interact()
+            .map_err(|e| InteractiveError::DialogError(format!("Seat input failed: {}", e)))?;
+
+        let before = self.config.clone();
+        self.config.move_player(player_id, seat - 1)?;
+
+        if let Some(player) = self.config.get_player(player_id) {
+            args.insert("name".to_string(), player.name.clone());
+        }
+        args.insert("seat".to_string(), seat.to_string());
+        let message = self.i18n.get_message_with_args("player_moved", &args)?;
+        println!("✓ {}", message);
+        self.record_action(ActionKind::Participants, message, before);
+        Ok(())
+    }
+
+    /// ロシアンルーレット設定メニュー
+    fn roulette_settings_menu(&mut self) -> Result<(), InteractiveError> {
+        println!("\n{}", self.i18n.get_message("roulette_config")?);
+
+        let mut args = HashMap::new();
+        args.insert(
+            "capacity".to_string(),
+            self.config.bullet_capacity.to_string(),
+        );
+        args.insert("live".to_string(), self.config.live_rounds.to_string());
+        args.insert(
+            "percentage".to_string(),
+            format!("{:.0}", self.config.roulette_probability_percentage()),
+        );
+        println!(
+            "{}",
+            self.i18n
+                .get_message_with_args("current_probability", &args)?
+        );
+
+        let new_capacity: u8 = Input::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("bullet_capacity_prompt")?)
+            .default(self.config.bullet_capacity)
+            .validate_with(|input: &u8| -> Result<(), &str> {
+                if *input >= 1 && *input <= 12 {
+                    Ok(())
+                } else {
+                    Err("Capacity must be between 1 and 12")
+                }
+            })
+            .interact()
+            .map_err(|e| InteractiveError::DialogError(format!("Capacity input failed: {}", e)))?;
+
+        let new_live_rounds: u8 = Input::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("live_rounds_prompt")?)
+            .default(self.config.live_rounds.min(new_capacity))
+            .validate_with(|input: &u8| -> Result<(), &str> {
+                if *input >= 1 && *input <= new_capacity {
+                    Ok(())
+                } else {
+                    Err("Live rounds must be between 1 and the bullet capacity")
+                }
+            })
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Live rounds input failed: {}", e))
+            })?;
+
+        let current_duration = if self.config.animation.enabled {
+            self.config.animation.duration_ms
+        } else {
+            0
+        };
+        let new_duration: u32 = Input::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("animation_duration_prompt")?)
+            .default(current_duration)
+            .validate_with(|input: &u32| -> Result<(), String> {
+                if *input <= MAX_ANIMATION_MS {
+                    Ok(())
+                } else {
+                    Err(format!("Duration must be at most {} ms", MAX_ANIMATION_MS))
+                }
+            })
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Animation duration input failed: {}", e))
+            })?;
+
+        self.config.bullet_capacity = new_capacity;
+        self.config.live_rounds = new_live_rounds;
+        // 0は演出なし（以前の長さは残す）
+        self.config.animation.enabled = new_duration > 0;
+        if new_duration > 0 {
+            self.config.animation.duration_ms = new_duration;
+        }
+
+        let sound_on = Confirm::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("sound_effects_prompt")?)
+            .default(!self.config.sound.muted)
+            .interact()
+            .map_err(|e| InteractiveError::DialogError(format!("Sound setting failed: {}", e)))?;
+        self.config.sound.muted = !sound_on;
+        self.sound = SoundPlayer::new(&self.config.sound);
+
+        println!(
+            "✓ 装弾数を{}、実弾数を{}に設定しました",
+            new_capacity, new_live_rounds
+        );
+        Ok(())
+    }
+
+    /// ルールプリセットを選択して適用する
+    fn preset_menu(&mut self) -> Result<(), InteractiveError> {
+        println!("\n{}", self.i18n.get_message("presets_title")?);
+
+        let (presets, errors) = Preset::load_all();
+        for error in errors {
+            let mut args = HashMap::new();
+            args.insert("error".to_string(), error.to_string());
+            println!(
+                "{}",
+                style(
+                    self.i18n
+                        .get_message_with_args("preset_load_warning", &args)?
+                )
+                .yellow()
+            );
+        }
+
+        let mut items = Vec::with_capacity(presets.len() + 1);
+        for preset in &presets {
+            let mut args = HashMap::new();
+            args.insert("name".to_string(), preset.name.clone());
+            args.insert(
+                "description".to_string(),
+                preset.description_for(&self.config.language).to_string(),
+            );
+            let key = if preset.user_defined {
+                "preset_item_user"
+            } else {
+                "preset_item"
+            };
+            items.push(self.i18n.get_message_with_args(key, &args)?);
+        }
+        items.push(self.i18n.get_message("player_action_back")?);
+
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("select_preset")?)
+            .items(&items)
+            .default(0)
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Preset selection failed: {}", e))
+            })?;
+        let Some(preset) = presets.get(selection) else {
+            return Ok(());
+        };
+
+        let mut args = HashMap::new();
+        args.insert("name".to_string(), preset.name.clone());
+        args.insert("capacity".to_string(), preset.bullet_capacity.to_string());
+        args.insert("live".to_string(), preset.live_rounds.to_string());
+        args.insert("players".to_string(), preset.participant_count.to_string());
+        args.insert("deck".to_string(), preset.deck.total().to_string());
+        let jokers_key = if preset.rules.jokers_wild {
+            "preset_jokers_wild"
+        } else {
+            "preset_jokers_plain"
+        };
+        args.insert("jokers".to_string(), self.i18n.get_message(jokers_key)?);
+        println!(
+            "{}",
+            self.i18n.get_message_with_args("preset_summary", &args)?
+        );
+
+        let confirmed = Confirm::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("confirm_apply_preset")?)
+            .default(true)
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Preset confirmation failed: {}", e))
+            })?;
+        if !confirmed {
+            return Ok(());
+        }
+
+        let before = self.config.clone();
+        if let Err(e) = self.config.apply_preset(preset) {
+            println!("{}", style(format!("⚠️ {}", e)).yellow());
+            return Ok(());
+        }
+
+        let message = self.i18n.get_message_with_args("preset_applied", &args)?;
+        println!("✓ {}", message);
+        self.record_action(ActionKind::Preset, message, before);
+        Ok(())
+    }
+
+    /// 終わったゲームの順位をトーナメントの得点に加算する
+    fn record_tournament_game(&mut self) -> Result<(), InteractiveError> {
+        if self.tournament.is_none() {
+            return Ok(());
+        }
+
+        // 別のプロセスが記録した得点も含めた最新の記録に加算する
+        let updated = Tournament::update(|tournament| tournament.record_game(&self.config))?;
+        let Some((tournament, awarded)) = updated else {
+            self.tournament = None;
+            return Ok(());
+        };
+        self.tournament = Some(tournament);
+
+        println!("\n{}", self.i18n.get_message("tournament_points_awarded")?);
+        for (place, (player_id, points)) in awarded.iter().enumerate() {
+            let mut args = HashMap::new();
+            args.insert("place".to_string(), (place + 1).to_string());
+            args.insert(
+                "name".to_string(),
+                self.config
+                    .get_player(*player_id)
+                    .map(|p| p.name.clone())
+                    .unwrap_or_default(),
+            );
+            args.insert("points".to_string(), points.to_string());
+            println!(
+                "  {}",
+                self.i18n
+                    .get_message_with_args("tournament_points_line", &args)?
+            );
+        }
+        Ok(())
+    }
+
+    /// トーナメントの成績表を表示
+    fn show_standings(&self, tournament: &Tournament) -> Result<(), InteractiveError> {
+        let mut args = HashMap::new();
+        args.insert("games".to_string(), tournament.games_played.to_string());
+        let goal_key = match tournament.goal {
+            TournamentGoal::Games(target) => {
+                args.insert("target".to_string(), target.to_string());
+                "tournament_goal_games"
+            }
+            TournamentGoal::Points(target) => {
+                args.insert("target".to_string(), target.to_string());
+                "tournament_goal_points"
+            }
+        };
+        println!(
+            "\n{}",
+            self.i18n
+                .get_message_with_args("tournament_standings", &args)?
+        );
+        println!("{}", self.i18n.get_message_with_args(goal_key, &args)?);
+
+        for (rank, standing) in tournament.sorted_standings().iter().enumerate() {
+            args.clear();
+            args.insert("rank".to_string(), (rank + 1).to_string());
+            args.insert("name".to_string(), standing.name.clone());
+            args.insert("points".to_string(), standing.points.to_string());
+            args.insert("wins".to_string(), standing.wins.to_string());
+            args.insert("games".to_string(), standing.games.to_string());
+            println!(
+                "  {}",
+                self.i18n
+                    .get_message_with_args("tournament_standing_line", &args)?
+            );
+        }
+        Ok(())
+    }
+
+    /// トーナメントメニュー（開始・成績表・勝ち抜き表・終了）
+    fn tournament_menu(&mut self) -> Result<(), InteractiveError> {
+        println!("\n{}", self.i18n.get_message("tournament_title")?);
+
+        let Some(tournament) = self.tournament.clone() else {
+            println!("{}", self.i18n.get_message("tournament_none")?);
+            let start = Confirm::with_theme(&self.theme)
+                .with_prompt(self.i18n.get_message("confirm_start_tournament")?)
+                .default(true)
+                .interact()
+                .map_err(|e| {
+                    InteractiveError::DialogError(format!("Tournament confirmation failed: {}", e))
+                })?;
+            if start {
+                self.start_tournament()?;
+            }
+            return Ok(());
+        };
+
+        let actions = vec![
+            self.i18n.get_message("tournament_action_standings")?,
+            self.i18n.get_message("tournament_action_bracket")?,
+            self.i18n.get_message("tournament_action_end")?,
+            self.i18n.get_message("player_action_back")?,
+        ];
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("choose_option")?)
+            .items(&actions)
+            .default(0)
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Tournament action failed: {}", e))
+            })?;
+
+        match selection {
+            0 => {
+                self.show_standings(&tournament)?;
+                self.wait_for_continue()?;
+            }
+            1 => self.bracket_menu()?,
+            2 => {
+                let end = Confirm::with_theme(&self.theme)
+                    .with_prompt(self.i18n.get_message("confirm_end_tournament")?)
+                    .default(false)
+                    .interact()
+                    .map_err(|e| {
+                        InteractiveError::DialogError(format!(
+                            "Tournament confirmation failed: {}",
+                            e
+                        ))
+                    })?;
+                if end {
+                    self.show_standings(&tournament)?;
+                    Tournament::clear()?;
+                    self.tournament = None;
+                    println!("✓ {}", self.i18n.get_message("tournament_ended")?);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// 終了条件を入力してトーナメントを開始する
+    fn start_tournament(&mut self) -> Result<(), InteractiveError> {
+        let goals = vec![
+            self.i18n.get_message("tournament_goal_type_games")?,
+            self.i18n.get_message("tournament_goal_type_points")?,
+        ];
+        let goal_type = Select::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("tournament_goal_prompt")?)
+            .items(&goals)
+            .default(0)
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Tournament goal selection failed: {}", e))
+            })?;
+
+        let target: u32 = Input::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("tournament_target_prompt")?)
+            .default(if goal_type == 0 { 5 } else { 10 })
+            .validate_with(|input: &u32| -> Result<(), &str> {
+                if *input >= 1 {
+                    Ok(())
+                } else {
+                    Err("Target must be at least 1")
+                }
+            })
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Tournament target input failed: {}", e))
+            })?;
+
+        let goal = if goal_type == 0 {
+            TournamentGoal::Games(target)
+        } else {
+            TournamentGoal::Points(target)
+        };
+        let tournament = Tournament::new(goal)?;
+        tournament.save()?;
+        self.tournament = Some(tournament);
+        println!("✓ {}", self.i18n.get_message("tournament_started")?);
+        Ok(())
+    }
+
+    /// 勝ち抜き表の作成・勝者の記録
+    fn bracket_menu(&mut self) -> Result<(), InteractiveError> {
+        let Some(tournament) = self.tournament.clone() else {
+            return Ok(());
+        };
+
+        let Some(bracket) = tournament.bracket.as_ref() else {
+            // 勝ち抜き表を作成（参加者の初期値は現在の座席の全員）
+            let seated = self
+                .config
+                .players
+                .iter()
+                .map(|p| p.name.clone())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let entrants: String = Input::with_theme(&self.theme)
+                .with_prompt(self.i18n.get_message("bracket_entrants_prompt")?)
+                .default(seated)
+                .interact()
+                .map_err(|e| {
+                    InteractiveError::DialogError(format!("Entrant input failed: {}", e))
+                })?;
+            let table_size: u8 = Input::with_theme(&self.theme)
+                .with_prompt(self.i18n.get_message("bracket_table_size_prompt")?)
+                .default(MAX_PLAYERS.min(4))
+                .interact()
+                .map_err(|e| {
+                    InteractiveError::DialogError(format!("Table size input failed: {}", e))
+                })?;
+
+            let entrants = entrants
+                .split(',')
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty())
+                .collect();
+            match Bracket::new(entrants, table_size) {
+                Ok(bracket) => {
+                    let updated = Tournament::update(|latest| {
+                        latest.bracket = Some(bracket);
+                        Ok(())
+                    })?;
+                    self.tournament = updated.map(|(tournament, _)| tournament);
+                    if let Some(bracket) = self.tournament.as_ref().and_then(|t| t.bracket.as_ref())
+                    {
+                        self.show_bracket(bracket)?;
+                    }
+                }
+                Err(e) => println!("{}", style(format!("⚠️ {}", e)).yellow()),
+            }
+            return Ok(());
+        };
+
+        self.show_bracket(bracket)?;
+        if bracket.champion().is_some() {
+            self.wait_for_continue()?;
+            return Ok(());
+        }
+
+        // 勝者が決まっていない卓を選んで勝者を記録
+        let open_tables: Vec<usize> = bracket
+            .current_round()
+            .iter()
+            .enumerate()
+            .filter(|(_, table)| table.winner.is_none())
+            .map(|(index, _)| index)
+            .collect();
+        let mut items = Vec::with_capacity(open_tables.len() + 1);
+        for &index in &open_tables {
+            let mut args = HashMap::new();
+            args.insert("table".to_string(), (index + 1).to_string());
+            args.insert(
+                "players".to_string(),
+                bracket.current_round()[index].players.join(", "),
+            );
+            items.push(self.i18n.get_message_with_args("bracket_table", &args)?);
+        }
+        items.push(self.i18n.get_message("player_action_back")?);
+
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("bracket_select_table")?)
+            .items(&items)
+            .default(0)
+            .interact()
+            .map_err(|e| InteractiveError::DialogError(format!("Table selection failed: {}", e)))?;
+        let Some(&table_index) = open_tables.get(selection) else {
+            return Ok(());
+        };
+
+        let players = bracket.current_round()[table_index].players.clone();
+        let winner = Select::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("bracket_select_winner")?)
+            .items(&players)
+            .default(0)
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Winner selection failed: {}", e))
+            })?;
+        let winner = &players[winner];
+        let updated = Tournament::update(|latest| match latest.bracket.as_mut() {
+            Some(bracket) => bracket.record_winner(table_index, winner),
+            None => Err(TournamentError::InvalidValue(
+                "The bracket was removed".to_string(),
+            )),
+        })?;
+        self.tournament = updated.map(|(tournament, _)| tournament);
+
+        let champion = self
+            .tournament
+            .as_ref()
+            .and_then(|t| t.bracket.as_ref())
+            .and_then(|bracket| bracket.champion().map(str::to_string));
+        if let Some(champion) = champion {
+            let mut args = HashMap::new();
+            args.insert("name".to_string(), champion);
+            println!(
+                "\n{}",
+                style(
+                    self.i18n
+                        .get_message_with_args("tournament_champion", &args)?
+                )
+                .green()
+                .bold()
+            );
+        }
+        Ok(())
+    }
+
+    /// 勝ち抜き表を表示
+    fn show_bracket(&self, bracket: &Bracket) -> Result<(), InteractiveError> {
+        for (round_index, round) in bracket.rounds.iter().enumerate() {
+            let mut args = HashMap::new();
+            args.insert("round".to_string(), (round_index + 1).to_string());
+            println!(
+                "\n{}",
+                self.i18n.get_message_with_args("bracket_round", &args)?
+            );
+            for (index, table) in round.iter().enumerate() {
+                args.clear();
+                args.insert("table".to_string(), (index + 1).to_string());
+                args.insert("players".to_string(), table.players.join(", "));
+                let line = self.i18n.get_message_with_args("bracket_table", &args)?;
+                match &table.winner {
+                    Some(winner) => println!("  {} → 🏆 {}", line, winner),
+                    None => println!("  {}", line),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 勝者表示
+    fn show_winner(&self, winner: &Player) -> Result<(), InteractiveError> {
+        println!("\n🎉 ゲーム終了！ 🎉");
+
+        let mut args = HashMap::new();
+        args.insert("name".to_string(), winner.name.clone());
+        println!("{}", self.i18n.get_message_with_args("game_winner", &args)?);
+        self.show_placements()?;
+
+        self.wait_for_continue()?;
+        Ok(())
+    }
+
+    /// 最終順位（除外されたラウンドと理由付き）を表示
+    fn show_placements(&self) -> Result<(), InteractiveError> {
+        println!("\n{}", self.i18n.get_message("placements_title")?);
+        for placement in self.config.placements() {
+            let mut args = HashMap::new();
+            args.insert("place".to_string(), placement.place.to_string());
+            args.insert(
+                "name".to_string(),
+                self.config
+                    .get_player(placement.player_id)
+                    .map(|p| p.name.clone())
+                    .unwrap_or_default(),
+            );
+
+            let key = match placement.elimination {
+                None if placement.place == 1 => "placement_winner",
+                Some(Elimination {
+                    round: Some(round),
+                    cause,
+                    ..
+                }) => {
+                    let cause_key = match cause {
+                        EliminationCause::Roulette => "elimination_cause_roulette",
+                        EliminationCause::Manual => "elimination_cause_manual",
+                        EliminationCause::Unknown => "elimination_cause_unknown",
+                    };
+                    args.insert("round".to_string(), round.to_string());
+                    args.insert("cause".to_string(), self.i18n.get_message(cause_key)?);
+                    "placement_eliminated"
+                }
+                _ => "placement_unrecorded",
+            };
+            println!("  {}", self.i18n.get_message_with_args(key, &args)?);
+        }
+        Ok(())
+    }
+
+    /// 続行待ち
+    fn wait_for_continue(&self) -> Result<(), InteractiveError> {
+        println!("\n{}", self.i18n.get_message("continue_prompt")?);
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        Ok(())
+    }
+
+    /// 参加人数設定メニュー
+    fn participant_count_settings_menu(&mut self) -> Result<(), InteractiveError> {
+        println!("\n{}", self.i18n.get_message("participant_count_setting")?);
+
+        let mut args = HashMap::new();
+        args.insert(
+            "count".to_string(),
+            self.config.participant_count.to_string(),
+        );
+        println!(
+            "{}",
+            self.i18n
+                .get_message_with_args("current_participant_count", &args)?
+        );
+
+        let mut range_args = HashMap::new();
+        range_args.insert("min".to_string(), MIN_PLAYERS.to_string());
+        range_args.insert("max".to_string(), MAX_PLAYERS.to_string());
+        let new_count: u8 = Input::with_theme(&self.theme)
+            .with_prompt(
+                self.i18n
+                    .get_message_with_args("set_participant_count", &range_args)?,
+            )
+            .default(self.config.participant_count)
+            .validate_with(|input: &u8| -> Result<(), &str> {
+                if (MIN_PLAYERS..=MAX_PLAYERS).contains(input) {
+                    Ok(())
+                } else {
+                    Err("Participant count is out of range")
+                }
+            })
+            .interact()
+            .map_err(|e| {
+                InteractiveError::DialogError(format!("Participant count input failed: {}", e))
+            })?;
+
+        let before = self.config.clone();
+        self.config.set_participant_count(new_count)?;
+
+        let mut args = HashMap::new();
+        args.insert("count".to_string(), new_count.to_string());
+        let message = self
+            .i18n
+            .get_message_with_args("participant_count_updated", &args)?;
+        println!("✓ {}", message);
+
+        self.record_action(ActionKind::Participants, message, before);
+        Ok(())
+    }
+
+    /// 設定の変更を操作履歴に記録する（保存は未保存の変更としてまとめて行う）
+    fn record_action(&mut self, kind: ActionKind, description: String, before: GameConfig) {
+        spectator::record_change(&before, &self.config);
+        self.unsaved.push(description.clone());
+        self.journal
+            .record(kind, description, before, self.config.clone());
+    }
+
+    /// ターンの移動を記録
+    fn record_turn_change(&mut self, before: GameConfig) -> Result<(), InteractiveError> {
+        let mut args = HashMap::new();
+        if let Some(player) = self.config.current_player() {
+            args.insert("name".to_string(), player.name.clone());
+        }
+        let message = self.i18n.get_message_with_args("next_turn", &args)?;
+        self.record_action(ActionKind::Turn, message, before);
+        Ok(())
+    }
+
+    /// 名前変更を記録
+    fn record_rename(&mut self, player_id: u8, before: GameConfig) -> Result<(), InteractiveError> {
+        let mut args = HashMap::new();
+        if let Some(player) = before.get_player(player_id) {
+            args.insert("old".to_string(), player.name.clone());
+        }
+        if let Some(player) = self.config.get_player(player_id) {
+            args.insert("new".to_string(), player.name.clone());
+        }
+        let message = self.i18n.get_message_with_args("journal_rename", &args)?;
+        self.record_action(ActionKind::Rename, message, before);
+        Ok(())
+    }
+
+    /// 取り消し・やり直しメニュー
+    fn undo_redo_menu(&mut self) -> Result<(), InteractiveError> {
+        println!("\n{}", self.i18n.get_message("undo_redo")?);
+
+        // 直近の監査記録（ルーレット結果の取り消しなど）
+        let audit_log = self.journal.audit_log();
+        if !audit_log.is_empty() {
+            println!("{}", self.i18n.get_message("audit_log")?);
+            for entry in audit_log.iter().rev().take(3) {
+                println!("  [{}] {}", entry.timestamp, entry.message);
+            }
+        }
+
+        let none_label = self.i18n.get_message("journal_none")?;
+        let mut args = HashMap::new();
+        args.insert(
+            "action".to_string(),
+            self.journal
+                .peek_undo()
+                .map(|entry| entry.description.clone())
+                .unwrap_or_else(|| none_label.clone()),
+        );
+        let undo_label = self.i18n.get_message_with_args("undo_action", &args)?;
+        args.insert(
+            "action".to_string(),
+            self.journal
+                .peek_redo()
+                .map(|entry| entry.description.clone())
+                .unwrap_or(none_label),
+        );
+        let redo_label = self.i18n.get_message_with_args("redo_action", &args)?;
+
+        let items = vec![
+            undo_label,
+            redo_label,
+            self.i18n.get_message("player_action_back")?,
+        ];
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt(self.i18n.get_message("choose_option")?)
+            .items(&items)
+            .default(0)
+            .interact()
+            .map_err(|e| InteractiveError::DialogError(format!("Undo selection failed: {}", e)))?;
+
+        let before = self.config.clone();
+        let applied = match selection {
+            0 => {
+                let Some(entry) = self.journal.peek_undo() else {
+                    println!("{}", self.i18n.get_message("nothing_to_undo")?);
+                    return Ok(());
+                };
+
+                // ルーレット結果の取り消しは再抽選につながるため明示的に確認する
+                let confirmed_roulette = if entry.kind.is_roulette() {
+                    let mut args = HashMap::new();
+                    args.insert("action".to_string(), entry.description.clone());
+                    println!(
+                        "⚠️ {}",
+                        self.i18n
+                            .get_message_with_args("undo_roulette_warning", &args)?
+                    );
+                    Confirm::with_theme(&self.theme)
+                        .with_prompt(self.i18n.get_message("confirm_undo_roulette")?)
+                        .default(false)
+                        .interact()
+                        .map_err(|e| {
+                            InteractiveError::DialogError(format!(
+                                "Undo confirmation failed: {}",
+                                e
+                            ))
+                        })?
+                } else {
+                    false
+                };
+
+                self.journal
+                    .undo(&mut self.config, confirmed_roulette)
+                    .map(|entry| entry.map(|entry| ("undone", entry)))
+            }
+            1 => self
+                .journal
+                .redo(&mut self.config)
+                .map(|entry| entry.map(|entry| ("redone", entry))),
+            _ => return Ok(()),
+        };
+        let applied = match applied {
+            Err(JournalError::Conflict(fields)) => {
+                let mut args = HashMap::new();
+                args.insert("fields".to_string(), fields);
+                println!(
+                    "⚠️ {}",
+                    self.i18n.get_message_with_args("journal_conflict", &args)?
+                );
+                return Ok(());
+            }
+            applied => applied?,
+        };
+
+        match applied {
+            Some((key, entry)) => {
+                spectator::record_change(&before, &self.config);
+                let mut args = HashMap::new();
+                args.insert("action".to_string(), entry.description);
+                let message = self.i18n.get_message_with_args(key, &args)?;
+                self.unsaved.push(message.clone());
+                if self.save_config()? {
+                    println!("✓ {}", message);
+                }
+            }
+            None if selection == 1 => {
+                println!("{}", self.i18n.get_message("nothing_to_redo")?);
+            }
+            None => {}
+        }
+
         Ok(())
     }
 
     /// ゲームリセット処理
     fn handle_game_reset(&mut self) -> Result<(), InteractiveError> {
-        println!("\n{}", self.i18n.get_message("game_reset")?);
+        let message = self.i18n.get_message("game_reset")?;
+        println!("\n{}", message);
+        let before = self.config.clone();
         self.config.reset_game();
+        self.record_action(ActionKind::Reset, message, before);
         Ok(())
     }
 
-    /// 新しいゲームを開始するか確認
-    fn ask_new_game(&self) -> Result<bool, InteractiveError> {
+    /// 新しいゲームを開始するか確認（トーナメント開催中は成績も表示）
+    fn ask_new_game(&mut self) -> Result<bool, InteractiveError> {
+        if let Some(tournament) = &self.tournament {
+            self.show_standings(tournament)?;
+
+            if tournament.is_finished() {
+                let mut args = HashMap::new();
+                let champion = tournament
+                    .leader()
+                    .map(|s| s.name.clone())
+                    .unwrap_or_default();
+                args.insert("name".to_string(), champion);
+                println!(
+                    "\n{}",
+                    style(
+                        self.i18n
+                            .get_message_with_args("tournament_champion", &args)?
+                    )
+                    .green()
+                    .bold()
+                );
+                Tournament::clear()?;
+                self.tournament = None;
+            }
+        }
+
         let confirmed = Confirm::with_theme(&self.theme)
             .with_prompt(self.i18n.get_message("start_new_game")?)
             .default(true)
@@ -542,12 +2005,12 @@ impl InteractiveUI {
     fn player_recovery_menu(&mut self) -> Result<(), InteractiveError> {
         println!("\n{}", self.i18n.get_message("player_recovery")?);
 
-        // 回復可能なプレイヤー（非アクティブで参加人数範囲内）を取得
+        // 回復可能なプレイヤー（非アクティブで参加席）を取得
         let recoverable_players: Vec<(u8, String)> = self
             .config
-            .players
+            .participants()
             .iter()
-            .filter(|p| !p.is_active && p.id <= self.config.participant_count)
+            .filter(|p| !p.is_active)
             .map(|p| (p.id, p.name.clone()))
             .collect();
 
@@ -586,14 +2049,12 @@ impl InteractiveUI {
 
         if confirmed {
             // プレイヤーを回復（アクティブ化）
-            if let Some(player) = self.config.get_player_mut(*target_id) {
-                player.is_active = true;
-            }
+            let before = self.config.clone();
+            self.config.recover_player(*target_id)?;
 
-            println!(
-                "{}",
-                self.i18n.get_message_with_args("player_recovered", &args)?
-            );
+            let message = self.i18n.get_message_with_args("player_recovered", &args)?;
+            println!("{}", message);
+            self.record_action(ActionKind::Recovery, message, before);
         }
 
         Ok(())
@@ -603,12 +2064,11 @@ impl InteractiveUI {
     fn player_revive_menu(&mut self) -> Result<(), InteractiveError> {
         println!("\n{}", self.i18n.get_message("player_revive")?);
 
-        // 蘇生可能なプレイヤー（非アクティブで参加人数範囲外）を取得
+        // 蘇生可能なプレイヤー（控え席）を取得
         let revivable_players: Vec<(u8, String)> = self
             .config
-            .players
+            .benched_players()
             .iter()
-            .filter(|p| !p.is_active && p.id > self.config.participant_count)
             .map(|p| (p.id, p.name.clone()))
             .collect();
 
@@ -646,26 +2106,32 @@ impl InteractiveUI {
             })?;
 
         if confirmed {
-            let target_id = *target_id;
-            // プレイヤーを蘇生（アクティブ化し、参加人数を増やす）
-            if let Some(player) = self.config.get_player_mut(target_id) {
-                player.is_active = true;
-                // 参加人数を増やして蘇生したプレイヤーを含める
-                if self.config.participant_count < target_id {
-                    self.config.participant_count = target_id;
-                }
-            }
+            // プレイヤーを蘇生（参加席に加え、参加人数を増やす）
+            let before = self.config.clone();
+            self.config.revive_player(*target_id)?;
 
-            println!(
-                "{}",
-                self.i18n.get_message_with_args("player_revived", &args)?
-            );
+            let message = self.i18n.get_message_with_args("player_revived", &args)?;
+            println!("{}", message);
+            self.record_action(ActionKind::Revive, message, before);
         }
 
         Ok(())
     }
 }
 
+/// プロフィールの色名を端末の色に変換
+fn profile_color(color: &str) -> Color {
+    match color {
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        _ => Color::White,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
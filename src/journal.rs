@@ -0,0 +1,605 @@
+use liars_table::{ConfigError, FileLock, GameConfig, journal_path, write_atomic};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+/// 保持する取り消し履歴の最大件数
+const MAX_HISTORY: usize = 50;
+
+#[derive(Error, Debug)]
+pub enum JournalError {
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    JsonError(#[from] serde_json::Error),
+    #[error("Changed after the action: {0}")]
+    Conflict(String),
+    #[error("Configuration error: {0}")]
+    ConfigError(#[from] ConfigError),
+}
+
+/// 設定の保存の結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveOutcome {
+    /// そのまま保存した
+    Saved,
+    /// 別のプロセスが書き換えていたため、最新の設定に変更を反映し直して保存した
+    Rebased,
+    /// 別のプロセスが同じ項目を書き換えていたため、変更を保存せずに最新の設定に置き換えた
+    Lost,
+}
+
+/// 記録される卓の操作の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActionKind {
+    /// ロシアンルーレットの結果（アウトによる除外を含む）
+    Roulette,
+    /// 除外されたプレイヤーの回復
+    Recovery,
+    /// 控え席のプレイヤーの蘇生
+    Revive,
+    /// 名前・プロフィールの変更
+    Rename,
+    /// 参加人数・座席の変更
+    Participants,
+    /// ターンの移動（Accept）
+    Turn,
+    /// ルールプリセットの適用
+    Preset,
+    /// 新しいゲームの開始
+    Reset,
+}
+
+impl ActionKind {
+    /// 取り消すと再抽選が可能になる操作か（明示的な確認と監査記録が必要）
+    pub fn is_roulette(self) -> bool {
+        self == ActionKind::Roulette
+    }
+}
+
+/// 1回の操作の記録（操作前後の設定のスナップショット）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub kind: ActionKind,
+    pub description: String,
+    pub timestamp: u64,
+    pub before: GameConfig,
+    pub after: GameConfig,
+}
+
+impl JournalEntry {
+    /// 同じ操作の記録か（別々に読み込んだ履歴どうしで比べる）
+    fn same_action(&self, other: &JournalEntry) -> bool {
+        self.kind == other.kind
+            && self.timestamp == other.timestamp
+            && self.description == other.description
+    }
+}
+
+/// 取り消し・やり直しの監査記録
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub timestamp: u64,
+    pub message: String,
+}
+
+/// まだ保存していない履歴の変更（保存時に最新の履歴へ反映し直す）
+#[derive(Debug, Clone)]
+enum Change {
+    Record(JournalEntry),
+    Undo(JournalEntry),
+    Redo(JournalEntry),
+    Audit(AuditEntry),
+}
+
+/// 取り消し・やり直しのための操作履歴
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Journal {
+    #[serde(default)]
+    undo_stack: Vec<JournalEntry>,
+    #[serde(default)]
+    redo_stack: Vec<JournalEntry>,
+    #[serde(default)]
+    audit: Vec<AuditEntry>,
+    #[serde(skip)]
+    pending: Vec<Change>,
+}
+
+impl Journal {
+    /// 履歴ファイルから読み込む（存在しない場合は空）
+    pub fn load() -> Result<Self, JournalError> {
+        Self::load_from(&Self::get_journal_path())
+    }
+
+    /// 読み込んだ後の変更を履歴ファイルに保存する
+    ///
+    /// ロック中に最新の履歴を読み込み直して変更を反映するため、
+    /// 別のプロセス（Web画面・チャットボットなど）が記録した操作を上書きしない
+    pub fn save(&mut self) -> Result<(), JournalError> {
+        self.save_to(&Self::get_journal_path())
+    }
+
+    /// 指定したパスから読み込む（存在しない場合は空）
+    fn load_from(path: &Path) -> Result<Self, JournalError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// 指定したパスに保存する（読み込み直しから書き込みまでロックを保持する）
+    fn save_to(&mut self, path: &Path) -> Result<(), JournalError> {
+        let _lock = FileLock::acquire(path)?;
+
+        let mut latest = Self::load_from(path)?;
+        for change in std::mem::take(&mut self.pending) {
+            latest.replay(change);
+        }
+        let content = serde_json::to_string_pretty(&latest)?;
+        write_atomic(path, content.as_bytes())?;
+        *self = latest;
+        Ok(())
+    }
+
+    /// 保存していない変更を反映する
+    ///
+    /// 取り消し・やり直しは、対象の操作が最新の履歴でも次に取り消される（やり直される）
+    /// 操作の場合だけ反映する
+    fn replay(&mut self, change: Change) {
+        match change {
+            Change::Record(entry) => self.push_undo(entry),
+            Change::Undo(entry) => {
+                if self
+                    .undo_stack
+                    .last()
+                    .is_some_and(|top| top.same_action(&entry))
+                {
+                    self.undo_stack.pop();
+                    self.redo_stack.push(entry);
+                }
+            }
+            Change::Redo(entry) => {
+                if self
+                    .redo_stack
+                    .last()
+                    .is_some_and(|top| top.same_action(&entry))
+                {
+                    self.redo_stack.pop();
+                    self.undo_stack.push(entry);
+                }
+            }
+            Change::Audit(entry) => self.audit.push(entry),
+        }
+    }
+
+    /// 操作を取り消し履歴に積む（やり直し履歴は破棄される）
+    fn push_undo(&mut self, entry: JournalEntry) {
+        self.undo_stack.push(entry);
+        if self.undo_stack.len() > MAX_HISTORY {
+            self.undo_stack.remove(0);
+        }
+        self.redo_stack.clear();
+    }
+
+    /// 履歴ファイルのパスを取得（設定ファイルごとの履歴）
+    fn get_journal_path() -> PathBuf {
+        journal_path()
+    }
+
+    /// 操作を記録する（やり直し履歴は破棄される）
+    pub fn record(
+        &mut self,
+        kind: ActionKind,
+        description: String,
+        before: GameConfig,
+        after: GameConfig,
+    ) {
+        let entry = JournalEntry {
+            kind,
+            description,
+            timestamp: now(),
+            before,
+            after,
+        };
+        self.pending.push(Change::Record(entry.clone()));
+        self.push_undo(entry);
+    }
+
+    /// 最近の操作（新しい順に最大`count`件）
+    pub fn recent(&self, count: usize) -> impl Iterator<Item = &JournalEntry> {
+        self.undo_stack.iter().rev().take(count)
+    }
+
+    /// 次に取り消される操作
+    pub fn peek_undo(&self) -> Option<&JournalEntry> {
+        self.undo_stack.last()
+    }
+
+    /// 次にやり直される操作
+    pub fn peek_redo(&self) -> Option<&JournalEntry> {
+        self.redo_stack.last()
+    }
+
+    /// 直前の操作を取り消し、その操作で変わった項目だけを操作前に戻す
+    ///
+    /// ロシアンルーレットの結果は取り消すと再抽選できてしまうため、
+    /// 呼び出し側で明示的に確認したうえで`confirmed_roulette`を渡す。
+    /// 確認なしの場合は何もせず`None`を返す。
+    /// 操作の後に同じ項目が変更されていた場合は`JournalError::Conflict`を返す
+    pub fn undo(
+        &mut self,
+        config: &mut GameConfig,
+        confirmed_roulette: bool,
+    ) -> Result<Option<JournalEntry>, JournalError> {
+        let Some(entry) = self.undo_stack.last() else {
+            return Ok(None);
+        };
+        if entry.kind.is_roulette() && !confirmed_roulette {
+            return Ok(None);
+        }
+
+        apply_changes(config, &entry.after, &entry.before)?;
+        let Some(entry) = self.undo_stack.pop() else {
+            return Ok(None);
+        };
+        if entry.kind.is_roulette() {
+            self.audit(format!("Roulette result undone: {}", entry.description));
+        }
+        self.pending.push(Change::Undo(entry.clone()));
+        self.redo_stack.push(entry.clone());
+        Ok(Some(entry))
+    }
+
+    /// 取り消した操作をやり直し、その操作で変わった項目だけを操作後に戻す
+    pub fn redo(&mut self, config: &mut GameConfig) -> Result<Option<JournalEntry>, JournalError> {
+        let Some(entry) = self.redo_stack.last() else {
+            return Ok(None);
+        };
+
+        apply_changes(config, &entry.before, &entry.after)?;
+        let Some(entry) = self.redo_stack.pop() else {
+            return Ok(None);
+        };
+        if entry.kind.is_roulette() {
+            self.audit(format!("Roulette result restored: {}", entry.description));
+        }
+        self.pending.push(Change::Redo(entry.clone()));
+        self.undo_stack.push(entry.clone());
+        Ok(Some(entry))
+    }
+
+    /// 監査記録を追加
+    pub fn audit(&mut self, message: String) {
+        let entry = AuditEntry {
+            timestamp: now(),
+            message,
+        };
+        self.pending.push(Change::Audit(entry.clone()));
+        self.audit.push(entry);
+    }
+
+    /// 監査記録の一覧
+    pub fn audit_log(&self) -> &[AuditEntry] {
+        &self.audit
+    }
+}
+
+/// `saved`（最後に読み書きした時点の設定）からの`config`の変更を保存する
+///
+/// 別のプロセスが先に保存先を書き換えていた場合（`ConfigError::StaleConfig`）は、
+/// `load`で最新の設定を読み込み直して同じ変更を反映し直す。
+/// 同じ項目が書き換えられていて反映できない場合は、`config`を最新の設定に置き換えて
+/// `SaveOutcome::Lost`を返す
+pub fn save_changes(
+    config: &mut GameConfig,
+    saved: &GameConfig,
+    mut save: impl FnMut(&mut GameConfig) -> Result<(), ConfigError>,
+    load: impl Fn() -> Result<GameConfig, ConfigError>,
+) -> Result<SaveOutcome, JournalError> {
+    let mut base = saved.clone();
+    let mut outcome = SaveOutcome::Saved;
+    loop {
+        match save(config) {
+            Err(ConfigError::StaleConfig) => {
+                let mut latest = load()?;
+                let fresh = latest.clone();
+                match apply_changes(&mut latest, &base, config) {
+                    Ok(()) => {
+                        base = fresh;
+                        *config = latest;
+                        outcome = SaveOutcome::Rebased;
+                    }
+                    Err(JournalError::Conflict(_)) => {
+                        *config = latest;
+                        return Ok(SaveOutcome::Lost);
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            result => {
+                result?;
+                return Ok(outcome);
+            }
+        }
+    }
+}
+
+/// `from`から`to`で変わった項目だけを`config`に反映する
+///
+/// 他の項目（操作の後に変更された無関係な設定など）はそのまま残す。
+/// 反映する項目が`from`の時点から変更されている場合は何もせずエラーを返す
+fn apply_changes(
+    config: &mut GameConfig,
+    from: &GameConfig,
+    to: &GameConfig,
+) -> Result<(), JournalError> {
+    let from = serde_json::to_value(from)?;
+    let to = serde_json::to_value(to)?;
+    let mut current = serde_json::to_value(&*config)?;
+
+    let mut changes = Vec::new();
+    diff(&from, &to, String::new(), &mut changes);
+
+    let conflicts: Vec<&str> = changes
+        .iter()
+        .filter(|(path, _)| current.pointer(path) != from.pointer(path))
+        .map(|(path, _)| path.as_str())
+        .collect();
+    if !conflicts.is_empty() {
+        return Err(JournalError::Conflict(conflicts.join(", ")));
+    }
+
+    for (path, value) in changes {
+        match current.pointer_mut(&path) {
+            Some(slot) => *slot = value,
+            None => return Err(JournalError::Conflict(path)),
+        }
+    }
+
+    let merged: GameConfig = serde_json::from_value(current)?;
+    merged
+        .validate()
+        .map_err(|e| JournalError::Conflict(e.to_string()))?;
+    config.restore_from(&merged);
+    Ok(())
+}
+
+/// 2つの値の異なる箇所（JSONポインタと`to`側の値）を集める
+///
+/// オブジェクトと同じ長さの配列は要素ごとに比べ、それ以外は値全体を1つの変更とする
+fn diff(from: &Value, to: &Value, path: String, changes: &mut Vec<(String, Value)>) {
+    match (from, to) {
+        (Value::Object(a), Value::Object(b)) if a.len() == b.len() && a.keys().eq(b.keys()) => {
+            for (key, value) in a {
+                diff(value, &b[key], format!("{}/{}", path, key), changes);
+            }
+        }
+        (Value::Array(a), Value::Array(b)) if a.len() == b.len() => {
+            for (index, (x, y)) in a.iter().zip(b).enumerate() {
+                diff(x, y, format!("{}/{}", path, index), changes);
+            }
+        }
+        _ if from != to => changes.push((path, to.clone())),
+        _ => {}
+    }
+}
+
+/// 現在時刻（UNIX秒）
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undo_redo() {
+        let mut journal = Journal::default();
+        let mut config = GameConfig::default();
+
+        let before = config.clone();
+        config.change_player_name(1, "太郎".to_string()).unwrap();
+        journal.record(
+            ActionKind::Rename,
+            "rename".to_string(),
+            before,
+            config.clone(),
+        );
+
+        let entry = journal.undo(&mut config, false).unwrap().unwrap();
+        assert_eq!(entry.kind, ActionKind::Rename);
+        assert_eq!(config.get_player(1).unwrap().name, "プレイヤー1");
+        assert!(journal.peek_undo().is_none());
+
+        journal.redo(&mut config).unwrap().unwrap();
+        assert_eq!(config.get_player(1).unwrap().name, "太郎");
+        assert!(journal.peek_redo().is_none());
+        assert!(journal.audit_log().is_empty());
+    }
+
+    #[test]
+    fn test_roulette_undo_requires_confirmation() {
+        let mut journal = Journal::default();
+        let mut config = GameConfig::default();
+
+        let before = config.clone();
+        config.eliminate_player(2).unwrap();
+        journal.record(
+            ActionKind::Roulette,
+            "player 2 out".to_string(),
+            before,
+            config.clone(),
+        );
+
+        // 確認なしでは取り消せない
+        assert!(journal.undo(&mut config, false).unwrap().is_none());
+        assert!(!config.get_player(2).unwrap().is_active);
+        assert!(journal.audit_log().is_empty());
+
+        // 確認すると取り消され、監査記録が残る
+        assert!(journal.undo(&mut config, true).unwrap().is_some());
+        assert!(config.get_player(2).unwrap().is_active);
+        assert_eq!(journal.audit_log().len(), 1);
+    }
+
+    #[test]
+    fn test_undo_keeps_unrelated_changes() {
+        let mut journal = Journal::default();
+        let mut config = GameConfig::default();
+
+        let before = config.clone();
+        config.change_player_name(1, "太郎".to_string()).unwrap();
+        journal.record(
+            ActionKind::Rename,
+            "rename".to_string(),
+            before,
+            config.clone(),
+        );
+
+        // 記録されない設定の変更と、別のプレイヤーの名前の変更
+        config.bullet_capacity = 8;
+        config.change_player_name(2, "花子".to_string()).unwrap();
+
+        journal.undo(&mut config, false).unwrap().unwrap();
+        assert_eq!(config.get_player(1).unwrap().name, "プレイヤー1");
+        assert_eq!(config.get_player(2).unwrap().name, "花子");
+        assert_eq!(config.bullet_capacity, 8);
+
+        journal.redo(&mut config).unwrap().unwrap();
+        assert_eq!(config.get_player(1).unwrap().name, "太郎");
+        assert_eq!(config.bullet_capacity, 8);
+    }
+
+    #[test]
+    fn test_undo_refuses_overwritten_change() {
+        let mut journal = Journal::default();
+        let mut config = GameConfig::default();
+
+        let before = config.clone();
+        config.change_player_name(1, "太郎".to_string()).unwrap();
+        journal.record(
+            ActionKind::Rename,
+            "rename".to_string(),
+            before,
+            config.clone(),
+        );
+
+        // 同じ項目が後から変更されていると取り消さない
+        config.change_player_name(1, "次郎".to_string()).unwrap();
+        assert!(matches!(
+            journal.undo(&mut config, false),
+            Err(JournalError::Conflict(_))
+        ));
+        assert_eq!(config.get_player(1).unwrap().name, "次郎");
+        assert!(journal.peek_undo().is_some());
+        assert!(journal.peek_redo().is_none());
+    }
+
+    #[test]
+    fn test_save_changes_after_stale_read() {
+        let dir = std::env::temp_dir().join(format!("liars_table_journal_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("config.toml");
+        let save = |config: &mut GameConfig| config.save_to(&path);
+        let load = || GameConfig::load_from(&path).map(|(config, _)| config);
+
+        let (mut first, _) = GameConfig::load_from(&path).unwrap();
+        let (mut second, _) = GameConfig::load_from(&path).unwrap();
+        first.change_player_name(1, "太郎".to_string()).unwrap();
+        first.save_to(&path).unwrap();
+
+        // 別の項目の変更は最新の設定に反映し直して保存される
+        let saved = second.clone();
+        second.change_player_name(2, "花子".to_string()).unwrap();
+        let outcome = save_changes(&mut second, &saved, save, load).unwrap();
+        assert_eq!(outcome, SaveOutcome::Rebased);
+        let reloaded = load().unwrap();
+        assert_eq!(reloaded.get_player(1).unwrap().name, "太郎");
+        assert_eq!(reloaded.get_player(2).unwrap().name, "花子");
+
+        // 同じ項目の変更は保存されず、最新の設定に置き換わる
+        let saved = first.clone();
+        first.change_player_name(2, "次郎".to_string()).unwrap();
+        let outcome = save_changes(&mut first, &saved, save, load).unwrap();
+        assert_eq!(outcome, SaveOutcome::Lost);
+        assert_eq!(first.get_player(2).unwrap().name, "花子");
+        assert_eq!(load().unwrap().get_player(2).unwrap().name, "花子");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_save_keeps_actions_recorded_by_others() {
+        let dir =
+            std::env::temp_dir().join(format!("liars_table_journal_save_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("config.journal.json");
+        let config = GameConfig::default();
+
+        // 同じ履歴を読み込んだ2つのプロセスがそれぞれ記録して保存する
+        let mut first = Journal::load_from(&path).unwrap();
+        let mut second = Journal::load_from(&path).unwrap();
+        first.record(
+            ActionKind::Turn,
+            "first".to_string(),
+            config.clone(),
+            config.clone(),
+        );
+        first.save_to(&path).unwrap();
+        second.record(
+            ActionKind::Rename,
+            "second".to_string(),
+            config.clone(),
+            config.clone(),
+        );
+        second.save_to(&path).unwrap();
+
+        let saved = Journal::load_from(&path).unwrap();
+        let descriptions: Vec<&str> = saved
+            .recent(10)
+            .map(|entry| entry.description.as_str())
+            .collect();
+        assert_eq!(descriptions, ["second", "first"]);
+        assert_eq!(second.recent(10).count(), 2);
+
+        // 取り消しは、最新の履歴でも次に取り消される操作の場合だけ反映する
+        let mut undone = config.clone();
+        first.undo(&mut undone, false).unwrap().unwrap();
+        first.save_to(&path).unwrap();
+        let saved = Journal::load_from(&path).unwrap();
+        assert_eq!(saved.peek_undo().unwrap().description, "second");
+        assert!(saved.peek_redo().is_none());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_record_clears_redo() {
+        let mut journal = Journal::default();
+        let mut config = GameConfig::default();
+
+        journal.record(
+            ActionKind::Turn,
+            "turn".to_string(),
+            config.clone(),
+            config.clone(),
+        );
+        journal.undo(&mut config, false).unwrap().unwrap();
+        assert!(journal.peek_redo().is_some());
+
+        journal.record(
+            ActionKind::Turn,
+            "turn".to_string(),
+            config.clone(),
+            config.clone(),
+        );
+        assert!(journal.peek_redo().is_none());
+    }
+}
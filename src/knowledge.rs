@@ -0,0 +1,362 @@
+use crate::card::{Card, CardType};
+use crate::game::{Game, GameError, GameEvent};
+use crate::roulette::RouletteResult;
+use serde::{Deserialize, Serialize};
+
+/// 全員に公開された出来事（伏せたまま通ったカードの中身は含まない）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum PublicEvent {
+    /// 手札の配布（プレイヤーIDと配られた枚数）
+    Dealt { counts: Vec<(u8, usize)> },
+    /// カードを伏せて出し、種類を宣言した（枚数のみ公開）
+    Played {
+        player_id: u8,
+        count: usize,
+        declared_type: CardType,
+    },
+    /// 直前のプレイが受け入れられ、伏せたまま場に積まれた（枚数のみ公開）
+    Accepted {
+        player_id: u8,
+        target_player: u8,
+        count: usize,
+    },
+    /// 直前のプレイが疑われ、出されたカードが公開された
+    Revealed {
+        challenger_id: u8,
+        target_player: u8,
+        cards: Vec<Card>,
+        declared_type: CardType,
+        is_liar: bool,
+    },
+    /// ロシアンルーレットの結果
+    Roulette {
+        player_id: u8,
+        result: RouletteResult,
+    },
+}
+
+impl From<&GameEvent> for PublicEvent {
+    fn from(event: &GameEvent) -> Self {
+        match event {
+            GameEvent::Dealt { hands } => PublicEvent::Dealt {
+                counts: hands.iter().map(|(id, cards)| (*id, cards.len())).collect(),
+            },
+            GameEvent::Played {
+                player_id,
+                cards,
+                declared_type,
+            } => PublicEvent::Played {
+                player_id: *player_id,
+                count: cards.len(),
+                declared_type: *declared_type,
+            },
+            GameEvent::Accepted {
+                player_id,
+                target_player,
+                cards,
+            } => PublicEvent::Accepted {
+                player_id: *player_id,
+                target_player: *target_player,
+                count: cards.len(),
+            },
+            GameEvent::Challenged {
+                challenger_id,
+                target_player,
+                revealed,
+                declared_type,
+                is_liar,
+            } => PublicEvent::Revealed {
+                challenger_id: *challenger_id,
+                target_player: *target_player,
+                cards: revealed.clone(),
+                declared_type: *declared_type,
+                is_liar: *is_liar,
+            },
+            GameEvent::Roulette { player_id, result } => PublicEvent::Roulette {
+                player_id: *player_id,
+                result: result.clone(),
+            },
+        }
+    }
+}
+
+/// 記録（[`Game::history`]）から全員に公開された情報だけを取り出す
+pub fn public_log(game: &Game) -> Vec<PublicEvent> {
+    game.history.iter().map(PublicEvent::from).collect()
+}
+
+/// 1種類のカードの枚数の内訳
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownCount {
+    pub card_type: CardType,
+    /// ゲームのデッキ構成での枚数
+    pub total: usize,
+    /// チャレンジで公開された枚数
+    pub revealed: usize,
+    /// 自分の手札にある枚数
+    pub in_hand: usize,
+    /// 自分が伏せて出し、まだ場にある枚数
+    pub played: usize,
+    /// どこにあるか分からない枚数
+    pub unseen: usize,
+}
+
+/// 公開されたカードと`viewer`の手札・伏せて出したカードから分かる種類ごとの内訳
+/// （`None`は観戦者の視点）
+pub fn known_cards(game: &Game, viewer: Option<u8>) -> Vec<KnownCount> {
+    let hand = viewer
+        .and_then(|id| game.players.iter().find(|p| p.id == id))
+        .map_or(&[][..], |p| p.hand.as_slice());
+    let played = viewer.map_or_else(Vec::new, |id| own_face_down(game, id));
+    let count = |cards: &[Card], card_type: CardType| {
+        cards.iter().filter(|c| c.card_type == card_type).count()
+    };
+
+    [
+        CardType::Ace,
+        CardType::Queen,
+        CardType::King,
+        CardType::Joker,
+    ]
+    .into_iter()
+    .map(|card_type| {
+        let total = game.deck_composition.count(card_type) as usize;
+        let revealed = count(&game.discard, card_type);
+        let in_hand = count(hand, card_type);
+        let played = count(&played, card_type);
+        KnownCount {
+            card_type,
+            total,
+            revealed,
+            in_hand,
+            played,
+            unseen: total.saturating_sub(revealed + in_hand + played),
+        }
+    })
+    .collect()
+}
+
+/// `viewer`がこのラウンドに伏せて出し、公開されずに場（直前のプレイを含む）にあるカード
+fn own_face_down(game: &Game, viewer: u8) -> Vec<Card> {
+    let round_start = game
+        .history
+        .iter()
+        .rposition(|event| matches!(event, GameEvent::Dealt { .. }))
+        .unwrap_or(0);
+    let played: Vec<&Card> = game.history[round_start..]
+        .iter()
+        .flat_map(|event| match event {
+            GameEvent::Played {
+                player_id, cards, ..
+            } if *player_id == viewer => cards.as_slice(),
+            _ => &[],
+        })
+        .collect();
+    game.pile
+        .iter()
+        .chain(game.last_play.iter().flat_map(|play| &play.cards))
+        .filter(|card| played.contains(card))
+        .cloned()
+        .collect()
+}
+
+/// 場に伏せられているプレイ（枚数と宣言のみ）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicPlay {
+    pub player_id: u8,
+    pub count: usize,
+    pub declared_type: CardType,
+}
+
+/// 席ごとの公開情報
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicSeat {
+    pub id: u8,
+    pub name: String,
+    /// 手札の枚数
+    pub cards: usize,
+    pub is_active: bool,
+}
+
+/// 1人のプレイヤーが知り得る情報（ボットの判断の入力に使う）
+///
+/// 他のプレイヤーの手札や、公開されずに通ったカードの中身は含まない
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicView {
+    pub viewer: u8,
+    pub hand: Vec<Card>,
+    pub current_player: u8,
+    pub seats: Vec<PublicSeat>,
+    pub last_play: Option<PublicPlay>,
+    /// 場に伏せて積まれたカードの枚数
+    pub pile: usize,
+    /// 捨て札（公開されたカード）
+    pub discard: Vec<Card>,
+    pub known: Vec<KnownCount>,
+    pub log: Vec<PublicEvent>,
+}
+
+impl PublicView {
+    /// `viewer`から見た情報を作成
+    pub fn new(game: &Game, viewer: u8) -> Result<Self, GameError> {
+        let player = game
+            .players
+            .iter()
+            .find(|p| p.id == viewer)
+            .ok_or(GameError::PlayerNotFound)?;
+
+        Ok(Self {
+            viewer,
+            hand: player.hand.clone(),
+            current_player: game.current_player,
+            seats: game
+                .players
+                .iter()
+                .map(|p| PublicSeat {
+                    id: p.id,
+                    name: p.name.clone(),
+                    cards: p.cards_count(),
+                    is_active: p.is_active,
+                })
+                .collect(),
+            last_play: game.last_play.as_ref().map(|play| PublicPlay {
+                player_id: play.player_id,
+                count: play.cards.len(),
+                declared_type: play.declared_type,
+            }),
+            pile: game.pile.len(),
+            discard: game.discard.clone(),
+            known: known_cards(game, Some(viewer)),
+            log: public_log(game),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng;
+
+    /// 1が出したカードを2が受け入れて出し、3が2のプレイを疑ったゲーム
+    fn challenged_game() -> (Game, Vec<Card>) {
+        let mut game = Game::new(3, 1);
+        game.deal_cards_with(&mut rng::seeded(8)).unwrap();
+        game.play_cards(1, vec![0, 1], CardType::Queen).unwrap();
+        let revealed = game.players[1].hand[..1].to_vec();
+        game.play_cards(2, vec![0], CardType::Queen).unwrap();
+        game.challenge(3).unwrap();
+        (game, revealed)
+    }
+
+    #[test]
+    fn test_public_log_hides_unrevealed_cards() {
+        let (game, revealed) = challenged_game();
+
+        let log = public_log(&game);
+        assert_eq!(log.len(), 5);
+        assert_eq!(
+            log[0],
+            PublicEvent::Dealt {
+                counts: vec![(1, 5), (2, 5), (3, 5)]
+            }
+        );
+        assert_eq!(
+            log[2],
+            PublicEvent::Accepted {
+                player_id: 2,
+                target_player: 1,
+                count: 2
+            }
+        );
+        assert_eq!(
+            log[3],
+            PublicEvent::Played {
+                player_id: 2,
+                count: 1,
+                declared_type: CardType::Queen
+            }
+        );
+        // 公開されるのは直前のプレイだけ
+        assert!(matches!(&log[4], PublicEvent::Revealed { cards, .. } if *cards == revealed));
+    }
+
+    #[test]
+    fn test_known_cards() {
+        let (game, revealed) = challenged_game();
+        assert_eq!(game.discard, revealed);
+
+        let known = known_cards(&game, Some(2));
+        let hand = &game.players[1].hand;
+        for count in &known {
+            assert_eq!(count.total, 4);
+            assert_eq!(
+                count.revealed,
+                revealed
+                    .iter()
+                    .filter(|c| c.card_type == count.card_type)
+                    .count()
+            );
+            assert_eq!(
+                count.in_hand,
+                hand.iter()
+                    .filter(|c| c.card_type == count.card_type)
+                    .count()
+            );
+            assert_eq!(
+                count.revealed + count.in_hand + count.played + count.unseen,
+                4
+            );
+        }
+        // 観戦者は公開されたカードだけを知っている
+        assert!(known_cards(&game, None).iter().all(|c| c.in_hand == 0));
+    }
+
+    #[test]
+    fn test_known_cards_follow_game() {
+        let mut game = Game::new(2, 1);
+        let composition = crate::config::DeckComposition {
+            aces: 6,
+            queens: 2,
+            kings: 2,
+            jokers: 0,
+        };
+        game.set_deck_composition(composition).unwrap();
+        game.deal_cards_with(&mut rng::seeded(3)).unwrap();
+        let totals: Vec<usize> = known_cards(&game, None).iter().map(|c| c.total).collect();
+        assert_eq!(totals, vec![6, 2, 2, 0]);
+
+        // 自分が伏せて出したカードは、受け入れられて場に積まれても分かっている
+        let played = game.players[0].hand[..2].to_vec();
+        game.play_cards(1, vec![0, 1], CardType::Ace).unwrap();
+        game.accept(2).unwrap();
+        let own: usize = known_cards(&game, Some(1)).iter().map(|c| c.played).sum();
+        assert_eq!(own, played.len());
+        let other: usize = known_cards(&game, Some(2)).iter().map(|c| c.played).sum();
+        assert_eq!(other, 0);
+    }
+
+    #[test]
+    fn test_view_excludes_other_hands() {
+        let mut game = Game::new(3, 1);
+        game.deal_cards_with(&mut rng::seeded(8)).unwrap();
+        game.play_cards(1, vec![0, 1], CardType::Queen).unwrap();
+        game.play_cards(2, vec![0, 1], CardType::King).unwrap();
+
+        let view = PublicView::new(&game, 3).unwrap();
+        assert_eq!(view.hand, game.players[2].hand);
+        assert_eq!(view.seats[1].cards, 3);
+        assert_eq!(view.pile, 2);
+        assert_eq!(
+            view.last_play,
+            Some(PublicPlay {
+                player_id: 2,
+                count: 2,
+                declared_type: CardType::King
+            })
+        );
+        let json = serde_json::to_string(&view).unwrap();
+        assert!(json.contains("\"event\":\"accepted\""));
+        assert!(PublicView::new(&game, 9).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+//! Liar's Barにインスパイアされたロシアンルーレットゲームの中核
+//!
+//! `liars_table`バイナリ（対話式UI・全画面ダッシュボード・Web画面）のほか、
+//! Discordボットや配信オーバーレイなど別のアプリケーションからも利用できる。
+//!
+//! # 公開API
+//!
+//! クレート直下に再エクスポートした型と関数が安定した公開APIで、
+//! 0.x の間もパッチバージョンでは互換性を壊さない。
+//! エラー型は`#[non_exhaustive]`のため、バリアントの追加は互換性を壊さない変更として扱う。
+//! モジュールはバイナリ（対話式UI・Web画面など）と共有する実装で、公開APIには含まない。
+//!
+//! - 卓の設定と進行: [`GameConfig`]
+//! - ロシアンルーレット: [`spin`]・[`spin_chambers`]・[`spin_chambers_with`]・[`RouletteConfig`]
+//! - カードゲーム: [`Game`]・[`Deck`]・[`Card`]・[`CardType`]
+//! - 記録したカードゲームの見返し: [`GameEvent`]・[`Replay`]
+//! - 公開された情報（捨て札・宣言の記録、ボットの入力）: [`PublicView`]
+//! - 多言語メッセージ: [`I18nManager`]（ライブラリからは[`I18nManager::bundled`]で作成する）
+//! - 保存先・乱数の差し替え: [`Storage`]・[`MemoryStorage`]・[`RandomSource`]・[`seeded_rng`]
+//! - チャットのコマンド（Discordボットなど）: [`CommandAdapter`]・[`Transport`]・[`ActionRecorder`]
+//! - プレイヤーのプロフィール: [`Profile`]・[`ProfileStore`]
+//! - ファイルの保存先と名前付きの卓（`native`）: [`data_dir`]・[`journal_path`]・[`list_tables`]・[`FileLock`]など
+//!
+//! # 例
+//!
+//! ```
+//! use liars_table::{GameConfig, RouletteResult, seeded_rng, spin_chambers_with};
+//!
+//! let mut config = GameConfig::default();
+//! let outcome = spin_chambers_with(&config.roulette_config(), &mut seeded_rng(1));
+//! config.resolve_roulette(1, &outcome.result).unwrap();
+//!
+//! let alive = config.get_player(1).unwrap().is_active;
+//! assert_eq!(alive, outcome.result == RouletteResult::Safe);
+//! ```
+//!
+//! # フィーチャー
+//!
+//! - `native`（デフォルト）: ファイルへの保存（[`GameConfig::load`]など）と端末・Web画面
+//! - `wasm`: JavaScript向けのAPI（`LiarsTable`）
+//! - `wav`: 効果音ファイルの再生
+
+// プラットフォームに依存しないゲームの中核（`wasm32-unknown-unknown`にもビルドできる）
+// 実装はクレート内に閉じ、公開APIは下の再エクスポートのみ
+mod bot;
+mod card;
+mod config;
+mod game;
+mod i18n;
+mod knowledge;
+mod migration;
+mod player;
+mod preset;
+mod profile;
+mod replay;
+mod rng;
+mod roulette;
+mod storage;
+
+// ファイルへの保存（`native`）
+#[cfg(feature = "native")]
+mod paths;
+#[cfg(feature = "native")]
+mod table;
+
+// JavaScript向けのAPI（`wasm`）
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use bot::{
+    ActionRecorder, BotError, Command, CommandAdapter, RecordedAction, Target, Transport,
+};
+#[cfg(feature = "native")]
+pub use bot::StdioTransport;
+pub use card::{Card, CardType, Deck};
+pub use config::{
+    AnimationSettings, ConfigError, DeckComposition, Elimination, EliminationCause, GameConfig,
+    MAX_ANIMATION_MS, MAX_PLAYERS, MIN_PLAYERS, Placement, Player, RuleToggles, SeatStatus,
+    SoundSettings,
+};
+pub use game::{ChallengeResult, Game, GameError, GameEvent, HAND_SIZE, LastPlay};
+pub use i18n::{I18nError, I18nManager, LanguageInfo};
+pub use knowledge::{
+    KnownCount, PublicEvent, PublicPlay, PublicSeat, PublicView, known_cards, public_log,
+};
+pub use migration::{CURRENT_CONFIG_VERSION, MigrationReport};
+pub use preset::{Preset, PresetError};
+pub use profile::{PROFILE_COLORS, Profile, ProfileError, ProfileStore};
+pub use replay::Replay;
+pub use rng::{Random, RandomSource, default_rng, seeded as seeded_rng};
+pub use roulette::{
+    RouletteConfig, RouletteResult, SpinOutcome, spin, spin_chambers, spin_chambers_with,
+};
+pub use storage::{MemoryStorage, Storage};
+
+// ファイルの保存先と名前付きの卓（`native`）
+#[cfg(feature = "native")]
+pub use paths::{
+    ValueSource, config_path_with_source, data_dir, data_dir_with_source, game_path,
+    has_explicit_config, journal_path, set_config_override, tournament_path,
+};
+#[cfg(feature = "native")]
+pub use storage::{FileLock, FileStorage, backup_file, write_atomic};
+#[cfg(feature = "native")]
+pub use table::{
+    TableError, active as active_table, create as create_table, delete as delete_table,
+    duplicate as duplicate_table, exists as table_exists, is_pinned as is_table_pinned,
+    list as list_tables, pin as pin_table, switch as switch_table,
+    validate_name as validate_table_name,
+};
+#[cfg(feature = "wasm")]
+pub use wasm::LiarsTable;
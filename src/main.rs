@@ -1,24 +1,122 @@
-mod config;
-mod i18n;
+mod animation;
+mod card_game;
 mod interactive;
-mod roulette;
+mod journal;
+mod record;
+mod replay_view;
+mod round;
+mod sound;
+mod spectator;
+mod tournament;
+mod tui;
+mod web;
+
+// ゲームの中核はライブラリクレート（src/lib.rs）にある
+use liars_table::{
+    BotError, CommandAdapter, FileLock, FileStorage, GameConfig, I18nManager, StdioTransport,
+    ValueSource, active_table, config_path_with_source, create_table, data_dir_with_source,
+    delete_table, duplicate_table, game_path, journal_path, list_tables, pin_table,
+    set_config_override, switch_table,
+};
 
 use interactive::{InteractiveError, InteractiveUI};
 use std::env;
+use std::path::PathBuf;
 use std::process;
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
+
+    // 設定ファイルの指定（--config PATH）はどの位置でも受け付ける
+    if let Some(index) = args.iter().position(|a| a == "--config") {
+        let Some(path) = args.get(index + 1).cloned() else {
+            eprintln!("❌ --config には設定ファイルのパスを指定してください");
+            process::exit(1);
+        };
+        set_config_override(path.into());
+        args.drain(index..=index + 1);
+    }
+
+    // 使用する卓の指定（--table NAME）はどの位置でも受け付ける
+    if let Some(index) = args.iter().position(|a| a == "--table") {
+        let Some(name) = args.get(index + 1).cloned() else {
+            eprintln!("❌ --table には卓の名前を指定してください");
+            process::exit(1);
+        };
+        if let Err(e) = pin_table(&name) {
+            eprintln!("❌ エラーが発生しました: {}", e);
+            process::exit(1);
+        }
+        args.drain(index..=index + 1);
+    }
+
+    // 演出の無効化（--no-animation）はどの位置でも受け付ける
+    if let Some(index) = args.iter().position(|a| a == "--no-animation") {
+        animation::set_reduced_motion();
+        args.remove(index);
+    }
+
+    // 配信向けのイベントの書き出し（--spectate TARGET）はどの位置でも受け付ける
+    if let Some(index) = args.iter().position(|a| a == "--spectate") {
+        let Some(target) = args.get(index + 1).cloned() else {
+            eprintln!(
+                "❌ --spectate には書き出し先（ファイル、tcp:ADDR、unix:PATH）を指定してください"
+            );
+            process::exit(1);
+        };
+        if let Err(e) = spectator::start(&target) {
+            eprintln!("❌ エラーが発生しました: {}", e);
+            process::exit(1);
+        }
+        args.drain(index..=index + 1);
+    }
 
     // ヘルプメッセージ
     if args.len() > 1 && (args[1] == "--help" || args[1] == "-h") {
         println!("🎴 Liar's Table v0.0.1");
-        println!("");
+        println!();
         println!("使用方法:");
         println!("  liars_table                 対話式ゲームを開始");
+        println!("  liars_table --tui           全画面ダッシュボードを開始");
+        println!(
+            "  liars_table web [--lan] [--port N | ADDR]  Web画面を開始（既定: 127.0.0.1:7878、--lan でLANから操作可能）"
+        );
+        println!(
+            "  liars_table bot                            標準入力のチャットコマンド（!roulette @名前 など）を処理"
+        );
         println!("  liars_table --test          テストモードで実行（非対話的）");
+        println!("  liars_table undo [--yes]    直前の操作を取り消す");
+        println!("  liars_table redo            取り消した操作をやり直す");
+        println!(
+            "  liars_table card [COMMAND]               カードゲームを遊ぶ（操作ごとに自動保存、次回は続きから）"
+        );
+        println!(
+            "  liars_table replay [FILE]   記録したカードゲームを1手ずつ見返す（全員の手札を表示）"
+        );
+        println!("  liars_table table list      卓の一覧を表示");
+        println!("  liars_table table create NAME            新しい卓を作成");
+        println!("  liars_table table switch NAME            使用する卓を切り替え");
+        println!("  liars_table table delete NAME [--yes]    卓を削除");
+        println!("  liars_table table duplicate FROM NAME    卓を複製");
+        println!("  liars_table --table NAME ...             この実行でだけ指定した卓を使用");
+        println!("  liars_table config show                  実際に使われる設定と出どころを表示");
+        println!("  liars_table --config PATH ...            指定した設定ファイルを使用");
+        println!("  liars_table --no-animation ...           ロシアンルーレットの演出を省略");
+        println!(
+            "  liars_table --spectate TARGET ...        状態の変化をNDJSONで書き出す（ファイル、tcp:ADDR、unix:PATH）"
+        );
+        println!();
+        println!("環境変数:");
+        println!("  LIARS_TABLE_HOME             データディレクトリ（既定: ~/.liars_table）");
+        println!("  LIARS_TABLE_CONFIG           設定ファイルのパス（--config が優先）");
+        println!(
+            "  XDG_CONFIG_HOME              ~/.liars_table がない場合の保存先の親ディレクトリ"
+        );
+        println!("  LIARS_TABLE_LANGUAGE         言語を上書き");
+        println!("  LIARS_TABLE_BULLET_CAPACITY  装弾数を上書き");
+        println!("  LIARS_TABLE_LIVE_ROUNDS      実弾数を上書き");
         println!("  liars_table --help          このヘルプを表示");
-        println!("");
+        println!();
         println!("説明:");
         println!("  Liar's Barにインスパイアされたロシアンルーレットゲーム");
         println!("  プレイヤー同士でロシアンルーレットを楽しめます");
@@ -39,6 +137,112 @@ fn main() {
         }
     }
 
+    // 取り消し・やり直し（undo / redo サブコマンド）
+    if args.len() > 1 && (args[1] == "undo" || args[1] == "redo") {
+        let assume_yes = args.iter().skip(2).any(|a| a == "--yes" || a == "-y");
+        match run_journal_command(&args[1], assume_yes) {
+            Ok(()) => process::exit(0),
+            Err(e) => {
+                eprintln!("❌ エラーが発生しました: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    // 卓の管理（table サブコマンド）
+    if args.len() > 1 && args[1] == "table" {
+        match run_table_command(&args[2..]) {
+            Ok(()) => process::exit(0),
+            Err(e) => {
+                eprintln!("❌ エラーが発生しました: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    // 設定の確認（config show サブコマンド）
+    if args.len() > 1 && args[1] == "config" {
+        if args.get(2).map(String::as_str) != Some("show") {
+            eprintln!("使用方法: liars_table config show");
+            process::exit(1);
+        }
+        match run_config_show() {
+            Ok(()) => process::exit(0),
+            Err(e) => {
+                eprintln!("❌ エラーが発生しました: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    // 全画面ダッシュボード（--tuiフラグ）
+    if args.len() > 1 && args[1] == "--tui" {
+        match tui::run() {
+            Ok(()) => process::exit(0),
+            Err(e) => {
+                eprintln!("❌ エラーが発生しました: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    // Web画面（web サブコマンド）
+    if args.len() > 1 && args[1] == "web" {
+        let options: Vec<&str> = args[2..].iter().map(String::as_str).collect();
+        let lan = options.contains(&"--lan");
+        let options: Vec<&str> = options.into_iter().filter(|a| *a != "--lan").collect();
+        let addr = match web::listen_addr(lan, &options) {
+            Ok(addr) => addr,
+            Err(e) => {
+                eprintln!("❌ {}", e);
+                eprintln!("使用方法: liars_table web [--lan] [--port N | ADDR]");
+                process::exit(1);
+            }
+        };
+        match web::run(&addr, lan) {
+            Ok(()) => process::exit(0),
+            Err(e) => {
+                eprintln!("❌ エラーが発生しました: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    // カードゲーム（card サブコマンド、卓ごとに自動保存して再開）
+    if args.len() > 1 && args[1] == "card" {
+        match card_game::run(&args[2..]) {
+            Ok(()) => process::exit(0),
+            Err(e) => {
+                eprintln!("❌ エラーが発生しました: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    // 記録したカードゲームの見返し（replay サブコマンド）
+    if args.len() > 1 && args[1] == "replay" {
+        // ファイルを省略した場合は卓のカードゲームの保存ファイル
+        let file = args.get(2).map(PathBuf::from).unwrap_or_else(game_path);
+        match replay_view::run(&file) {
+            Ok(()) => process::exit(0),
+            Err(e) => {
+                eprintln!("❌ エラーが発生しました: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    // チャットのコマンド（bot サブコマンド、標準入出力でのお手本）
+    if args.len() > 1 && args[1] == "bot" {
+        match run_bot() {
+            Ok(()) => process::exit(0),
+            Err(e) => {
+                eprintln!("❌ エラーが発生しました: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
     // v0.0.1 対話式UIを起動
     match run_interactive_mode() {
         Ok(()) => {
@@ -65,6 +269,191 @@ fn run_interactive_mode() -> Result<(), InteractiveError> {
     ui.run()
 }
 
+/// 直前の操作を取り消す・やり直す
+///
+/// ロシアンルーレットの結果の取り消しは `--yes` か対話的な確認が必要
+fn run_journal_command(command: &str, assume_yes: bool) -> Result<(), InteractiveError> {
+    let mut config = GameConfig::load()?;
+    let mut journal = journal::Journal::load()?;
+
+    let applied = if command == "undo" {
+        let Some(entry) = journal.peek_undo() else {
+            println!("取り消せる操作がありません");
+            return Ok(());
+        };
+
+        let confirmed_roulette = if entry.kind.is_roulette() && !assume_yes {
+            println!(
+                "⚠️  ロシアンルーレットの結果を取り消します: {}",
+                entry.description
+            );
+            dialoguer::Confirm::new()
+                .with_prompt("本当に取り消しますか？（監査記録に残ります）")
+                .default(false)
+                .interact()
+                .map_err(|e| {
+                    InteractiveError::DialogError(format!("Undo confirmation failed: {}", e))
+                })?
+        } else {
+            assume_yes
+        };
+
+        let undone = journal.undo(&mut config, confirmed_roulette);
+        if let Ok(None) = undone {
+            println!("取り消しを中止しました");
+        }
+        undone
+    } else {
+        let redone = journal.redo(&mut config);
+        if let Ok(None) = redone {
+            println!("やり直せる操作がありません");
+        }
+        redone
+    };
+    let applied = match applied {
+        Err(journal::JournalError::Conflict(fields)) => {
+            println!(
+                "操作の後に同じ項目が変更されているため、中止しました: {}",
+                fields
+            );
+            return Ok(());
+        }
+        applied => applied?,
+    };
+
+    if let Some(entry) = applied {
+        config.save()?;
+        journal.save()?;
+        let verb = if command == "undo" {
+            "取り消しました"
+        } else {
+            "やり直しました"
+        };
+        println!("✅ {}: {}", verb, entry.description);
+    }
+
+    Ok(())
+}
+
+/// 実際に使われる設定（ファイル・環境変数・引数を合わせた結果）と出どころを表示する
+fn run_config_show() -> Result<(), InteractiveError> {
+    let config = GameConfig::load()?;
+
+    let (data_dir, data_dir_source) = data_dir_with_source();
+    let (config_path, config_path_source) = config_path_with_source();
+    println!(
+        "data_dir    = {}  [{}]",
+        data_dir.display(),
+        data_dir_source
+    );
+    println!(
+        "config_path = {}  [{}]",
+        config_path.display(),
+        config_path_source
+    );
+    println!("journal     = {}", journal_path().display());
+    println!("sounds      = {}", sound::sounds_dir().display());
+    println!();
+
+    for value in config.effective_values() {
+        println!("{:<17} = {}  [{}]", value.key, value.value, value.source);
+    }
+
+    Ok(())
+}
+
+/// 標準入力の1行ずつをチャットのコマンドとして使用中の卓に適用する
+fn run_bot() -> Result<(), BotError> {
+    use liars_table::Transport;
+
+    let (config_path, source) = config_path_with_source();
+    let table_name = match source {
+        ValueSource::Table(name) => name,
+        _ => config_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+    };
+
+    // 古い形式・読み取れない設定は、起動時にバックアップを作成してから移行する
+    let (_, report) = GameConfig::load_from(&config_path)?;
+    if let Some(report) = report {
+        eprint!("{}", report);
+    }
+
+    let storage = FileStorage::new(&config_path);
+    let mut adapter = CommandAdapter::new(&table_name, storage, I18nManager::new()?)
+        .with_recorder(record::BotRecorder);
+    let mut transport = StdioTransport;
+    while let Some(message) = transport.receive()? {
+        // 読み込みから保存までの間、他のプロセスによる書き込みを止める
+        let reply = {
+            let _lock = FileLock::acquire(&config_path)?;
+            adapter.handle(&message)?
+        };
+        if let Some(reply) = reply {
+            transport.send(&reply)?;
+        }
+    }
+    Ok(())
+}
+
+/// 名前付きの卓を管理する
+fn run_table_command(args: &[String]) -> Result<(), InteractiveError> {
+    let assume_yes = args.iter().any(|a| a == "--yes" || a == "-y");
+    let args: Vec<&str> = args
+        .iter()
+        .map(String::as_str)
+        .filter(|a| *a != "--yes" && *a != "-y")
+        .collect();
+
+    match args.as_slice() {
+        [] | ["list"] => {
+            let active = active_table();
+            for name in list_tables()? {
+                let marker = if name == active { "*" } else { " " };
+                println!("{} {}", marker, name);
+            }
+        }
+        ["create", name] => {
+            create_table(name)?;
+            println!("✅ 卓を作成しました: {}", name);
+        }
+        ["switch", name] => {
+            switch_table(name)?;
+            println!("✅ 使用する卓を切り替えました: {}", name);
+        }
+        ["duplicate", source, name] => {
+            duplicate_table(source, name)?;
+            println!("✅ 卓を複製しました: {} → {}", source, name);
+        }
+        ["delete", name] => {
+            let confirmed = assume_yes
+                || dialoguer::Confirm::new()
+                    .with_prompt(format!("卓 {} を削除しますか？（元に戻せません）", name))
+                    .default(false)
+                    .interact()
+                    .map_err(|e| {
+                        InteractiveError::DialogError(format!("Delete confirmation failed: {}", e))
+                    })?;
+            if confirmed {
+                delete_table(name)?;
+                println!("✅ 卓を削除しました: {}", name);
+            } else {
+                println!("削除を中止しました");
+            }
+        }
+        _ => {
+            eprintln!(
+                "使用方法: liars_table table [list|create NAME|switch NAME|delete NAME|duplicate FROM NAME]"
+            );
+            process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
 /// テストモードを実行（非対話的）
 fn run_test_mode() -> Result<(), InteractiveError> {
     println!("🧪 テストモードを開始します...");
@@ -74,7 +463,7 @@ fn run_test_mode() -> Result<(), InteractiveError> {
     println!("✅ InteractiveUI初期化成功");
 
     // 設定ファイルのテスト
-    let config = config::GameConfig::load()?;
+    let config = GameConfig::load()?;
     println!("✅ 設定ファイル読み込み成功");
     println!("   - 言語: {}", config.language);
     println!("   - 参加人数: {}人", config.participant_count);
@@ -86,7 +475,7 @@ fn run_test_mode() -> Result<(), InteractiveError> {
     );
 
     // i18nテスト
-    let i18n = i18n::I18nManager::new()?;
+    let i18n = I18nManager::new()?;
     println!("✅ 多言語システム初期化成功");
     println!(
         "   - 利用可能言語: {:?}",
@@ -0,0 +1,562 @@
+use crate::config::{GameConfig, MAX_PLAYERS, MIN_PLAYERS, Player};
+use std::fmt;
+use toml::Value;
+use toml::value::Table;
+
+/// 現在の設定ファイルのスキーマバージョン
+///
+/// - v0: `[game]` / `[players]` セクション形式（`player_count`と`names`配列）
+/// - v1: フラット形式（`version`なし、`chamber_capacity`や参加人数なしの場合あり）
+/// - v2: `version`フィールド付きの形式（除外順は`elimination_order`のID配列）
+/// - v3: 除外をラウンド・理由付きの`eliminations`で記録する現在の形式
+pub const CURRENT_CONFIG_VERSION: u32 = 3;
+
+/// 現在の形式で認識されるトップレベルのキー
+const KNOWN_KEYS: [&str; 15] = [
+    "version",
+    "language",
+    "bullet_capacity",
+    "players",
+    "current_turn",
+    "participant_count",
+    "game_completed",
+    "live_rounds",
+    "deck",
+    "rules",
+    "animation",
+    "sound",
+    "round",
+    "game_number",
+    "eliminations",
+];
+
+/// 設定ファイルの移行・復旧で何が起きたかの報告
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// 読み込んだファイルのスキーマバージョン（読み取れなかった場合は`None`）
+    pub from_version: Option<u32>,
+    /// 引き継いだ・変換した内容
+    pub migrated: Vec<String>,
+    /// 引き継げずに破棄した内容
+    pub discarded: Vec<String>,
+    /// 元のファイルのバックアップ先
+    pub backup_path: Option<std::path::PathBuf>,
+}
+
+impl MigrationReport {
+    /// 設定ファイルを書き換える必要があるか（移行・破棄・復旧のいずれかが発生した）
+    pub fn has_changes(&self) -> bool {
+        self.from_version != Some(CURRENT_CONFIG_VERSION)
+            || !self.migrated.is_empty()
+            || !self.discarded.is_empty()
+    }
+}
+
+impl fmt::Display for MigrationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.from_version {
+            Some(version) => writeln!(
+                f,
+                "⚠️ 設定ファイルを v{} から v{} に移行しました",
+                version, CURRENT_CONFIG_VERSION
+            )?,
+            None => writeln!(
+                f,
+                "⚠️ 設定ファイルを読み取れなかったため、復旧できた値だけを引き継ぎました"
+            )?,
+        }
+        for item in &self.migrated {
+            writeln!(f, "  ✅ 引き継ぎ: {}", item)?;
+        }
+        for item in &self.discarded {
+            writeln!(f, "  ❌ 破棄: {}", item)?;
+        }
+        if let Some(path) = &self.backup_path {
+            writeln!(f, "  💾 元のファイルのバックアップ: {}", path.display())?;
+        }
+        Ok(())
+    }
+}
+
+/// 移行できなかった理由
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MigrationFailure {
+    /// このバージョンより新しい形式（上書きすると情報が失われるため読み込まない）
+    UnsupportedVersion(u32),
+    /// 読み取れない・検証に失敗した（復旧処理に回す）
+    Unreadable(String),
+}
+
+/// 設定ファイルの内容を現在の形式に移行して読み込む
+pub fn migrate(content: &str) -> Result<(GameConfig, MigrationReport), MigrationFailure> {
+    let mut table: Table =
+        toml::from_str(content).map_err(|e| MigrationFailure::Unreadable(e.to_string()))?;
+
+    let version = detect_version(&table)?;
+    let mut report = MigrationReport {
+        from_version: Some(version),
+        ..Default::default()
+    };
+
+    if version < 1 {
+        migrate_v0_to_v1(&mut table, &mut report);
+    }
+    if version < 2 {
+        migrate_v1_to_v2(&mut table, &mut report);
+    }
+    if version < 3 {
+        migrate_v2_to_v3(&mut table, &mut report);
+    }
+
+    // 現在の形式で使われないキーは読み込み時に失われるため報告する
+    let unknown: Vec<String> = table
+        .keys()
+        .filter(|key| !KNOWN_KEYS.contains(&key.as_str()))
+        .cloned()
+        .collect();
+    for key in unknown {
+        if let Some(value) = table.remove(&key) {
+            report.discarded.push(format!("{} = {}", key, value));
+        }
+    }
+
+    let config: GameConfig = Value::Table(table)
+        .try_into()
+        .map_err(|e: toml::de::Error| MigrationFailure::Unreadable(e.to_string()))?;
+    config
+        .validate()
+        .map_err(|e| MigrationFailure::Unreadable(e.to_string()))?;
+
+    Ok((config, report))
+}
+
+/// 読み取れない設定ファイルから、言語・装弾数・プレイヤー名だけでも復旧する
+pub fn salvage(content: &str, reason: &str) -> (GameConfig, MigrationReport) {
+    let mut config = GameConfig::default();
+    let mut report = MigrationReport::default();
+    let reason = reason.lines().next().unwrap_or_default();
+    report.discarded.push(format!("読み取りエラー: {}", reason));
+
+    // TOMLとして読めない場合は`key = value`の行単位で拾える値を探す
+    let table: Table = toml::from_str(content).unwrap_or_else(|_| salvage_lines(content));
+    let game = table.get("game").and_then(Value::as_table);
+    let lookup = |key: &str| table.get(key).or_else(|| game.and_then(|g| g.get(key)));
+
+    if let Some(language) = lookup("language").and_then(Value::as_str) {
+        config.language = language.to_string();
+        report.migrated.push(format!("language = {}", language));
+    }
+
+    let capacity = lookup("bullet_capacity")
+        .or_else(|| lookup("chamber_capacity"))
+        .and_then(Value::as_integer);
+    match capacity {
+        Some(capacity @ 1..=12) => {
+            config.bullet_capacity = capacity as u8;
+            report
+                .migrated
+                .push(format!("bullet_capacity = {}", capacity));
+        }
+        Some(capacity) => report
+            .discarded
+            .push(format!("bullet_capacity = {}", capacity)),
+        None => {}
+    }
+
+    let names = collect_player_names(&table, &mut report);
+    if !names.is_empty() {
+        config.players = seats_from_names(&names);
+        config.participant_count = config.players.len() as u8;
+        config.current_turn = 1;
+        report
+            .migrated
+            .push(format!("players = {}", names.join(", ")));
+    }
+
+    if config.validate().is_err() {
+        // 引き継いだ値の組み合わせが不正な場合はデフォルトに戻す
+        report
+            .discarded
+            .push("復旧した値（検証に失敗したため）".to_string());
+        config = GameConfig::default();
+    }
+
+    (config, report)
+}
+
+/// スキーマバージョンを判定する
+fn detect_version(table: &Table) -> Result<u32, MigrationFailure> {
+    match table.get("version") {
+        Some(Value::Integer(version)) if *version >= 0 => {
+            let version = *version as u32;
+            if version > CURRENT_CONFIG_VERSION {
+                Err(MigrationFailure::UnsupportedVersion(version))
+            } else {
+                Ok(version)
+            }
+        }
+        Some(other) => Err(MigrationFailure::Unreadable(format!(
+            "invalid version: {}",
+            other
+        ))),
+        None if table.contains_key("game") => Ok(0),
+        None => Ok(1),
+    }
+}
+
+/// v0（`[game]` / `[players]`セクション形式）からv1（フラット形式）へ
+fn migrate_v0_to_v1(table: &mut Table, report: &mut MigrationReport) {
+    if let Some(Value::Table(game)) = table.remove("game") {
+        for (key, value) in game {
+            match key.as_str() {
+                "language" | "bullet_capacity" | "chamber_capacity" => {
+                    table.insert(key, value);
+                }
+                "player_count" => {
+                    report
+                        .migrated
+                        .push(format!("game.player_count = {} → participant_count", value));
+                    table.insert("participant_count".to_string(), value);
+                }
+                _ => report.discarded.push(format!("game.{} = {}", key, value)),
+            }
+        }
+    }
+
+    let names = match table.remove("players") {
+        Some(Value::Table(mut players)) => {
+            let names = players.remove("names");
+            for (key, value) in players {
+                report
+                    .discarded
+                    .push(format!("players.{} = {}", key, value));
+            }
+            names
+        }
+        other => other,
+    };
+    let names: Vec<String> = names
+        .as_ref()
+        .and_then(Value::as_array)
+        .map(|names| {
+            names
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let names = fit_seat_count(names, report);
+    report
+        .migrated
+        .push(format!("players.names → {}席", names.len()));
+    let players = seats_from_names(&names);
+
+    // 旧形式の人数が座席数と合わない場合は座席数に揃える
+    let participants = table
+        .get("participant_count")
+        .and_then(Value::as_integer)
+        .unwrap_or(players.len() as i64)
+        .clamp(MIN_PLAYERS as i64, players.len() as i64);
+    table.insert(
+        "participant_count".to_string(),
+        Value::Integer(participants),
+    );
+    for player in players.iter().skip(participants as usize) {
+        // 参加席外のプレイヤーは控え席
+        report.migrated.push(format!("{} → 控え席", player.name));
+    }
+    let players: Vec<Player> = players
+        .into_iter()
+        .enumerate()
+        .map(|(index, mut player)| {
+            player.is_active = (index as i64) < participants;
+            player
+        })
+        .collect();
+    table.insert(
+        "players".to_string(),
+        Value::try_from(players).expect("players are serializable"),
+    );
+    table.insert("current_turn".to_string(), Value::Integer(1));
+}
+
+/// v1（フラット形式）からv2（バージョン付き）へ
+fn migrate_v1_to_v2(table: &mut Table, report: &mut MigrationReport) {
+    if let Some(capacity) = table.remove("chamber_capacity") {
+        if table.contains_key("bullet_capacity") {
+            report
+                .discarded
+                .push(format!("chamber_capacity = {}", capacity));
+        } else {
+            report
+                .migrated
+                .push(format!("chamber_capacity = {} → bullet_capacity", capacity));
+            table.insert("bullet_capacity".to_string(), capacity);
+        }
+    }
+
+    if !table.contains_key("participant_count") {
+        // 参加人数の概念がない形式では全座席が参加していた
+        let seats = table
+            .get("players")
+            .and_then(Value::as_array)
+            .map_or(0, |players| players.len());
+        if seats > 0 {
+            table.insert(
+                "participant_count".to_string(),
+                Value::Integer(seats as i64),
+            );
+            report
+                .migrated
+                .push(format!("participant_count = {}（全座席）", seats));
+        }
+    }
+
+    table.insert("version".to_string(), Value::Integer(2));
+}
+
+/// v2（除外順のID配列）からv3（ラウンド・理由付きの除外記録）へ
+///
+/// v2ではラウンドと理由を記録していないため、順序だけを引き継ぐ
+fn migrate_v2_to_v3(table: &mut Table, report: &mut MigrationReport) {
+    if let Some(order) = table.remove("elimination_order") {
+        let ids: Vec<i64> = order
+            .as_array()
+            .map(|ids| ids.iter().filter_map(Value::as_integer).collect())
+            .unwrap_or_default();
+        let eliminations: Vec<Value> = ids
+            .iter()
+            .map(|&id| {
+                let mut entry = Table::new();
+                entry.insert("player_id".to_string(), Value::Integer(id));
+                Value::Table(entry)
+            })
+            .collect();
+        if !eliminations.is_empty() {
+            report
+                .migrated
+                .push(format!("elimination_order = {} → eliminations", order));
+            table.insert("eliminations".to_string(), Value::Array(eliminations));
+        }
+    }
+
+    table.insert(
+        "version".to_string(),
+        Value::Integer(CURRENT_CONFIG_VERSION as i64),
+    );
+}
+
+/// `players`（v0の`names`配列またはv1以降のプレイヤー配列）から名前を集める
+fn collect_player_names(table: &Table, report: &mut MigrationReport) -> Vec<String> {
+    let entries = match table.get("players") {
+        Some(Value::Table(players)) => players.get("names").and_then(Value::as_array),
+        Some(Value::Array(players)) => Some(players),
+        _ => None,
+    };
+
+    let names = entries
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|entry| match entry {
+                    Value::String(name) => Some(name.as_str()),
+                    Value::Table(player) => player.get("name").and_then(Value::as_str),
+                    _ => None,
+                })
+                .map(str::trim)
+                .filter(|name| !name.is_empty() && name.len() <= 20)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+    fit_seat_count(names, report)
+}
+
+/// 座席数を上限以下に切り詰め、下限に満たない場合はデフォルト名で補う
+fn fit_seat_count(mut names: Vec<String>, report: &mut MigrationReport) -> Vec<String> {
+    if names.is_empty() {
+        return names;
+    }
+    if names.len() > MAX_PLAYERS as usize {
+        for name in names.drain(MAX_PLAYERS as usize..) {
+            report
+                .discarded
+                .push(format!("{}（座席数の上限超過）", name));
+        }
+    }
+    while names.len() < MIN_PLAYERS as usize {
+        names.push(format!("プレイヤー{}", names.len() + 1));
+    }
+    names
+}
+
+/// 名前の一覧から座席順のプレイヤーを作る
+fn seats_from_names(names: &[String]) -> Vec<Player> {
+    names
+        .iter()
+        .enumerate()
+        .map(|(index, name)| Player {
+            id: index as u8 + 1,
+            name: name.clone(),
+            is_active: true,
+            profile_id: None,
+        })
+        .collect()
+}
+
+/// 壊れたTOMLから`key = value`形式で読める行だけを拾う
+fn salvage_lines(content: &str) -> Table {
+    let mut table = Table::new();
+    let mut names = Vec::new();
+    for line in content.lines() {
+        let Ok(parsed) = toml::from_str::<Table>(line) else {
+            continue;
+        };
+        for (key, value) in parsed {
+            match (key.as_str(), value) {
+                ("name" | "names", Value::String(name)) => names.push(Value::String(name)),
+                ("names", Value::Array(list)) => names.extend(list),
+                (_, value) => {
+                    table.entry(key).or_insert(value);
+                }
+            }
+        }
+    }
+    if !names.is_empty() {
+        table.insert("players".to_string(), Value::Array(names));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_version_loads_unchanged() {
+        let config = GameConfig::default();
+        let content = toml::to_string_pretty(&config).unwrap();
+
+        let (loaded, report) = migrate(&content).unwrap();
+        assert_eq!(loaded.players.len(), config.players.len());
+        assert!(!report.has_changes());
+    }
+
+    #[test]
+    fn test_migrate_v0_sectioned_layout() {
+        let content = r#"
+[game]
+language = "en"
+bullet_capacity = 8
+player_count = 2
+
+[players]
+names = ["太郎", "花子", "次郎"]
+"#;
+        let (config, report) = migrate(content).unwrap();
+        assert_eq!(report.from_version, Some(0));
+        assert_eq!(config.version, CURRENT_CONFIG_VERSION);
+        assert_eq!(config.language, "en");
+        assert_eq!(config.bullet_capacity, 8);
+        assert_eq!(config.players[2].name, "次郎");
+        assert_eq!(config.participant_count, 2);
+        assert!(!config.players[2].is_active);
+    }
+
+    #[test]
+    fn test_migrate_v1_flat_layout() {
+        let content = r#"
+language = "ja"
+chamber_capacity = 5
+current_turn = 2
+theme = "dark"
+
+[[players]]
+id = 1
+name = "太郎"
+is_active = true
+
+[[players]]
+id = 2
+name = "花子"
+is_active = true
+
+[[players]]
+id = 3
+name = "次郎"
+is_active = false
+"#;
+        let (config, report) = migrate(content).unwrap();
+        assert_eq!(report.from_version, Some(1));
+        assert_eq!(config.bullet_capacity, 5);
+        assert_eq!(config.participant_count, 3);
+        assert_eq!(config.current_turn, 2);
+        assert!(
+            report
+                .discarded
+                .iter()
+                .any(|item| item.starts_with("theme"))
+        );
+    }
+
+    #[test]
+    fn test_migrate_v2_elimination_order() {
+        let mut config = GameConfig::default();
+        config.players[2].is_active = false;
+        config.players[0].is_active = false;
+        config.current_turn = 2;
+        let mut table: Table = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+        table.insert("version".to_string(), Value::Integer(2));
+        table.remove("eliminations");
+        table.remove("round");
+        table.insert(
+            "elimination_order".to_string(),
+            Value::Array(vec![Value::Integer(3), Value::Integer(1)]),
+        );
+
+        let (config, report) = migrate(&toml::to_string(&table).unwrap()).unwrap();
+        assert_eq!(report.from_version, Some(2));
+        assert_eq!(config.round, 1);
+        let ids: Vec<u8> = config.eliminations.iter().map(|e| e.player_id).collect();
+        assert_eq!(ids, vec![3, 1]);
+        assert!(config.eliminations.iter().all(|e| e.round.is_none()));
+        assert_eq!(config.finishing_order(), vec![2, 4, 1, 3]);
+    }
+
+    #[test]
+    fn test_newer_version_is_rejected() {
+        let content = format!("version = {}\n", CURRENT_CONFIG_VERSION + 1);
+        assert_eq!(
+            migrate(&content).unwrap_err(),
+            MigrationFailure::UnsupportedVersion(CURRENT_CONFIG_VERSION + 1)
+        );
+    }
+
+    #[test]
+    fn test_salvage_keeps_player_names() {
+        let content = r#"
+language = "en"
+bullet_capacity = 7
+[[players]]
+name = "太郎"
+[[players]]
+name = "花子"
+this line is broken
+"#;
+        let reason = match migrate(content) {
+            Err(MigrationFailure::Unreadable(reason)) => reason,
+            other => panic!("unexpected: {:?}", other),
+        };
+
+        let (config, report) = salvage(content, &reason);
+        assert_eq!(config.language, "en");
+        assert_eq!(config.bullet_capacity, 7);
+        assert_eq!(config.players[0].name, "太郎");
+        assert_eq!(config.players[1].name, "花子");
+        assert_eq!(config.participant_count, 2);
+        assert!(report.from_version.is_none());
+        assert!(report.has_changes());
+    }
+}
@@ -0,0 +1,142 @@
+use crate::table;
+use std::env;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+/// データディレクトリを指定する環境変数
+pub const HOME_ENV: &str = "LIARS_TABLE_HOME";
+/// 設定ファイルのパスを指定する環境変数
+pub const CONFIG_ENV: &str = "LIARS_TABLE_CONFIG";
+/// XDG Base Directoryの設定ディレクトリ
+const XDG_CONFIG_ENV: &str = "XDG_CONFIG_HOME";
+
+/// `--config PATH`で指定された設定ファイル
+static CONFIG_OVERRIDE: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// 設定値やパスがどこから来たか
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValueSource {
+    /// 組み込みのデフォルト
+    Default,
+    /// 設定ファイル
+    File(PathBuf),
+    /// 環境変数
+    Env(&'static str),
+    /// コマンドライン引数
+    Flag(&'static str),
+    /// 名前付きの卓
+    Table(String),
+}
+
+impl fmt::Display for ValueSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueSource::Default => write!(f, "default"),
+            ValueSource::File(path) => write!(f, "file {}", path.display()),
+            ValueSource::Env(name) => write!(f, "env {}", name),
+            ValueSource::Flag(flag) => write!(f, "flag {}", flag),
+            ValueSource::Table(name) => write!(f, "table {}", name),
+        }
+    }
+}
+
+/// 設定やプロフィールを保存するディレクトリを取得
+pub fn data_dir() -> PathBuf {
+    data_dir_with_source().0
+}
+
+/// データディレクトリとその決定元
+///
+/// `LIARS_TABLE_HOME`、既存の`~/.liars_table`、`$XDG_CONFIG_HOME/liars_table`、
+/// `~/.liars_table`の順に決まる（ホームディレクトリがない場合は現在のディレクトリ）
+pub fn data_dir_with_source() -> (PathBuf, ValueSource) {
+    if let Some(dir) = env_path(HOME_ENV) {
+        return (dir, ValueSource::Env(HOME_ENV));
+    }
+
+    let Some(home_dir) = dirs::home_dir() else {
+        return (PathBuf::from("."), ValueSource::Default);
+    };
+
+    // 既存のデータがある場合はXDGより優先して使い続ける
+    let legacy_dir = home_dir.join(".liars_table");
+    if !legacy_dir.exists()
+        && let Some(config_home) = env_path(XDG_CONFIG_ENV)
+    {
+        return (
+            config_home.join("liars_table"),
+            ValueSource::Env(XDG_CONFIG_ENV),
+        );
+    }
+    (legacy_dir, ValueSource::Default)
+}
+
+/// `--config PATH`の指定を記録する
+pub fn set_config_override(path: PathBuf) {
+    if let Ok(mut config_override) = CONFIG_OVERRIDE.write() {
+        *config_override = Some(path);
+    }
+}
+
+/// 設定ファイルが明示的に指定されているか（`--config`または`LIARS_TABLE_CONFIG`）
+pub fn has_explicit_config() -> bool {
+    !matches!(config_path_with_source().1, ValueSource::Table(_))
+}
+
+/// 設定ファイルのパスを取得
+pub fn config_path() -> PathBuf {
+    config_path_with_source().0
+}
+
+/// 設定ファイルのパスとその決定元
+///
+/// `--config`、`LIARS_TABLE_CONFIG`、使用中の卓の設定ファイルの順に決まる
+pub fn config_path_with_source() -> (PathBuf, ValueSource) {
+    if let Some(path) = CONFIG_OVERRIDE.read().ok().and_then(|path| path.clone()) {
+        return (path, ValueSource::Flag("--config"));
+    }
+    if let Some(path) = env_path(CONFIG_ENV) {
+        return (path, ValueSource::Env(CONFIG_ENV));
+    }
+
+    let name = table::active();
+    (table::config_path(&name), ValueSource::Table(name))
+}
+
+/// 取り消し履歴ファイルのパスを取得
+///
+/// 設定ファイルが明示的に指定されている場合はその隣（`<名前>.journal.json`）に置く
+pub fn journal_path() -> PathBuf {
+    match config_path_with_source() {
+        (_, ValueSource::Table(name)) => table::journal_path(&name),
+        (path, _) => path.with_extension("journal.json"),
+    }
+}
+
+/// トーナメント記録ファイルのパスを取得
+///
+/// 設定ファイルが明示的に指定されている場合はその隣（`<名前>.tournament.json`）に置く
+pub fn tournament_path() -> PathBuf {
+    match config_path_with_source() {
+        (_, ValueSource::Table(name)) => table::tournament_path(&name),
+        (path, _) => path.with_extension("tournament.json"),
+    }
+}
+
+/// 進行中のカードゲームの保存ファイルのパスを取得
+///
+/// 設定ファイルが明示的に指定されている場合はその隣（`<名前>.game.json`）に置く
+pub fn game_path() -> PathBuf {
+    match config_path_with_source() {
+        (_, ValueSource::Table(name)) => table::game_path(&name),
+        (path, _) => path.with_extension("game.json"),
+    }
+}
+
+/// 空でない環境変数をパスとして取得
+fn env_path(name: &str) -> Option<PathBuf> {
+    env::var_os(name)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
@@ -7,6 +7,9 @@ pub struct Player {
     pub name: String,
     pub hand: Vec<Card>,
     pub is_active: bool,
+    /// 一度でもカードを配られたか（配布前の空の手札を勝利と区別する）
+    #[serde(default)]
+    pub has_been_dealt: bool,
 }
 
 impl Player {
@@ -16,11 +19,13 @@ impl Player {
             name: format!("Player {}", id),
             hand: Vec::new(),
             is_active: true,
+            has_been_dealt: false,
         }
     }
     
     pub fn add_cards(&mut self, cards: Vec<Card>) {
         self.hand.extend(cards);
+        self.has_been_dealt = true;
     }
     
     pub fn remove_cards(&mut self, positions: &[usize]) -> Result<Vec<Card>, String> {
@@ -30,6 +35,11 @@ impl Player {
                 return Err(format!("Invalid card position: {}. Player has {} cards.", pos + 1, self.hand.len()));
             }
         }
+        for (index, &pos) in positions.iter().enumerate() {
+            if positions[..index].contains(&pos) {
+                return Err(format!("Duplicate card position: {}.", pos + 1));
+            }
+        }
         
         // Sort positions in reverse order to remove from back to front
         let mut sorted_positions = positions.to_vec();
@@ -55,7 +65,7 @@ impl Player {
     }
     
     pub fn has_won(&self) -> bool {
-        self.is_active && self.hand.is_empty()
+        self.is_active && self.has_been_dealt && self.hand.is_empty()
     }
 }
 
@@ -113,6 +123,20 @@ mod tests {
         assert!(result.is_err());
     }
     
+    #[test]
+    fn test_remove_duplicate_position() {
+        let mut player = Player::new(1);
+        let cards = vec![
+            Card { card_type: CardType::Ace, id: 1 },
+            Card { card_type: CardType::Queen, id: 2 },
+        ];
+        player.add_cards(cards);
+        
+        // 同じ位置を2回指定しても別のカードを取り出さない
+        assert!(player.remove_cards(&[0, 0]).is_err());
+        assert_eq!(player.hand.len(), 2);
+    }
+    
     #[test]
     fn test_eliminate() {
         let mut player = Player::new(1);
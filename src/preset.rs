@@ -0,0 +1,171 @@
+use crate::config::{DeckComposition, RuleToggles};
+#[cfg(feature = "native")]
+use crate::paths::data_dir;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+#[cfg(feature = "native")]
+use std::fs;
+#[cfg(feature = "native")]
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// バイナリに同梱されるプリセット
+const BUNDLED_PRESETS: [&str; 4] = [
+    include_str!("../presets/classic.toml"),
+    include_str!("../presets/liars_bar.toml"),
+    include_str!("../presets/devil.toml"),
+    include_str!("../presets/hardcore.toml"),
+];
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum PresetError {
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Invalid preset {path}: {message}")]
+    InvalidPreset { path: String, message: String },
+}
+
+/// 装弾数・実弾数・参加人数・デッキ構成・ルールをまとめたゲームモード
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Preset {
+    pub name: String,
+    #[serde(default)]
+    pub description: HashMap<String, String>, // 言語コードごとの説明
+    pub bullet_capacity: u8,
+    #[serde(default = "default_live_rounds")]
+    pub live_rounds: u8,
+    pub participant_count: u8,
+    #[serde(default)]
+    pub deck: DeckComposition,
+    #[serde(default)]
+    pub rules: RuleToggles,
+    #[serde(skip)]
+    pub user_defined: bool, // ユーザーのプリセットファイルから読み込んだか
+}
+
+/// live_roundsのデフォルト値
+fn default_live_rounds() -> u8 {
+    1
+}
+
+impl Preset {
+    /// 指定した言語の説明（なければ英語、それもなければ任意の説明）
+    pub fn description_for(&self, language: &str) -> &str {
+        self.description
+            .get(language)
+            .or_else(|| self.description.get("en"))
+            .or_else(|| self.description.values().next())
+            .map_or("", String::as_str)
+    }
+
+    /// 同梱のプリセット
+    pub fn bundled() -> Vec<Preset> {
+        BUNDLED_PRESETS
+            .iter()
+            .map(|content| toml::from_str(content).expect("bundled presets are valid TOML"))
+            .collect()
+    }
+}
+
+/// ユーザープリセットの読み込み（`native`）
+#[cfg(feature = "native")]
+impl Preset {
+    /// 同梱のプリセットと `~/.liars_table/presets/*.toml` のユーザープリセットを読み込む
+    ///
+    /// 同名のユーザープリセットは同梱のものを置き換える。
+    /// 読み込めないファイルは飛ばし、そのエラーを返す
+    pub fn load_all() -> (Vec<Preset>, Vec<PresetError>) {
+        let mut presets = Self::bundled();
+        let mut errors = Vec::new();
+
+        let user_presets = match Self::load_user_presets() {
+            Ok(user_presets) => user_presets,
+            Err(e) => {
+                errors.push(e);
+                Vec::new()
+            }
+        };
+        for result in user_presets {
+            match result {
+                Ok(preset) => match presets.iter_mut().find(|p| p.name == preset.name) {
+                    Some(existing) => *existing = preset,
+                    None => presets.push(preset),
+                },
+                Err(e) => errors.push(e),
+            }
+        }
+
+        (presets, errors)
+    }
+
+    /// ユーザープリセットのディレクトリ
+    pub fn user_presets_dir() -> PathBuf {
+        data_dir().join("presets")
+    }
+
+    /// ユーザープリセットを名前順に読み込む
+    fn load_user_presets() -> Result<Vec<Result<Preset, PresetError>>, PresetError> {
+        let dir = Self::user_presets_dir();
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut paths: Vec<PathBuf> = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .collect();
+        paths.sort();
+
+        Ok(paths
+            .into_iter()
+            .map(|path| {
+                let invalid = |message: String| PresetError::InvalidPreset {
+                    path: path.display().to_string(),
+                    message,
+                };
+                let content = fs::read_to_string(&path)?;
+                let mut preset: Preset =
+                    toml::from_str(&content).map_err(|e| invalid(e.to_string()))?;
+                preset.user_defined = true;
+                Ok(preset)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::GameConfig;
+
+    #[test]
+    fn test_bundled_presets_apply() {
+        let presets = Preset::bundled();
+        assert_eq!(presets.len(), BUNDLED_PRESETS.len());
+
+        for preset in &presets {
+            let mut config = GameConfig::default();
+            config.apply_preset(preset).unwrap();
+            assert_eq!(config.bullet_capacity, preset.bullet_capacity);
+            assert_eq!(config.live_rounds, preset.live_rounds);
+            assert_eq!(config.participant_count, preset.participant_count);
+            assert_eq!(config.deck, preset.deck);
+            assert!(!preset.description_for("ja").is_empty());
+        }
+    }
+
+    #[test]
+    fn test_invalid_preset_is_not_applied() {
+        let mut preset = Preset::bundled().remove(0);
+        preset.live_rounds = preset.bullet_capacity + 1;
+
+        let mut config = GameConfig::default();
+        config.eliminate_player(2).unwrap();
+        assert!(config.apply_preset(&preset).is_err());
+
+        // 失敗した場合は何も変わらない
+        assert_eq!(config.live_rounds, 1);
+        assert!(!config.get_player(2).unwrap().is_active);
+    }
+}
@@ -0,0 +1,187 @@
+#[cfg(feature = "native")]
+use crate::paths::data_dir;
+#[cfg(feature = "native")]
+use crate::storage;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "native")]
+use std::fs;
+#[cfg(feature = "native")]
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// プロフィールで選択できる色
+pub const PROFILE_COLORS: [&str; 7] =
+    ["red", "green", "yellow", "blue", "magenta", "cyan", "white"];
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum ProfileError {
+    #[error("Profile not found: {0}")]
+    ProfileNotFound(u32),
+    #[error("Invalid profile value: {0}")]
+    InvalidValue(String),
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("TOML parsing error: {0}")]
+    TomlError(#[from] toml::de::Error),
+    #[error("TOML serialization error: {0}")]
+    TomlSerializeError(#[from] toml::ser::Error),
+}
+
+/// 座席に依存しないプレイヤーの保存済みプロフィール
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Profile {
+    pub id: u32,
+    pub name: String,
+    pub color: String,  // PROFILE_COLORSのいずれか
+    pub avatar: String, // 絵文字アバター
+    #[serde(default)]
+    pub language: Option<String>, // 希望言語コード
+}
+
+impl Profile {
+    /// アバター付きの表示名
+    pub fn display_name(&self) -> String {
+        format!("{} {}", self.avatar, self.name)
+    }
+
+    /// プロフィール値の検証
+    fn validate(&self) -> Result<(), ProfileError> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err(ProfileError::InvalidValue(
+                "Profile name cannot be empty".to_string(),
+            ));
+        }
+        if name.len() > 20 {
+            return Err(ProfileError::InvalidValue(
+                "Profile name too long".to_string(),
+            ));
+        }
+        if !PROFILE_COLORS.contains(&self.color.as_str()) {
+            return Err(ProfileError::InvalidValue(format!(
+                "Unknown color: {}",
+                self.color
+            )));
+        }
+        let avatar_chars = self.avatar.trim().chars().count();
+        if avatar_chars == 0 || avatar_chars > 4 {
+            return Err(ProfileError::InvalidValue(
+                "Avatar must be a single emoji".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// `~/.liars_table/profiles.toml` に保存されるプロフィール一覧
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ProfileStore {
+    #[serde(default)]
+    pub profiles: Vec<Profile>,
+}
+
+/// ファイルへの保存（`native`）
+#[cfg(feature = "native")]
+impl ProfileStore {
+    /// プロフィールファイルから読み込む（存在しない場合は空）
+    pub fn load() -> Result<Self, ProfileError> {
+        let path = Self::get_profiles_path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)?;
+        let store: ProfileStore = toml::from_str(&content)?;
+        Ok(store)
+    }
+
+    /// プロフィールファイルに保存する
+    pub fn save(&self) -> Result<(), ProfileError> {
+        let path = Self::get_profiles_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let content = toml::to_string_pretty(self)?;
+        storage::write_atomic(&path, content.as_bytes())?;
+        Ok(())
+    }
+
+    /// プロフィールファイルのパスを取得
+    fn get_profiles_path() -> PathBuf {
+        data_dir().join("profiles.toml")
+    }
+}
+
+impl ProfileStore {
+    /// 新しいプロフィールを追加し、そのIDを返す
+    pub fn add_profile(
+        &mut self,
+        name: &str,
+        color: &str,
+        avatar: &str,
+        language: Option<String>,
+    ) -> Result<u32, ProfileError> {
+        let id = self.profiles.iter().map(|p| p.id).max().unwrap_or(0) + 1;
+        let profile = Profile {
+            id,
+            name: name.trim().to_string(),
+            color: color.to_string(),
+            avatar: avatar.trim().to_string(),
+            language,
+        };
+        profile.validate()?;
+
+        if self.profiles.iter().any(|p| p.name == profile.name) {
+            return Err(ProfileError::InvalidValue(format!(
+                "Profile {} already exists",
+                profile.name
+            )));
+        }
+
+        self.profiles.push(profile);
+        Ok(id)
+    }
+
+    /// プロフィールをIDで検索
+    pub fn get(&self, id: u32) -> Option<&Profile> {
+        self.profiles.iter().find(|p| p.id == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_profile() {
+        let mut store = ProfileStore::default();
+
+        let id = store
+            .add_profile(" 太郎 ", "red", "🦊", Some("ja".to_string()))
+            .unwrap();
+        assert_eq!(id, 1);
+        assert_eq!(store.get(id).unwrap().name, "太郎");
+        assert_eq!(store.get(id).unwrap().display_name(), "🦊 太郎");
+
+        // 同名・不正な値は追加できない
+        assert!(store.add_profile("太郎", "blue", "🐻", None).is_err());
+        assert!(store.add_profile("花子", "purple", "🐻", None).is_err());
+        assert!(store.add_profile("花子", "blue", "", None).is_err());
+
+        assert_eq!(store.add_profile("花子", "blue", "🐻", None).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_profile_store_roundtrip() {
+        let mut store = ProfileStore::default();
+        store
+            .add_profile("太郎", "cyan", "🐸", Some("en".to_string()))
+            .unwrap();
+
+        let content = toml::to_string_pretty(&store).unwrap();
+        let loaded: ProfileStore = toml::from_str(&content).unwrap();
+        assert_eq!(loaded.profiles, store.profiles);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::journal::{ActionKind, Journal, JournalError};
+use crate::spectator;
+use crate::tournament::{Tournament, TournamentError};
+use liars_table::{ActionRecorder, BotError, Command, GameConfig, RecordedAction, SpinOutcome};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+#[allow(clippy::enum_variant_names)]
+pub enum RecordError {
+    #[error("Journal error: {0}")]
+    JournalError(#[from] JournalError),
+    #[error("Tournament error: {0}")]
+    TournamentError(#[from] TournamentError),
+}
+
+/// 保存した操作を観戦画面・取り消し履歴・トーナメントに記録し、更新した取り消し履歴を返す
+///
+/// Web画面とチャットボットの操作で共通。`spin`はロシアンルーレットの対象と結果で、
+/// 勝者が決まった場合はトーナメントの得点に加算する
+pub fn record_saved(
+    kind: ActionKind,
+    message: String,
+    before: GameConfig,
+    after: &GameConfig,
+    spin: Option<(u8, &SpinOutcome)>,
+) -> Result<Journal, RecordError> {
+    if let Some((player_id, outcome)) = spin {
+        spectator::spin_started(&before, player_id);
+        spectator::spin_result(&before, player_id, &outcome.result);
+    }
+    spectator::record_change(&before, after);
+
+    let mut journal = Journal::load()?;
+    journal.record(kind, message, before, after.clone());
+    journal.save()?;
+
+    if kind == ActionKind::Roulette && after.game_completed {
+        Tournament::update(|tournament| tournament.record_game(after))?;
+    }
+    Ok(journal)
+}
+
+/// チャットボット（`bot`サブコマンド）の操作の記録先
+pub struct BotRecorder;
+
+impl ActionRecorder for BotRecorder {
+    fn record(&mut self, action: &RecordedAction<'_>) -> Result<(), BotError> {
+        let kind = match action.command {
+            Command::Roulette(_) => ActionKind::Roulette,
+            Command::Recover(_) => ActionKind::Recovery,
+            Command::Revive(_) => ActionKind::Revive,
+            Command::NewGame => ActionKind::Reset,
+            Command::Status | Command::Help => return Ok(()),
+        };
+        record_saved(
+            kind,
+            action.message.to_string(),
+            action.before.clone(),
+            action.after,
+            action.spin,
+        )
+        .map(|_| ())
+        .map_err(|e| BotError::RecordError(e.to_string()))
+    }
+}
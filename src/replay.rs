@@ -0,0 +1,79 @@
+use crate::game::{Game, GameError, GameEvent};
+
+/// 記録されたカードゲームを1つずつ進めたり戻したりして見返す
+///
+/// 各ステップの状態は記録を始める前の状態から出来事を順に反映して作るため、
+/// 当時は伏せられていた手札もすべて確認できる
+#[derive(Debug, Clone)]
+pub struct Replay {
+    start: Game,
+    events: Vec<GameEvent>,
+}
+
+impl Replay {
+    /// 記録（[`Game::history`]）を持つゲームから作成
+    pub fn new(game: &Game) -> Self {
+        Self {
+            start: game.initial_state(),
+            events: game.history.clone(),
+        }
+    }
+
+    /// 記録された出来事の数（ステップは0から`len()`まで）
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// `step`番目のステップで起きた出来事（0は開始前で出来事なし）
+    pub fn event(&self, step: usize) -> Option<&GameEvent> {
+        step.checked_sub(1).and_then(|index| self.events.get(index))
+    }
+
+    /// `step`番目の出来事までを反映した状態（`len()`を超える場合は最後の状態）
+    ///
+    /// 記録が壊れている場合は`GameError::CorruptState`
+    pub fn state_at(&self, step: usize) -> Result<Game, GameError> {
+        let mut game = self.start.clone();
+        for event in self.events.iter().take(step) {
+            game.apply_event(event)?;
+        }
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::CardType;
+    use crate::rng;
+
+    #[test]
+    fn test_step_forward_and_back() {
+        let mut game = Game::new(2, 1);
+        game.deal_cards_with(&mut rng::seeded(5)).unwrap();
+        let hand = game.players[0].hand.clone();
+        game.play_cards(1, vec![0], CardType::Ace).unwrap();
+        game.challenge(2).unwrap();
+
+        let replay = Replay::new(&game);
+        assert_eq!(replay.len(), 3);
+        assert!(replay.event(0).is_none());
+        assert!(matches!(replay.event(2), Some(GameEvent::Played { .. })));
+
+        // 配布直後は出す前の手札がすべて見える
+        let dealt = replay.state_at(1).unwrap();
+        assert_eq!(dealt.players[0].hand, hand);
+        let played = replay.state_at(2).unwrap();
+        assert_eq!(played.players[0].hand.len(), hand.len() - 1);
+        assert!(played.last_play.is_some());
+        assert!(replay.state_at(3).unwrap().last_play.is_none());
+
+        // 戻っても同じ状態が再現される
+        assert_eq!(replay.state_at(1).unwrap().players[0].hand, hand);
+        assert_eq!(replay.state_at(99).unwrap().history.len(), 3);
+    }
+}
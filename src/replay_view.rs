@@ -0,0 +1,234 @@
+use dialoguer::console::style;
+use liars_table::{
+    Card, Game, GameConfig, GameError, GameEvent, I18nError, I18nManager, Replay, RouletteResult,
+};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+#[allow(clippy::enum_variant_names)]
+pub enum ReplayViewError {
+    #[error("I18n error: {0}")]
+    I18nError(#[from] I18nError),
+    #[error("Game error: {0}")]
+    GameError(#[from] GameError),
+    #[error("JSON error: {0}")]
+    JsonError(#[from] serde_json::Error),
+    #[error("IO error: {0}")]
+    IoError(#[from] io::Error),
+}
+
+/// 入力された操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Control {
+    Next,
+    Back,
+    Jump(usize),
+    Quit,
+}
+
+/// 1行の入力を操作として解釈する（空行は次へ）
+fn parse_control(input: &str) -> Option<Control> {
+    match input.trim().to_lowercase().as_str() {
+        "" | "n" => Some(Control::Next),
+        "b" | "p" => Some(Control::Back),
+        "q" => Some(Control::Quit),
+        other => other.parse().ok().map(Control::Jump),
+    }
+}
+
+/// 操作を反映した次のステップ（範囲外には移動しない）
+fn next_step(step: usize, last: usize, control: Control) -> usize {
+    match control {
+        Control::Next => (step + 1).min(last),
+        Control::Back => step.saturating_sub(1),
+        Control::Jump(target) => target.min(last),
+        Control::Quit => step,
+    }
+}
+
+/// 記録されたカードゲーム（`Game`をJSONで保存したファイル）を1ステップずつ表示する
+pub fn run(file: &Path) -> Result<(), ReplayViewError> {
+    let game: Game = serde_json::from_str(&fs::read_to_string(file)?)?;
+    let replay = Replay::new(&game);
+
+    let mut i18n = I18nManager::new()?;
+    let language = GameConfig::load()
+        .map(|config| config.language)
+        .unwrap_or_default();
+    if i18n.is_language_available(&language) {
+        i18n.load_language(&language)?;
+    }
+
+    let mut args = HashMap::new();
+    args.insert("file".to_string(), file.display().to_string());
+    println!("{}", i18n.get_message_with_args("replay_title", &args)?);
+    if replay.is_empty() {
+        println!("{}", i18n.get_message("replay_no_events")?);
+        return Ok(());
+    }
+
+    let mut step = 0;
+    loop {
+        show_step(&i18n, &replay, step)?;
+
+        print!("{} ", i18n.get_message("replay_controls")?);
+        io::stdout().flush()?;
+        let mut input = String::new();
+        if io::stdin().read_line(&mut input)? == 0 {
+            return Ok(());
+        }
+        match parse_control(&input) {
+            Some(Control::Quit) => return Ok(()),
+            Some(control) => step = next_step(step, replay.len(), control),
+            None => println!("{}", i18n.get_message("error_invalid_input")?),
+        }
+    }
+}
+
+/// ステップの出来事と、その時点の全員の手札を表示する
+fn show_step(i18n: &I18nManager, replay: &Replay, step: usize) -> Result<(), ReplayViewError> {
+    let game = replay.state_at(step)?;
+
+    let mut args = HashMap::new();
+    args.insert("step".to_string(), step.to_string());
+    args.insert("total".to_string(), replay.len().to_string());
+    println!(
+        "\n{}",
+        style(i18n.get_message_with_args("replay_step", &args)?).bold()
+    );
+    println!("{}", describe(i18n, &game, replay.event(step))?);
+
+    for player in &game.players {
+        let mut args = HashMap::new();
+        args.insert("name".to_string(), player.name.clone());
+        args.insert("cards".to_string(), cards_text(&player.hand));
+        let line = i18n.get_message_with_args("replay_hand", &args)?;
+        if !player.is_active {
+            println!(
+                "  {} ({})",
+                style(line).dim(),
+                i18n.get_message("tui_status_out")?
+            );
+        } else if player.id == game.current_player && game.get_winner().is_none() {
+            println!("▶ {}", line);
+        } else {
+            println!("  {}", line);
+        }
+    }
+    if !game.pile.is_empty() {
+        args.insert("cards".to_string(), cards_text(&game.pile));
+        println!("{}", i18n.get_message_with_args("replay_pile", &args)?);
+    }
+    if !game.discard.is_empty() {
+        args.insert("cards".to_string(), cards_text(&game.discard));
+        println!("{}", i18n.get_message_with_args("card_discard", &args)?);
+    }
+
+    if let Some(winner) = game.get_winner().and_then(|id| player_name(&game, id)) {
+        args.insert("name".to_string(), winner);
+        println!(
+            "{}",
+            style(i18n.get_message_with_args("game_winner", &args)?).green()
+        );
+    }
+    Ok(())
+}
+
+/// 出来事の説明
+fn describe(
+    i18n: &I18nManager,
+    game: &Game,
+    event: Option<&GameEvent>,
+) -> Result<String, ReplayViewError> {
+    let name = |id: u8| player_name(game, id).unwrap_or_else(|| id.to_string());
+    let mut args = HashMap::new();
+    let key = match event {
+        None => "replay_start",
+        Some(GameEvent::Dealt { .. }) => "replay_dealt",
+        Some(GameEvent::Played {
+            player_id,
+            cards,
+            declared_type,
+        }) => {
+            args.insert("name".to_string(), name(*player_id));
+            args.insert("count".to_string(), cards.len().to_string());
+            args.insert("rank".to_string(), declared_type.to_string());
+            args.insert("cards".to_string(), cards_text(cards));
+            "replay_played"
+        }
+        Some(GameEvent::Accepted {
+            player_id,
+            target_player,
+            cards,
+        }) => {
+            args.insert("name".to_string(), name(*player_id));
+            args.insert("accused".to_string(), name(*target_player));
+            args.insert("count".to_string(), cards.len().to_string());
+            args.insert("cards".to_string(), cards_text(cards));
+            "replay_accepted"
+        }
+        Some(GameEvent::Challenged {
+            challenger_id,
+            target_player,
+            revealed,
+            is_liar,
+            ..
+        }) => {
+            args.insert("accuser".to_string(), name(*challenger_id));
+            args.insert("accused".to_string(), name(*target_player));
+            args.insert("cards".to_string(), cards_text(revealed));
+            let verdict = if *is_liar {
+                "verdict_liar"
+            } else {
+                "verdict_honest"
+            };
+            args.insert("verdict".to_string(), i18n.get_message(verdict)?);
+            "replay_challenged"
+        }
+        Some(GameEvent::Roulette { player_id, result }) => {
+            args.insert("name".to_string(), name(*player_id));
+            match result {
+                RouletteResult::Safe => "roulette_result_safe",
+                RouletteResult::Out => "roulette_result_out",
+            }
+        }
+    };
+    Ok(i18n.get_message_with_args(key, &args)?)
+}
+
+fn player_name(game: &Game, id: u8) -> Option<String> {
+    game.players
+        .iter()
+        .find(|p| p.id == id)
+        .map(|p| p.name.clone())
+}
+
+/// カードを空白区切りで並べる
+fn cards_text(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .map(Card::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_controls() {
+        assert_eq!(parse_control("\n"), Some(Control::Next));
+        assert_eq!(parse_control(" B "), Some(Control::Back));
+        assert_eq!(parse_control("3"), Some(Control::Jump(3)));
+        assert_eq!(parse_control("x"), None);
+
+        assert_eq!(next_step(0, 4, Control::Back), 0);
+        assert_eq!(next_step(4, 4, Control::Next), 4);
+        assert_eq!(next_step(1, 4, Control::Jump(9)), 4);
+    }
+}
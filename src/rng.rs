@@ -0,0 +1,92 @@
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+/// 乱数の供給元
+///
+/// シャッフルやロシアンルーレットはこのトレイトを実装した任意の乱数を受け取るため、
+/// テストや再現したい場面では`seeded`の乱数に差し替えられる
+pub trait RandomSource {
+    /// 32ビットの乱数
+    fn next_u32(&mut self) -> u32;
+    /// 64ビットの乱数
+    fn next_u64(&mut self) -> u64;
+}
+
+impl<R: RandomSource + ?Sized> RandomSource for Box<R> {
+    fn next_u32(&mut self) -> u32 {
+        (**self).next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+/// このクレートの乱数（[`default_rng`]・[`seeded`]で作成する）
+#[derive(Debug, Clone)]
+pub struct Random(StdRng);
+
+impl RandomSource for Random {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+}
+
+/// 既定の乱数（OSの乱数で初期化。WebAssemblyではJSの`crypto.getRandomValues`）
+pub fn default_rng() -> Random {
+    Random(StdRng::from_entropy())
+}
+
+/// シードから作る再現可能な乱数
+pub fn seeded(seed: u64) -> Random {
+    Random(StdRng::seed_from_u64(seed))
+}
+
+/// `rand`のシャッフルや範囲指定に渡すための包み
+pub(crate) struct Adapter<'a, R: RandomSource + ?Sized>(&'a mut R);
+
+/// 任意の乱数を`rand`のアルゴリズムで使えるようにする
+pub(crate) fn adapt<R: RandomSource + ?Sized>(rng: &mut R) -> Adapter<'_, R> {
+    Adapter(rng)
+}
+
+impl<R: RandomSource + ?Sized> RngCore for Adapter<'_, R> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.0.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_seeded_is_reproducible() {
+        let mut first = seeded(7);
+        let mut second = seeded(7);
+        let a: Vec<u32> = (0..8).map(|_| adapt(&mut first).gen_range(0..100)).collect();
+        let b: Vec<u32> = (0..8).map(|_| adapt(&mut second).gen_range(0..100)).collect();
+        assert_eq!(a, b);
+    }
+}
@@ -1,8 +1,10 @@
+use crate::rng::{self, RandomSource};
 use rand::Rng;
-use rand::thread_rng;
+use rand::seq::index::sample;
+use serde::{Deserialize, Serialize};
 
 /// ロシアンルーレットの結果
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RouletteResult {
     /// セーフ - 実弾に当たらなかった
     Safe,
@@ -25,8 +27,11 @@ pub enum RouletteResult {
 /// - 実弾数は常に1発固定
 /// - 確率は 1/bullet_capacity
 /// - 標準設定では 1/6 = 約16.7%の確率でOut
+#[deprecated(note = "実弾数を設定できる`spin`を使ってください")]
+#[allow(dead_code)] // 旧API（テストでのみ使用）
 pub fn execute_roulette(bullet_capacity: u8) -> RouletteResult {
-    let mut rng = thread_rng();
+    let mut rng = rng::default_rng();
+    let mut rng = rng::adapt(&mut rng);
 
     // 1 から bullet_capacity までの数値をランダムに選択
     // 1が実弾の位置とする（任意の1つの位置でも同じ確率）
@@ -39,6 +44,92 @@ pub fn execute_roulette(bullet_capacity: u8) -> RouletteResult {
     }
 }
 
+/// 実弾数を指定できるロシアンルーレット設定（カードゲームエンジン用）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouletteConfig {
+    /// 装弾数（シリンダー容量）
+    pub chambers: u8,
+    /// 実弾数
+    pub loaded_bullets: u8,
+}
+
+impl RouletteConfig {
+    /// 標準の6発シリンダーに指定数の実弾を込めた設定を作成
+    pub fn new(loaded_bullets: u8) -> Self {
+        Self {
+            chambers: 6,
+            loaded_bullets,
+        }
+    }
+
+    /// アウトになる確率（0.0 - 1.0）
+    pub fn probability(&self) -> f64 {
+        if self.chambers == 0 {
+            0.0
+        } else {
+            self.loaded_bullets.min(self.chambers) as f64 / self.chambers as f64
+        }
+    }
+}
+
+impl Default for RouletteConfig {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+/// シリンダーを回した結果（演出用に実弾と止まった位置も含む）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpinOutcome {
+    /// 実弾が込められた位置（0から）
+    pub loaded_chambers: Vec<usize>,
+    /// 止まった位置（0から）
+    pub chamber: usize,
+    pub result: RouletteResult,
+}
+
+/// 設定に従ってシリンダーを回す
+///
+/// 実弾の位置をランダムに決め、止まった位置が実弾ならOut
+pub fn spin(config: &RouletteConfig) -> RouletteResult {
+    spin_chambers(config).result
+}
+
+/// 設定に従ってシリンダーを回し、実弾と止まった位置を返す
+pub fn spin_chambers(config: &RouletteConfig) -> SpinOutcome {
+    spin_chambers_with(config, &mut rng::default_rng())
+}
+
+/// 指定した乱数でシリンダーを回す
+pub fn spin_chambers_with<R: RandomSource + ?Sized>(
+    config: &RouletteConfig,
+    rng: &mut R,
+) -> SpinOutcome {
+    if config.chambers == 0 {
+        return SpinOutcome {
+            loaded_chambers: Vec::new(),
+            chamber: 0,
+            result: RouletteResult::Safe,
+        };
+    }
+
+    let mut rng = rng::adapt(rng);
+    let loaded = config.loaded_bullets.min(config.chambers) as usize;
+    let loaded_chambers = sample(&mut rng, config.chambers as usize, loaded).into_vec();
+    let chamber = rng.gen_range(0..config.chambers as usize);
+
+    let result = if loaded_chambers.contains(&chamber) {
+        RouletteResult::Out
+    } else {
+        RouletteResult::Safe
+    };
+    SpinOutcome {
+        loaded_chambers,
+        chamber,
+        result,
+    }
+}
+
 /// ロシアンルーレットの確率を計算（0.0 - 1.0）
 #[allow(dead_code)] // 将来のバージョンで使用予定
 pub fn calculate_probability(bullet_capacity: u8) -> f64 {
@@ -60,6 +151,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(deprecated)]
     fn test_execute_roulette() {
         // 複数回実行して両方の結果が出ることを確認
         let mut safe_count = 0;
@@ -96,6 +188,52 @@ mod tests {
     }
 
     #[test]
+    fn test_roulette_config() {
+        let config = RouletteConfig::new(2);
+        assert_eq!(config.chambers, 6);
+        assert_eq!(config.loaded_bullets, 2);
+        assert_eq!(config.probability(), 2.0 / 6.0);
+        assert_eq!(RouletteConfig::default().loaded_bullets, 1);
+    }
+
+    #[test]
+    fn test_spin_all_or_no_bullets() {
+        let full = RouletteConfig {
+            chambers: 6,
+            loaded_bullets: 6,
+        };
+        let empty = RouletteConfig {
+            chambers: 6,
+            loaded_bullets: 0,
+        };
+        for _ in 0..100 {
+            assert_eq!(spin(&full), RouletteResult::Out);
+            assert_eq!(spin(&empty), RouletteResult::Safe);
+        }
+    }
+
+    #[test]
+    fn test_spin_chambers_matches_result() {
+        let config = RouletteConfig::new(2);
+        for _ in 0..100 {
+            let outcome = spin_chambers(&config);
+            assert_eq!(outcome.loaded_chambers.len(), 2);
+            assert!(outcome.chamber < 6);
+            let hit = outcome.loaded_chambers.contains(&outcome.chamber);
+            assert_eq!(outcome.result == RouletteResult::Out, hit);
+        }
+    }
+
+    #[test]
+    fn test_spin_chambers_with_seed_is_reproducible() {
+        let config = RouletteConfig::new(2);
+        let first = spin_chambers_with(&config, &mut rng::seeded(42));
+        let second = spin_chambers_with(&config, &mut rng::seeded(42));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    #[allow(deprecated)]
     fn test_extreme_cases() {
         // 装弾数1の場合は必ずOut
         for _ in 0..10 {
@@ -0,0 +1,106 @@
+use liars_table::{CardType, RuleToggles};
+
+/// チャレンジ（Liar宣言）で公開されたカードの判定結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChallengeOutcome {
+    /// 宣言は嘘だった
+    Liar,
+    /// 宣言は正直だった
+    Honest,
+}
+
+/// 進行中のチャレンジ（誰が誰を疑ったか）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Challenge {
+    /// Liarと宣言したプレイヤーID（現在のターンのプレイヤー）
+    pub accuser: u8,
+    /// 疑われたプレイヤーID（直前にカードを出したプレイヤー）
+    pub accused: u8,
+}
+
+impl Challenge {
+    pub fn new(accuser: u8, accused: u8) -> Self {
+        Self { accuser, accused }
+    }
+
+    /// 判定結果からロシアンルーレットを行うプレイヤーIDを決める
+    ///
+    /// - 嘘だった場合: カードを出したプレイヤー
+    /// - 正直だった場合: 指摘したプレイヤー
+    pub fn loser(&self, outcome: ChallengeOutcome) -> u8 {
+        match outcome {
+            ChallengeOutcome::Liar => self.accused,
+            ChallengeOutcome::Honest => self.accuser,
+        }
+    }
+}
+
+/// 物理カードで伏せて出されたプレイの記録
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeclaredPlay {
+    /// カードを出したプレイヤーID
+    pub player: u8,
+    /// 出した枚数
+    pub count: usize,
+    /// 宣言した種類
+    pub declared: CardType,
+}
+
+impl DeclaredPlay {
+    pub fn new(player: u8, count: usize, declared: CardType) -> Self {
+        Self {
+            player,
+            count,
+            declared,
+        }
+    }
+
+    /// 公開されたカードから判定する
+    ///
+    /// `Game::challenge`と同じく[`RuleToggles::is_honest_play`]で判定する（`jokers_wild`が無効ならJOKERは嘘）。
+    /// 公開された枚数が出した枚数と一致しない場合は`None`
+    pub fn judge(&self, revealed: &[CardType], jokers_wild: bool) -> Option<ChallengeOutcome> {
+        if revealed.len() != self.count {
+            return None;
+        }
+
+        let rules = RuleToggles { jokers_wild };
+        if rules.is_honest_play(revealed.iter().copied(), self.declared) {
+            Some(ChallengeOutcome::Honest)
+        } else {
+            Some(ChallengeOutcome::Liar)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_challenge_loser() {
+        let challenge = Challenge::new(2, 1);
+        assert_eq!(challenge.loser(ChallengeOutcome::Liar), 1);
+        assert_eq!(challenge.loser(ChallengeOutcome::Honest), 2);
+    }
+
+    #[test]
+    fn test_declared_play_judge() {
+        let play = DeclaredPlay::new(1, 2, CardType::King);
+        assert_eq!(
+            play.judge(&[CardType::King, CardType::Joker], true),
+            Some(ChallengeOutcome::Honest)
+        );
+        assert_eq!(
+            play.judge(&[CardType::King, CardType::Ace], true),
+            Some(ChallengeOutcome::Liar)
+        );
+        assert_eq!(play.judge(&[CardType::King], true), None);
+
+        // JOKERが万能でないルールではJOKERは嘘になる
+        assert_eq!(
+            play.judge(&[CardType::King, CardType::Joker], false),
+            Some(ChallengeOutcome::Liar)
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use liars_table::{SoundSettings, data_dir};
+use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SoundError {
+    #[error("IO error: {0}")]
+    IoError(#[from] io::Error),
+    #[cfg(feature = "wav")]
+    #[error("Audio output error: {0}")]
+    OutputError(String),
+}
+
+/// 効果音の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoundEffect {
+    /// シリンダーを回す音
+    Spin,
+    /// 空砲（セーフ）
+    Click,
+    /// 銃声（アウト）
+    Bang,
+}
+
+impl SoundEffect {
+    /// `~/.liars_table/sounds/`に置く効果音ファイルの名前
+    #[cfg_attr(not(feature = "wav"), allow(dead_code))] // WAV再生でのみ使用
+    pub fn file_name(self) -> &'static str {
+        match self {
+            SoundEffect::Spin => "spin.wav",
+            SoundEffect::Click => "click.wav",
+            SoundEffect::Bang => "bang.wav",
+        }
+    }
+
+    /// 端末のベルで代用する場合に鳴らす回数（回転音は鳴らさない）
+    fn bell_count(self) -> usize {
+        match self {
+            SoundEffect::Spin => 0,
+            SoundEffect::Click => 1,
+            SoundEffect::Bang => 2,
+        }
+    }
+}
+
+/// 効果音を鳴らす仕組み（端末のベル、WAV再生など）
+pub trait AudioBackend {
+    fn play(&self, effect: SoundEffect) -> Result<(), SoundError>;
+}
+
+/// 端末のベル（BEL文字）で効果音を代用する
+#[derive(Debug, Default)]
+pub struct BellBackend;
+
+impl AudioBackend for BellBackend {
+    fn play(&self, effect: SoundEffect) -> Result<(), SoundError> {
+        // 出力が端末でない場合（スクリプトやテスト）は制御文字を混ぜない
+        let mut stdout = io::stdout();
+        if !stdout.is_terminal() {
+            return Ok(());
+        }
+        for _ in 0..effect.bell_count() {
+            stdout.write_all(b"\x07")?;
+        }
+        stdout.flush()?;
+        Ok(())
+    }
+}
+
+/// 効果音ファイル（WAV）を再生する（`--features wav`）
+///
+/// ファイルがない効果音は端末のベルで代用する
+#[cfg(feature = "wav")]
+pub struct WavBackend {
+    _stream: rodio::OutputStream, // 再生中はストリームを保持する必要がある
+    handle: rodio::OutputStreamHandle,
+    dir: PathBuf,
+    fallback: BellBackend,
+}
+
+#[cfg(feature = "wav")]
+impl WavBackend {
+    /// 既定の出力デバイスを開く
+    pub fn new(dir: PathBuf) -> Result<Self, SoundError> {
+        let (stream, handle) = rodio::OutputStream::try_default()
+            .map_err(|e| SoundError::OutputError(e.to_string()))?;
+        Ok(Self {
+            _stream: stream,
+            handle,
+            dir,
+            fallback: BellBackend,
+        })
+    }
+}
+
+#[cfg(feature = "wav")]
+impl AudioBackend for WavBackend {
+    fn play(&self, effect: SoundEffect) -> Result<(), SoundError> {
+        let path = self.dir.join(effect.file_name());
+        if !path.exists() {
+            return self.fallback.play(effect);
+        }
+
+        let file = io::BufReader::new(std::fs::File::open(path)?);
+        let source =
+            rodio::Decoder::new(file).map_err(|e| SoundError::OutputError(e.to_string()))?;
+        let sink = rodio::Sink::try_new(&self.handle)
+            .map_err(|e| SoundError::OutputError(e.to_string()))?;
+        // 再生の終了を待たずにゲームを進める
+        sink.append(source);
+        sink.detach();
+        Ok(())
+    }
+}
+
+/// ユーザーが効果音ファイルを置くディレクトリ（`~/.liars_table/sounds`）
+pub fn sounds_dir() -> PathBuf {
+    data_dir().join("sounds")
+}
+
+/// 設定に従って効果音を鳴らす
+pub struct SoundPlayer {
+    backend: Option<Box<dyn AudioBackend>>, // ミュート中は`None`
+}
+
+impl SoundPlayer {
+    /// 設定に合った再生方法を選ぶ（WAVを再生できない場合は端末のベル）
+    pub fn new(settings: &SoundSettings) -> Self {
+        if settings.muted {
+            return Self { backend: None };
+        }
+
+        #[cfg(feature = "wav")]
+        if let Ok(backend) = WavBackend::new(sounds_dir()) {
+            return Self::with_backend(Box::new(backend));
+        }
+        Self::with_backend(Box::new(BellBackend))
+    }
+
+    /// 再生方法を指定して作成
+    pub fn with_backend(backend: Box<dyn AudioBackend>) -> Self {
+        Self {
+            backend: Some(backend),
+        }
+    }
+
+    /// 効果音を鳴らす（鳴らせなかった場合もゲームは続ける）
+    pub fn play(&self, effect: SoundEffect) {
+        if let Some(backend) = &self.backend {
+            let _ = backend.play(effect);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// 鳴らした効果音を記録する再生方法
+    struct RecordingBackend(Rc<RefCell<Vec<SoundEffect>>>);
+
+    impl AudioBackend for RecordingBackend {
+        fn play(&self, effect: SoundEffect) -> Result<(), SoundError> {
+            self.0.borrow_mut().push(effect);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_player_uses_backend() {
+        let played = Rc::new(RefCell::new(Vec::new()));
+        let player = SoundPlayer::with_backend(Box::new(RecordingBackend(played.clone())));
+        player.play(SoundEffect::Spin);
+        player.play(SoundEffect::Bang);
+        assert_eq!(*played.borrow(), vec![SoundEffect::Spin, SoundEffect::Bang]);
+    }
+
+    #[test]
+    fn test_muted_player_is_silent() {
+        let player = SoundPlayer::new(&SoundSettings { muted: true });
+        assert!(player.backend.is_none());
+        assert_eq!(SoundEffect::Bang.file_name(), "bang.wav");
+    }
+}
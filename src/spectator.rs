@@ -0,0 +1,418 @@
+use liars_table::{EliminationCause, GameConfig, RouletteResult, SeatStatus};
+use serde::Serialize;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SpectatorError {
+    #[error("IO error: {0}")]
+    IoError(#[from] io::Error),
+}
+
+/// `--spectate`で開いた出力先（このプロセスでだけ有効）
+static SPECTATOR: Mutex<Option<Hub>> = Mutex::new(None);
+
+/// 配信オーバーレイ向けに書き出す状態の変化（1行1イベントのJSON）
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum SpectatorEvent {
+    /// 新しいゲームの開始
+    NewGame,
+    /// ターンの移動
+    TurnChanged { player_id: u8, name: String },
+    /// ロシアンルーレットの開始
+    SpinStarted {
+        player_id: u8,
+        name: String,
+        live_rounds: u8,
+        bullet_capacity: u8,
+        probability: f64,
+    },
+    /// ロシアンルーレットの結果（`"safe"`・`"out"`）
+    SpinResult {
+        player_id: u8,
+        name: String,
+        result: &'static str,
+    },
+    /// プレイヤーの除外
+    Eliminated {
+        player_id: u8,
+        name: String,
+        cause: EliminationCause,
+    },
+    /// 除外されたプレイヤーの回復
+    Recovered { player_id: u8, name: String },
+    /// 控え席のプレイヤーの蘇生
+    Revived { player_id: u8, name: String },
+    /// 勝者の決定
+    Winner { player_id: u8, name: String },
+    /// 変化のあとの卓全体の状態（オーバーレイはこれだけで描き直せる）
+    State {
+        round: u32,
+        current_turn: u8,
+        game_completed: bool,
+        seats: Vec<SeatView>,
+    },
+}
+
+/// `State`イベントの座席
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SeatView {
+    pub id: u8,
+    pub name: String,
+    pub status: &'static str,
+}
+
+/// 書き出す1行（時刻とイベント）
+#[derive(Serialize)]
+struct Line<'a> {
+    time_ms: u64,
+    #[serde(flatten)]
+    event: &'a SpectatorEvent,
+}
+
+/// 書き出し先（ファイル・名前付きパイプ・接続中のソケット）と、
+/// 途中から接続したクライアントに最初に送る直近の状態
+#[derive(Default)]
+struct Outputs {
+    writers: Vec<Box<dyn Write + Send>>,
+    last_state: Option<String>,
+}
+
+/// 出力先の共有状態（ソケットの接続を受け付けるスレッドからも追加される）
+struct Hub {
+    outputs: Arc<Mutex<Outputs>>,
+}
+
+/// 出力先を開いて書き出しを開始する（`--spectate TARGET`）
+///
+/// - `tcp:ADDR`: TCPで待ち受け、接続したクライアントに送る（例: `tcp:127.0.0.1:7879`）
+/// - `unix:PATH`: Unixドメインソケットで待ち受ける（Unix系のみ）
+/// - それ以外: ファイルや名前付きパイプに追記する（パイプは読み手が開くまで待つ）
+pub fn start(target: &str) -> Result<(), SpectatorError> {
+    let outputs = Arc::new(Mutex::new(Outputs::default()));
+
+    if let Some(addr) = target.strip_prefix("tcp:") {
+        let listener = TcpListener::bind(addr)?;
+        accept_clients(Arc::clone(&outputs), move || {
+            listener.accept().map(|(stream, _)| stream)
+        });
+    } else if let Some(path) = target.strip_prefix("unix:") {
+        listen_unix(path, Arc::clone(&outputs))?;
+    } else {
+        let file = OpenOptions::new().create(true).append(true).open(target)?;
+        lock(&outputs).writers.push(Box::new(file));
+    }
+
+    if let Ok(mut spectator) = SPECTATOR.lock() {
+        *spectator = Some(Hub { outputs });
+    }
+    Ok(())
+}
+
+/// Unixドメインソケットで待ち受ける
+#[cfg(unix)]
+fn listen_unix(path: &str, outputs: Arc<Mutex<Outputs>>) -> Result<(), SpectatorError> {
+    use std::os::unix::net::UnixListener;
+
+    // 前回の実行で残ったソケットファイルは作り直す
+    let _ = std::fs::remove_file(path);
+    let listener = UnixListener::bind(path)?;
+    accept_clients(outputs, move || listener.accept().map(|(stream, _)| stream));
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn listen_unix(_path: &str, _outputs: Arc<Mutex<Outputs>>) -> Result<(), SpectatorError> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "unix: sockets are not supported on this platform",
+    )
+    .into())
+}
+
+/// 接続を受け付けるスレッドを開始し、接続したクライアントには直近の状態を送ってから追加する
+fn accept_clients<S, F>(outputs: Arc<Mutex<Outputs>>, mut accept: F)
+where
+    S: Write + Send + 'static,
+    F: FnMut() -> io::Result<S> + Send + 'static,
+{
+    thread::spawn(move || {
+        while let Ok(mut stream) = accept() {
+            let mut outputs = lock(&outputs);
+            if let Some(state) = &outputs.last_state
+                && stream.write_all(state.as_bytes()).is_err()
+            {
+                continue;
+            }
+            outputs.writers.push(Box::new(stream));
+        }
+    });
+}
+
+fn lock(outputs: &Mutex<Outputs>) -> std::sync::MutexGuard<'_, Outputs> {
+    outputs.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// 書き出しが有効か（無効な場合はイベントを組み立てない）
+fn is_enabled() -> bool {
+    SPECTATOR.lock().is_ok_and(|spectator| spectator.is_some())
+}
+
+/// 現在の状態を書き出す（起動時に最初の画面を描くため）
+pub fn snapshot(config: &GameConfig) {
+    if is_enabled() {
+        emit(&[state(config)]);
+    }
+}
+
+/// ロシアンルーレットの開始を書き出す
+pub fn spin_started(config: &GameConfig, player_id: u8) {
+    if !is_enabled() {
+        return;
+    }
+    emit(&[SpectatorEvent::SpinStarted {
+        player_id,
+        name: player_name(config, player_id),
+        live_rounds: config.live_rounds,
+        bullet_capacity: config.bullet_capacity,
+        probability: config.roulette_probability_percentage(),
+    }]);
+}
+
+/// ロシアンルーレットの結果を書き出す（除外やターンの移動は`record_change`で書き出す）
+pub fn spin_result(config: &GameConfig, player_id: u8, result: &RouletteResult) {
+    if !is_enabled() {
+        return;
+    }
+    emit(&[SpectatorEvent::SpinResult {
+        player_id,
+        name: player_name(config, player_id),
+        result: match result {
+            RouletteResult::Safe => "safe",
+            RouletteResult::Out => "out",
+        },
+    }]);
+}
+
+/// 操作の前後の差分と、変化のあとの状態を書き出す
+pub fn record_change(before: &GameConfig, after: &GameConfig) {
+    if !is_enabled() {
+        return;
+    }
+    let mut events = events_between(before, after);
+    events.push(state(after));
+    emit(&events);
+}
+
+/// すべての出力先に書き出す（書き込めなくなった出力先は外す）
+fn emit(events: &[SpectatorEvent]) {
+    let Ok(spectator) = SPECTATOR.lock() else {
+        return;
+    };
+    let Some(hub) = spectator.as_ref() else {
+        return;
+    };
+
+    let time_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0);
+    let mut outputs = lock(&hub.outputs);
+    for event in events {
+        let Ok(mut line) = serde_json::to_string(&Line { time_ms, event }) else {
+            continue;
+        };
+        line.push('\n');
+        outputs.writers.retain_mut(|writer| {
+            writer.write_all(line.as_bytes()).is_ok() && writer.flush().is_ok()
+        });
+        if matches!(event, SpectatorEvent::State { .. }) {
+            outputs.last_state = Some(line);
+        }
+    }
+}
+
+/// 操作の前後の設定から状態の変化を取り出す
+pub fn events_between(before: &GameConfig, after: &GameConfig) -> Vec<SpectatorEvent> {
+    let mut events = Vec::new();
+    let seat = |config: &GameConfig, id: u8| config.get_player(id).map(|p| config.seat_status(p));
+
+    // 新しいゲームでは全員が生存に戻るため、座席ごとの変化は書き出さない
+    let new_game =
+        after.round == 1 && !after.game_completed && (before.round != 1 || before.game_completed);
+    if new_game {
+        events.push(SpectatorEvent::NewGame);
+    } else {
+        for player in &after.players {
+            let (player_id, name) = (player.id, player.name.clone());
+            let event = match (seat(before, player.id), after.seat_status(player)) {
+                (Some(SeatStatus::Alive), SeatStatus::Out) => {
+                    let cause = after
+                        .eliminations
+                        .iter()
+                        .rev()
+                        .find(|e| e.player_id == player.id)
+                        .map(|e| e.cause)
+                        .unwrap_or_default();
+                    SpectatorEvent::Eliminated {
+                        player_id,
+                        name,
+                        cause,
+                    }
+                }
+                (Some(SeatStatus::Out), SeatStatus::Alive) => {
+                    SpectatorEvent::Recovered { player_id, name }
+                }
+                (Some(SeatStatus::Benched), SeatStatus::Alive) => {
+                    SpectatorEvent::Revived { player_id, name }
+                }
+                _ => continue,
+            };
+            events.push(event);
+        }
+    }
+
+    let winner = after.check_winner();
+    if let Some(winner) = winner
+        && before.check_winner().map(|p| p.id) != Some(winner.id)
+    {
+        events.push(SpectatorEvent::Winner {
+            player_id: winner.id,
+            name: winner.name.clone(),
+        });
+    }
+    if winner.is_none()
+        && (new_game || before.current_turn != after.current_turn)
+        && let Some(player) = after.current_player()
+    {
+        events.push(SpectatorEvent::TurnChanged {
+            player_id: player.id,
+            name: player.name.clone(),
+        });
+    }
+    events
+}
+
+/// 卓全体の状態
+fn state(config: &GameConfig) -> SpectatorEvent {
+    let seats = config
+        .players
+        .iter()
+        .map(|player| SeatView {
+            id: player.id,
+            name: player.name.clone(),
+            status: match config.seat_status(player) {
+                SeatStatus::Alive => "alive",
+                SeatStatus::Out => "out",
+                SeatStatus::Benched => "benched",
+            },
+        })
+        .collect();
+    SpectatorEvent::State {
+        round: config.round,
+        current_turn: config.current_turn,
+        game_completed: config.game_completed,
+        seats,
+    }
+}
+
+fn player_name(config: &GameConfig, player_id: u8) -> String {
+    config
+        .get_player(player_id)
+        .map(|p| p.name.clone())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(events: &[SpectatorEvent]) -> Vec<String> {
+        events
+            .iter()
+            .map(|event| {
+                let value = serde_json::to_value(event).unwrap();
+                value["event"].as_str().unwrap().to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_roulette_out_events() {
+        let before = GameConfig::default();
+        let mut after = before.clone();
+        after.resolve_roulette(1, &RouletteResult::Out).unwrap();
+
+        let events = events_between(&before, &after);
+        assert_eq!(names(&events), ["eliminated", "turn_changed"]);
+        assert_eq!(
+            events[0],
+            SpectatorEvent::Eliminated {
+                player_id: 1,
+                name: "プレイヤー1".to_string(),
+                cause: EliminationCause::Roulette,
+            }
+        );
+
+        // 安全なら同じプレイヤーのまま次のラウンドに進むだけ
+        let mut safe = before.clone();
+        safe.resolve_roulette(1, &RouletteResult::Safe).unwrap();
+        assert!(events_between(&before, &safe).is_empty());
+    }
+
+    #[test]
+    fn test_winner_and_new_game_events() {
+        let mut before = GameConfig::default();
+        before.eliminate_player(2).unwrap();
+        before.eliminate_player(3).unwrap();
+        let mut after = before.clone();
+        after.resolve_roulette(4, &RouletteResult::Out).unwrap();
+        assert_eq!(
+            names(&events_between(&before, &after)),
+            ["eliminated", "winner"]
+        );
+
+        after.mark_game_completed();
+        let mut reset = after.clone();
+        reset.reset_game();
+        assert_eq!(
+            names(&events_between(&after, &reset)),
+            ["new_game", "turn_changed"]
+        );
+    }
+
+    #[test]
+    fn test_recover_and_revive_events() {
+        let mut before = GameConfig::default();
+        before.participant_count = 3;
+        before.eliminate_player(2).unwrap();
+        let mut after = before.clone();
+        after.recover_player(2).unwrap();
+        after.revive_player(4).unwrap();
+        assert_eq!(
+            names(&events_between(&before, &after)),
+            ["recovered", "revived"]
+        );
+    }
+
+    #[test]
+    fn test_line_format() {
+        let event = state(&GameConfig::default());
+        let line = serde_json::to_string(&Line {
+            time_ms: 42,
+            event: &event,
+        })
+        .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert!(!line.contains('\n'));
+        assert_eq!(value["event"], "state");
+        assert_eq!(value["time_ms"], 42);
+        assert_eq!(value["seats"][0]["status"], "alive");
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::hash_map::DefaultHasher;
+#[cfg(feature = "native")]
+use std::fs::{self, File, OpenOptions};
+use std::hash::{Hash, Hasher};
+use std::io;
+#[cfg(feature = "native")]
+use std::io::Write;
+#[cfg(feature = "native")]
+use std::path::{Path, PathBuf};
+#[cfg(feature = "native")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 設定などの保存先（ファイル、ブラウザのlocalStorage、メモリなど）
+pub trait Storage {
+    /// 保存されている内容（まだ保存されていない場合は`None`）
+    fn read(&self) -> io::Result<Option<String>>;
+    /// 内容を置き換える
+    fn write(&mut self, contents: &str) -> io::Result<()>;
+}
+
+/// メモリ上の保存先（WebAssemblyではJS側がこの内容をlocalStorageなどに保存する）
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryStorage {
+    contents: Option<String>,
+}
+
+impl MemoryStorage {
+    /// 保存済みの内容から作成
+    pub fn new(contents: Option<String>) -> Self {
+        Self { contents }
+    }
+
+    /// 現在の内容
+    pub fn contents(&self) -> Option<&str> {
+        self.contents.as_deref()
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn read(&self) -> io::Result<Option<String>> {
+        Ok(self.contents.clone())
+    }
+
+    fn write(&mut self, contents: &str) -> io::Result<()> {
+        self.contents = Some(contents.to_string());
+        Ok(())
+    }
+}
+
+/// ファイルの保存先（書き込みは`write_atomic`で行う）
+///
+/// プロセス間の排他が必要な場合は呼び出し側で`FileLock`を取得する
+#[cfg(feature = "native")]
+#[derive(Debug, Clone)]
+pub struct FileStorage {
+    path: PathBuf,
+}
+
+#[cfg(feature = "native")]
+impl FileStorage {
+    pub fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+        }
+    }
+}
+
+#[cfg(feature = "native")]
+impl Storage for FileStorage {
+    fn read(&self) -> io::Result<Option<String>> {
+        match fs::read_to_string(&self.path) {
+            Ok(contents) => Ok(Some(contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn write(&mut self, contents: &str) -> io::Result<()> {
+        write_atomic(&self.path, contents.as_bytes())
+    }
+}
+
+/// 保存ファイルごとのプロセス間アドバイザリロック
+///
+/// `<ファイル名>.lock` を排他ロックし、ドロップ時に解放する。
+/// 別のプロセスがロック中の場合は解放されるまで待つ
+#[cfg(feature = "native")]
+#[derive(Debug)]
+pub struct FileLock {
+    file: File,
+}
+
+#[cfg(feature = "native")]
+impl FileLock {
+    /// 対象ファイルのロックを取得する
+    pub fn acquire(path: &Path) -> io::Result<Self> {
+        let lock_path = sibling_path(path, ".lock");
+        if let Some(parent) = lock_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&lock_path)?;
+        file.lock()?;
+        Ok(Self { file })
+    }
+}
+
+#[cfg(feature = "native")]
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = self.file.unlock();
+    }
+}
+
+/// 一時ファイルに書き込んでからリネームし、途中で落ちても元のファイルを壊さない
+#[cfg(feature = "native")]
+pub fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let temp_path = sibling_path(path, &format!(".tmp-{}", std::process::id()));
+    let result = (|| {
+        let mut file = File::create(&temp_path)?;
+        file.write_all(contents)?;
+        file.sync_all()?;
+        fs::rename(&temp_path, path)
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
+
+/// 元のファイルをタイムスタンプ付きの名前でコピーし、そのパスを返す
+///
+/// 移行・復旧や壊れた保存内容の置き換えで、上書きする前の内容を残すために使う
+#[cfg(feature = "native")]
+pub fn backup_file(path: &Path) -> io::Result<PathBuf> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let mut backup_path = path.with_file_name(format!("{}.bak-{}", file_name, timestamp));
+    let mut suffix = 1;
+    while backup_path.exists() {
+        backup_path = path.with_file_name(format!("{}.bak-{}-{}", file_name, timestamp, suffix));
+        suffix += 1;
+    }
+
+    fs::copy(path, &backup_path)?;
+    Ok(backup_path)
+}
+
+/// 読み込んだ内容の指紋（他のプロセスによる書き換えの検出用）
+pub fn fingerprint(contents: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// 同じディレクトリにある、ファイル名に接尾辞を付けたパス
+#[cfg(feature = "native")]
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut file_name = path
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    file_name.push(suffix);
+    path.with_file_name(file_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_storage() {
+        let mut storage = MemoryStorage::default();
+        assert_eq!(storage.read().unwrap(), None);
+        storage.write("version = 3").unwrap();
+        assert_eq!(storage.contents(), Some("version = 3"));
+    }
+
+    #[cfg(feature = "native")]
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "liars_table_storage_{}_{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    #[cfg(feature = "native")]
+    fn test_write_atomic_replaces_file() {
+        let dir = temp_dir("atomic");
+        let path = dir.join("config.toml");
+
+        write_atomic(&path, b"first").unwrap();
+        write_atomic(&path, b"second").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+
+        // 一時ファイルは残らない
+        let leftovers = fs::read_dir(&dir).unwrap().count();
+        assert_eq!(leftovers, 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "native")]
+    fn test_lock_is_released_on_drop() {
+        let dir = temp_dir("lock");
+        let path = dir.join("config.toml");
+
+        let lock = FileLock::acquire(&path).unwrap();
+        drop(lock);
+        // 解放後は再取得できる
+        let _lock = FileLock::acquire(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
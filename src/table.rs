@@ -0,0 +1,246 @@
+use crate::config::{ConfigError, GameConfig};
+use crate::paths::{self, ValueSource, data_dir};
+use crate::storage;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::RwLock;
+use thiserror::Error;
+
+/// 従来の`~/.liars_table/config.toml`を使う卓の名前
+pub const DEFAULT_TABLE: &str = "default";
+
+/// `--table`で指定された、このプロセスでだけ使う卓
+static PINNED_TABLE: RwLock<Option<String>> = RwLock::new(None);
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum TableError {
+    #[error("Invalid table name: {0} (use 1-32 letters, digits, '-' or '_')")]
+    InvalidName(String),
+    #[error("Table not found: {0}")]
+    TableNotFound(String),
+    #[error("Table already exists: {0}")]
+    AlreadyExists(String),
+    #[error("The default table cannot be deleted")]
+    CannotDeleteDefault,
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Config error: {0}")]
+    ConfigError(#[from] ConfigError),
+}
+
+/// 名前付きの卓を保存するディレクトリ（`~/.liars_table/tables`）
+pub fn tables_dir() -> PathBuf {
+    data_dir().join("tables")
+}
+
+/// 卓の設定ファイルのパス
+pub fn config_path(name: &str) -> PathBuf {
+    if name == DEFAULT_TABLE {
+        // データディレクトリまたは現在のディレクトリに設定ファイルを配置
+        match paths::data_dir_with_source() {
+            (_, ValueSource::Default) if dirs::home_dir().is_none() => {
+                PathBuf::from(".liars_table_config.toml")
+            }
+            (dir, _) => dir.join("config.toml"),
+        }
+    } else {
+        tables_dir().join(format!("{}.toml", name))
+    }
+}
+
+/// 卓の取り消し履歴ファイルのパス
+pub fn journal_path(name: &str) -> PathBuf {
+    if name == DEFAULT_TABLE {
+        data_dir().join("journal.json")
+    } else {
+        tables_dir().join(format!("{}.journal.json", name))
+    }
+}
+
+/// 卓のトーナメント記録ファイルのパス
+pub fn tournament_path(name: &str) -> PathBuf {
+    if name == DEFAULT_TABLE {
+        data_dir().join("tournament.json")
+    } else {
+        tables_dir().join(format!("{}.tournament.json", name))
+    }
+}
+
+/// 卓の進行中のカードゲームの保存ファイルのパス
+pub fn game_path(name: &str) -> PathBuf {
+    if name == DEFAULT_TABLE {
+        data_dir().join("game.json")
+    } else {
+        tables_dir().join(format!("{}.game.json", name))
+    }
+}
+
+/// 最後に切り替えた卓を記録するファイルのパス
+fn current_table_path() -> PathBuf {
+    data_dir().join("current_table")
+}
+
+/// 卓の名前の検証
+pub fn validate_name(name: &str) -> Result<(), TableError> {
+    let valid = !name.is_empty()
+        && name.len() <= 32
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(TableError::InvalidName(name.to_string()))
+    }
+}
+
+/// 卓が存在するか（デフォルトの卓は常に存在する）
+pub fn exists(name: &str) -> bool {
+    name == DEFAULT_TABLE || (validate_name(name).is_ok() && config_path(name).exists())
+}
+
+/// 卓の一覧（デフォルトの卓が先頭、以降は名前順）
+pub fn list() -> Result<Vec<String>, TableError> {
+    let mut names = Vec::new();
+    let dir = tables_dir();
+    if dir.exists() {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "toml")
+                && let Some(stem) = path.file_stem().and_then(|s| s.to_str())
+                && stem != DEFAULT_TABLE
+                && validate_name(stem).is_ok()
+            {
+                names.push(stem.to_string());
+            }
+        }
+    }
+    names.sort();
+    names.insert(0, DEFAULT_TABLE.to_string());
+    Ok(names)
+}
+
+/// 現在使用中の卓
+///
+/// `--table`の指定、最後に切り替えた卓、デフォルトの卓の順に決まる
+pub fn active() -> String {
+    if let Some(name) = PINNED_TABLE.read().ok().and_then(|pinned| pinned.clone()) {
+        return name;
+    }
+
+    fs::read_to_string(current_table_path())
+        .ok()
+        .map(|name| name.trim().to_string())
+        .filter(|name| exists(name))
+        .unwrap_or_else(|| DEFAULT_TABLE.to_string())
+}
+
+/// このプロセスでだけ使う卓を指定する（`--table NAME`）
+pub fn pin(name: &str) -> Result<(), TableError> {
+    if !exists(name) {
+        return Err(TableError::TableNotFound(name.to_string()));
+    }
+    if let Ok(mut pinned) = PINNED_TABLE.write() {
+        *pinned = Some(name.to_string());
+    }
+    Ok(())
+}
+
+/// `--table`で卓が指定されているか
+pub fn is_pinned() -> bool {
+    PINNED_TABLE.read().is_ok_and(|pinned| pinned.is_some())
+}
+
+/// 使用する卓を切り替え、次回以降も使うように記録する
+pub fn switch(name: &str) -> Result<(), TableError> {
+    if !exists(name) {
+        return Err(TableError::TableNotFound(name.to_string()));
+    }
+    storage::write_atomic(&current_table_path(), name.as_bytes())?;
+    if is_pinned() {
+        pin(name)?;
+    }
+    Ok(())
+}
+
+/// デフォルト設定で新しい卓を作る
+pub fn create(name: &str) -> Result<(), TableError> {
+    validate_name(name)?;
+    if exists(name) {
+        return Err(TableError::AlreadyExists(name.to_string()));
+    }
+    GameConfig::default().save_to(&config_path(name))?;
+    Ok(())
+}
+
+/// 卓の設定を別の名前で複製する（取り消し履歴は引き継がない）
+pub fn duplicate(source: &str, name: &str) -> Result<(), TableError> {
+    if !exists(source) {
+        return Err(TableError::TableNotFound(source.to_string()));
+    }
+    validate_name(name)?;
+    if exists(name) {
+        return Err(TableError::AlreadyExists(name.to_string()));
+    }
+
+    let (mut config, _) = GameConfig::load_from(&config_path(source))?;
+    config.save_to(&config_path(name))?;
+    Ok(())
+}
+
+/// 卓の設定・取り消し履歴・トーナメント記録・カードゲームを削除する
+pub fn delete(name: &str) -> Result<(), TableError> {
+    if name == DEFAULT_TABLE {
+        return Err(TableError::CannotDeleteDefault);
+    }
+    if !exists(name) {
+        return Err(TableError::TableNotFound(name.to_string()));
+    }
+
+    let was_active = active() == name;
+    let config = config_path(name);
+    fs::remove_file(&config)?;
+    for path in [
+        journal_path(name),
+        tournament_path(name),
+        game_path(name),
+        config.with_file_name(format!("{}.toml.lock", name)),
+    ] {
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+    }
+
+    // 削除した卓を使用中だった場合はデフォルトの卓に戻す
+    if was_active {
+        switch(DEFAULT_TABLE)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_name() {
+        assert!(validate_name("office").is_ok());
+        assert!(validate_name("home_2").is_ok());
+        assert!(validate_name("tour-2026").is_ok());
+
+        assert!(validate_name("").is_err());
+        assert!(validate_name("../config").is_err());
+        assert!(validate_name("with space").is_err());
+        assert!(validate_name(&"a".repeat(33)).is_err());
+    }
+
+    #[test]
+    fn test_table_paths() {
+        assert!(config_path("office").ends_with("tables/office.toml"));
+        assert!(journal_path("office").ends_with("tables/office.journal.json"));
+        assert!(journal_path(DEFAULT_TABLE).ends_with("journal.json"));
+        assert!(game_path("office").ends_with("tables/office.game.json"));
+        assert!(exists(DEFAULT_TABLE));
+    }
+}
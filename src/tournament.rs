@@ -0,0 +1,608 @@
+use liars_table::{FileLock, GameConfig, MAX_PLAYERS, MIN_PLAYERS, tournament_path, write_atomic};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TournamentError {
+    #[error("Invalid tournament value: {0}")]
+    InvalidValue(String),
+    #[error("The game has no winner yet")]
+    GameNotFinished,
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    JsonError(#[from] serde_json::Error),
+}
+
+/// トーナメントの終了条件
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "target", rename_all = "snake_case")]
+pub enum TournamentGoal {
+    /// 指定したゲーム数を終えたら終了
+    Games(u32),
+    /// 誰かが指定した得点に達したら終了
+    Points(u32),
+}
+
+/// プレイヤーごとの累計成績
+///
+/// プロフィールを紐づけたプレイヤーはプロフィールごと、それ以外は座席ごとに集計する
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Standing {
+    /// 最後に記録したゲームでの座席
+    pub player_id: u8,
+    #[serde(default)]
+    pub profile_id: Option<u32>,
+    pub name: String,
+    pub points: u32,
+    pub wins: u32,
+    pub games: u32,
+}
+
+/// 1ゲーム分の得点の記録
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameResult {
+    /// 設定のゲームの通し番号（[`GameConfig::game_number`]）
+    pub game_number: u32,
+    /// (プレイヤーID, 獲得点) の順位順
+    pub awarded: Vec<(u8, u32)>,
+    /// `awarded`と同じ順の各プレイヤーのプロフィールID
+    #[serde(default)]
+    pub profile_ids: Vec<Option<u32>>,
+}
+
+impl Standing {
+    /// 座席のプレイヤーの成績か（プロフィールがあればプロフィールで、なければ座席で比べる）
+    fn is_for(&self, player_id: u8, profile_id: Option<u32>) -> bool {
+        match profile_id {
+            Some(_) => self.profile_id == profile_id,
+            None => self.profile_id.is_none() && self.player_id == player_id,
+        }
+    }
+}
+
+/// 複数ゲームにまたがる得点の記録
+///
+/// `reset_game`で新しいゲームを始めても、卓ごとのトーナメント記録ファイルに残り続ける
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tournament {
+    pub goal: TournamentGoal,
+    /// 順位ごとの得点（1位から）。空の場合は「参加人数 - 順位」点
+    #[serde(default)]
+    pub placement_points: Vec<u32>,
+    #[serde(default)]
+    pub games_played: u32,
+    #[serde(default)]
+    pub standings: Vec<Standing>,
+    /// 記録済みのゲームごとの得点（同じゲームを二重に数えないために使う）
+    #[serde(default)]
+    pub results: Vec<GameResult>,
+    /// 大人数を複数の卓に分ける勝ち抜き表
+    #[serde(default)]
+    pub bracket: Option<Bracket>,
+}
+
+impl Tournament {
+    /// 新しいトーナメントを作成
+    pub fn new(goal: TournamentGoal) -> Result<Self, TournamentError> {
+        let target = match goal {
+            TournamentGoal::Games(target) | TournamentGoal::Points(target) => target,
+        };
+        if target == 0 {
+            return Err(TournamentError::InvalidValue(
+                "Tournament target must be at least 1".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            goal,
+            placement_points: Vec::new(),
+            games_played: 0,
+            standings: Vec::new(),
+            results: Vec::new(),
+            bracket: None,
+        })
+    }
+
+    /// トーナメント記録を読み込む（開催中でない場合は`None`）
+    pub fn load() -> Result<Option<Self>, TournamentError> {
+        Self::load_from(&tournament_path())
+    }
+
+    /// トーナメント記録を保存する（新しいトーナメントの開始）
+    pub fn save(&self) -> Result<(), TournamentError> {
+        let path = tournament_path();
+        let _lock = FileLock::acquire(&path)?;
+        self.write(&path)
+    }
+
+    /// 最新のトーナメント記録を読み込んで変更し、保存する
+    ///
+    /// 読み込みから書き込みまでロックを保持するため、別のプロセスが記録した得点を上書きしない。
+    /// 開催中でない場合は何もせず`None`を返す
+    pub fn update<T>(
+        change: impl FnOnce(&mut Self) -> Result<T, TournamentError>,
+    ) -> Result<Option<(Self, T)>, TournamentError> {
+        Self::update_at(&tournament_path(), change)
+    }
+
+    /// 指定したパスから読み込む
+    fn load_from(path: &Path) -> Result<Option<Self>, TournamentError> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(path)?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    /// 指定したパスの記録を変更する（[`Tournament::update`]）
+    fn update_at<T>(
+        path: &Path,
+        change: impl FnOnce(&mut Self) -> Result<T, TournamentError>,
+    ) -> Result<Option<(Self, T)>, TournamentError> {
+        let _lock = FileLock::acquire(path)?;
+        let Some(mut tournament) = Self::load_from(path)? else {
+            return Ok(None);
+        };
+
+        let value = change(&mut tournament)?;
+        tournament.write(path)?;
+        Ok(Some((tournament, value)))
+    }
+
+    /// ロックを取得済みのパスに書き込む
+    fn write(&self, path: &Path) -> Result<(), TournamentError> {
+        let content = serde_json::to_string_pretty(self)?;
+        write_atomic(path, content.as_bytes())?;
+        Ok(())
+    }
+
+    /// トーナメント記録を削除する（トーナメントの終了）
+    pub fn clear() -> Result<(), TournamentError> {
+        let path = tournament_path();
+        let _lock = FileLock::acquire(&path)?;
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    /// 順位（1から）に与える得点
+    pub fn points_for(&self, place: usize, participants: usize) -> u32 {
+        if self.placement_points.is_empty() {
+            participants.saturating_sub(place) as u32
+        } else {
+            self.placement_points
+                .get(place.saturating_sub(1))
+                .copied()
+                .unwrap_or(0)
+        }
+    }
+
+    /// 終わったゲームの順位から得点を加算し、(プレイヤーID, 獲得点) を順位順に返す
+    ///
+    /// 同じゲーム（取り消してやり直したゲームなど）を再び記録した場合は、
+    /// 前回の得点を取り消してから加算するため二重に数えない
+    pub fn record_game(&mut self, config: &GameConfig) -> Result<Vec<(u8, u32)>, TournamentError> {
+        if config.check_winner().is_none() {
+            return Err(TournamentError::GameNotFinished);
+        }
+
+        match self
+            .results
+            .iter()
+            .position(|r| r.game_number == config.game_number)
+        {
+            Some(index) => {
+                let previous = self.results.remove(index);
+                self.revert(&previous);
+            }
+            None => self.games_played += 1,
+        }
+
+        let order = config.finishing_order();
+        let mut awarded = Vec::with_capacity(order.len());
+        let mut profile_ids = Vec::with_capacity(order.len());
+        for (index, &player_id) in order.iter().enumerate() {
+            let points = self.points_for(index + 1, order.len());
+            let player = config.get_player(player_id);
+            let name = player.map(|p| p.name.clone()).unwrap_or_default();
+            let profile_id = player.and_then(|p| p.profile_id);
+
+            let standing = match self
+                .standings
+                .iter_mut()
+                .find(|s| s.is_for(player_id, profile_id))
+            {
+                Some(standing) => standing,
+                None => {
+                    self.standings.push(Standing {
+                        player_id,
+                        profile_id,
+                        name: name.clone(),
+                        points: 0,
+                        wins: 0,
+                        games: 0,
+                    });
+                    self.standings.last_mut().expect("standing was just pushed")
+                }
+            };
+            standing.player_id = player_id;
+            standing.name = name;
+            standing.points += points;
+            standing.games += 1;
+            if index == 0 {
+                standing.wins += 1;
+            }
+            awarded.push((player_id, points));
+            profile_ids.push(profile_id);
+        }
+
+        self.results.push(GameResult {
+            game_number: config.game_number,
+            awarded: awarded.clone(),
+            profile_ids,
+        });
+        Ok(awarded)
+    }
+
+    /// 記録済みのゲームの得点を成績から差し引く
+    fn revert(&mut self, result: &GameResult) {
+        for (index, &(player_id, points)) in result.awarded.iter().enumerate() {
+            let profile_id = result.profile_ids.get(index).copied().flatten();
+            if let Some(standing) = self
+                .standings
+                .iter_mut()
+                .find(|s| s.is_for(player_id, profile_id))
+            {
+                standing.points = standing.points.saturating_sub(points);
+                standing.games = standing.games.saturating_sub(1);
+                if index == 0 {
+                    standing.wins = standing.wins.saturating_sub(1);
+                }
+            }
+        }
+    }
+
+    /// 得点・勝利数の多い順に並べた成績
+    pub fn sorted_standings(&self) -> Vec<&Standing> {
+        let mut standings: Vec<&Standing> = self.standings.iter().collect();
+        standings.sort_by(|a, b| {
+            b.points
+                .cmp(&a.points)
+                .then(b.wins.cmp(&a.wins))
+                .then(a.player_id.cmp(&b.player_id))
+        });
+        standings
+    }
+
+    /// 首位のプレイヤー
+    pub fn leader(&self) -> Option<&Standing> {
+        self.sorted_standings().into_iter().next()
+    }
+
+    /// 終了条件に達したか
+    pub fn is_finished(&self) -> bool {
+        match self.goal {
+            TournamentGoal::Games(target) => self.games_played >= target,
+            TournamentGoal::Points(target) => self.standings.iter().any(|s| s.points >= target),
+        }
+    }
+}
+
+/// 勝ち抜き表の1つの卓
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BracketTable {
+    pub players: Vec<String>,
+    #[serde(default)]
+    pub winner: Option<String>,
+}
+
+/// 大人数を複数の卓に分け、各卓の勝者が次のラウンドに進む勝ち抜き表
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bracket {
+    /// 1卓あたりの最大人数
+    pub table_size: u8,
+    /// ラウンドごとの卓
+    pub rounds: Vec<Vec<BracketTable>>,
+}
+
+impl Bracket {
+    /// 参加者を卓に分けて勝ち抜き表を作る
+    pub fn new(entrants: Vec<String>, table_size: u8) -> Result<Self, TournamentError> {
+        if !(MIN_PLAYERS..=MAX_PLAYERS).contains(&table_size) {
+            return Err(TournamentError::InvalidValue(format!(
+                "Table size must be between {} and {}",
+                MIN_PLAYERS, MAX_PLAYERS
+            )));
+        }
+        if entrants.len() < MIN_PLAYERS as usize {
+            return Err(TournamentError::InvalidValue(format!(
+                "At least {} entrants are required",
+                MIN_PLAYERS
+            )));
+        }
+        for (index, name) in entrants.iter().enumerate() {
+            if name.trim().is_empty() || entrants[..index].contains(name) {
+                return Err(TournamentError::InvalidValue(format!(
+                    "Entrant names must be unique and non-empty: {}",
+                    name
+                )));
+            }
+        }
+
+        Ok(Self {
+            table_size,
+            rounds: vec![Self::split(entrants, table_size)],
+        })
+    }
+
+    /// 参加者を人数差が1以内になるように卓に分ける（1人だけの卓は不戦勝）
+    fn split(entrants: Vec<String>, table_size: u8) -> Vec<BracketTable> {
+        let table_count = entrants.len().div_ceil(table_size as usize);
+        let mut tables = vec![
+            BracketTable {
+                players: Vec::new(),
+                winner: None,
+            };
+            table_count
+        ];
+        for (index, name) in entrants.into_iter().enumerate() {
+            tables[index % table_count].players.push(name);
+        }
+        for table in &mut tables {
+            if table.players.len() == 1 {
+                table.winner = table.players.first().cloned();
+            }
+        }
+        tables
+    }
+
+    /// 現在のラウンドの卓
+    pub fn current_round(&self) -> &[BracketTable] {
+        self.rounds.last().map_or(&[], Vec::as_slice)
+    }
+
+    /// 現在のラウンドの卓の勝者を記録し、全卓が終わったら次のラウンドを作る
+    pub fn record_winner(
+        &mut self,
+        table_index: usize,
+        winner: &str,
+    ) -> Result<(), TournamentError> {
+        let table = self
+            .rounds
+            .last_mut()
+            .and_then(|round| round.get_mut(table_index))
+            .ok_or_else(|| {
+                TournamentError::InvalidValue(format!("Table {} not found", table_index + 1))
+            })?;
+        if !table.players.iter().any(|name| name == winner) {
+            return Err(TournamentError::InvalidValue(format!(
+                "{} is not seated at table {}",
+                winner,
+                table_index + 1
+            )));
+        }
+        table.winner = Some(winner.to_string());
+
+        let round = self.current_round();
+        if round.len() > 1 && round.iter().all(|table| table.winner.is_some()) {
+            let winners = round
+                .iter()
+                .filter_map(|table| table.winner.clone())
+                .collect();
+            self.rounds.push(Self::split(winners, self.table_size));
+        }
+        Ok(())
+    }
+
+    /// 優勝者（最終ラウンドの卓の勝者）
+    pub fn champion(&self) -> Option<&str> {
+        match self.current_round() {
+            [final_table] => final_table.winner.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_game_awards_placement_points() {
+        let mut config = GameConfig::default();
+        config.eliminate_player(3).unwrap();
+        config.eliminate_player(1).unwrap();
+        config.eliminate_player(4).unwrap();
+
+        let mut tournament = Tournament::new(TournamentGoal::Games(2)).unwrap();
+        let awarded = tournament.record_game(&config).unwrap();
+        // 最後まで残った2が1位、最初に除外された3が最下位
+        assert_eq!(awarded, vec![(2, 3), (4, 2), (1, 1), (3, 0)]);
+        assert!(!tournament.is_finished());
+
+        // 新しいゲームでも成績は引き継がれる
+        config.reset_game();
+        config.eliminate_player(2).unwrap();
+        config.eliminate_player(3).unwrap();
+        config.eliminate_player(4).unwrap();
+        tournament.record_game(&config).unwrap();
+
+        let leader = tournament.leader().unwrap();
+        assert_eq!((leader.player_id, leader.points, leader.wins), (1, 4, 1));
+        assert!(tournament.is_finished());
+    }
+
+    #[test]
+    fn test_replayed_game_is_recorded_once() {
+        let mut config = GameConfig::default();
+        for id in [3, 1, 4] {
+            config.eliminate_player(id).unwrap();
+        }
+        let mut tournament = Tournament::new(TournamentGoal::Games(3)).unwrap();
+        tournament.record_game(&config).unwrap();
+
+        // 最後のロシアンルーレットを取り消し、別の結果でやり直した同じゲーム
+        config.recover_player(4).unwrap();
+        config.recover_player(2).unwrap();
+        config.eliminate_player(2).unwrap();
+        let awarded = tournament.record_game(&config).unwrap();
+        assert_eq!(awarded[0], (4, 3));
+
+        assert_eq!(tournament.games_played, 1);
+        assert_eq!(tournament.results.len(), 1);
+        let total: u32 = tournament.standings.iter().map(|s| s.points).sum();
+        assert_eq!(total, 6);
+        let wins: u32 = tournament.standings.iter().map(|s| s.wins).sum();
+        assert_eq!(wins, 1);
+        assert!(tournament.standings.iter().all(|s| s.games == 1));
+
+        // 新しいゲームは別に数える
+        config.reset_game();
+        for id in [2, 3, 4] {
+            config.eliminate_player(id).unwrap();
+        }
+        tournament.record_game(&config).unwrap();
+        assert_eq!(tournament.games_played, 2);
+    }
+
+    #[test]
+    fn test_update_keeps_games_recorded_by_others() {
+        let dir =
+            std::env::temp_dir().join(format!("liars_table_tournament_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("config.tournament.json");
+
+        // 開催中でない場合は何もしない
+        assert!(Tournament::update_at(&path, |_| Ok(())).unwrap().is_none());
+        Tournament::new(TournamentGoal::Games(5))
+            .unwrap()
+            .write(&path)
+            .unwrap();
+
+        // 先に読み込んだ記録ではなく、最新の記録に加算する
+        let mut config = GameConfig::default();
+        config.eliminate_player(1).unwrap();
+        config.eliminate_player(2).unwrap();
+        config.eliminate_player(3).unwrap();
+        let stale = Tournament::load_from(&path).unwrap().unwrap();
+        Tournament::update_at(&path, |t| t.record_game(&config)).unwrap();
+        config.reset_game();
+        config.eliminate_player(4).unwrap();
+        config.eliminate_player(2).unwrap();
+        config.eliminate_player(3).unwrap();
+        let (updated, _) = Tournament::update_at(&path, |t| t.record_game(&config))
+            .unwrap()
+            .unwrap();
+        assert_eq!(stale.games_played, 0);
+        assert_eq!(updated.games_played, 2);
+        assert_eq!(Tournament::load_from(&path).unwrap().unwrap(), updated);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_profiles_keep_their_standings_across_seats() {
+        let mut config = GameConfig::default();
+        config.players[0].profile_id = Some(10);
+        config.players[1].profile_id = Some(20);
+        for id in [3, 4, 2] {
+            config.eliminate_player(id).unwrap();
+        }
+        let mut tournament = Tournament::new(TournamentGoal::Games(3)).unwrap();
+        tournament.record_game(&config).unwrap();
+
+        // 2つのプロフィールが席を入れ替えて次のゲームを遊ぶ
+        config.reset_game();
+        config.players[0].profile_id = Some(20);
+        config.players[1].profile_id = Some(10);
+        for id in [3, 4, 2] {
+            config.eliminate_player(id).unwrap();
+        }
+        tournament.record_game(&config).unwrap();
+
+        let standing = |tournament: &Tournament, profile_id| {
+            tournament
+                .standings
+                .iter()
+                .find(|s| s.profile_id == Some(profile_id))
+                .map(|s| (s.player_id, s.points, s.wins, s.games))
+                .unwrap()
+        };
+        // 10: 1位(3点)→2位(2点)、20: 2位(2点)→1位(3点)。座席は最後のゲームのもの
+        assert_eq!(standing(&tournament, 10), (2, 3 + 2, 1, 2));
+        assert_eq!(standing(&tournament, 20), (1, 2 + 3, 1, 2));
+        // プロフィールのない座席は座席ごとに集計する
+        assert_eq!(tournament.standings.len(), 4);
+
+        // 同じゲームを記録し直しても、プロフィールの成績から差し引いてから加算する
+        tournament.record_game(&config).unwrap();
+        assert_eq!(standing(&tournament, 20), (1, 5, 1, 2));
+        assert_eq!(standing(&tournament, 10), (2, 5, 1, 2));
+    }
+
+    #[test]
+    fn test_points_goal_and_custom_points() {
+        let mut config = GameConfig::default();
+        for id in [2, 3, 4] {
+            config.eliminate_player(id).unwrap();
+        }
+
+        let mut tournament = Tournament::new(TournamentGoal::Points(10)).unwrap();
+        tournament.placement_points = vec![10, 5];
+        assert_eq!(tournament.points_for(3, 4), 0);
+
+        tournament.record_game(&config).unwrap();
+        assert!(tournament.is_finished());
+        assert!(Tournament::new(TournamentGoal::Points(0)).is_err());
+    }
+
+    #[test]
+    fn test_unfinished_game_is_not_recorded() {
+        let config = GameConfig::default();
+        let mut tournament = Tournament::new(TournamentGoal::Games(1)).unwrap();
+        assert!(tournament.record_game(&config).is_err());
+        assert_eq!(tournament.games_played, 0);
+    }
+
+    #[test]
+    fn test_bracket_advances_winners() {
+        let entrants: Vec<String> = (1..=9).map(|i| format!("P{}", i)).collect();
+        let mut bracket = Bracket::new(entrants, 4).unwrap();
+
+        // 9人を4人卓に分けると3卓（3人ずつ）
+        let sizes: Vec<usize> = bracket
+            .current_round()
+            .iter()
+            .map(|t| t.players.len())
+            .collect();
+        assert_eq!(sizes, vec![3, 3, 3]);
+
+        assert!(bracket.record_winner(0, "P2").is_err());
+        bracket.record_winner(0, "P1").unwrap();
+        bracket.record_winner(1, "P5").unwrap();
+        assert_eq!(bracket.rounds.len(), 1);
+        bracket.record_winner(2, "P9").unwrap();
+
+        // 全卓の勝者が決勝卓へ
+        assert_eq!(bracket.rounds.len(), 2);
+        assert_eq!(bracket.current_round()[0].players, vec!["P1", "P5", "P9"]);
+        assert!(bracket.champion().is_none());
+        bracket.record_winner(0, "P5").unwrap();
+        assert_eq!(bracket.champion(), Some("P5"));
+    }
+
+    #[test]
+    fn test_bracket_bye_and_validation() {
+        let entrants: Vec<String> = ["A", "B", "C"].iter().map(|s| s.to_string()).collect();
+        let bracket = Bracket::new(entrants.clone(), 2).unwrap();
+        // 3人を2人卓に分けると1人の卓は不戦勝
+        assert_eq!(bracket.current_round()[1].winner.as_deref(), Some("B"));
+
+        assert!(Bracket::new(entrants.clone(), 1).is_err());
+        assert!(Bracket::new(vec!["A".to_string(), "A".to_string()], 2).is_err());
+    }
+}
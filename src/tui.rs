@@ -0,0 +1,555 @@
+use crate::journal::{self, ActionKind, Journal, JournalError, SaveOutcome};
+use crate::sound::{SoundEffect, SoundPlayer};
+use crate::spectator;
+use crate::tournament::{Tournament, TournamentError};
+use liars_table::{
+    ConfigError, GameConfig, I18nError, I18nManager, Player, RouletteResult, SeatStatus,
+    ValueSource, config_path_with_source, spin,
+};
+use ratatui::DefaultTerminal;
+use ratatui::Frame;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Row, Table, TableState};
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// 「最近の出来事」に表示する件数
+const RECENT_EVENTS: usize = 8;
+
+#[derive(Error, Debug)]
+#[allow(clippy::enum_variant_names)]
+pub enum TuiError {
+    #[error("Configuration error: {0}")]
+    ConfigError(#[from] ConfigError),
+    #[error("I18n error: {0}")]
+    I18nError(#[from] I18nError),
+    #[error("Journal error: {0}")]
+    JournalError(#[from] JournalError),
+    #[error("Tournament error: {0}")]
+    TournamentError(#[from] TournamentError),
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+}
+
+/// 全座席・ターン・確率・最近の出来事を常に表示する全画面ダッシュボード
+///
+/// `InteractiveUI`と同じ`GameConfig`・`I18nManager`・取り消し履歴を使う別のフロントエンド
+pub struct Dashboard {
+    i18n: I18nManager,
+    config: GameConfig,
+    journal: Journal,
+    sound: SoundPlayer,
+    selected: usize,              // カーソルのある座席の位置
+    pending_roulette: Option<u8>, // 実行の確認待ちのロシアンルーレットの対象
+    status: String,               // 直近の操作の結果
+    dirty: bool,                  // 保存していない変更があるか
+    saved: GameConfig,            // 最後に読み書きした時点の設定
+    unsaved: Vec<String>,         // 保存していない操作の説明
+    game_finished: bool,          // このキー操作で勝者が決まったか
+    quit: bool,
+}
+
+impl Dashboard {
+    /// 設定・言語・取り消し履歴を読み込んでダッシュボードを作成
+    pub fn new() -> Result<Self, TuiError> {
+        let mut i18n = I18nManager::new()?;
+        let config = GameConfig::load()?;
+        let journal = Journal::load()?;
+
+        // 設定された言語に切り替え
+        if i18n.is_language_available(&config.language) {
+            i18n.load_language(&config.language)?;
+        }
+        spectator::snapshot(&config);
+
+        Ok(Self::with_state(i18n, config, journal))
+    }
+
+    fn with_state(i18n: I18nManager, config: GameConfig, journal: Journal) -> Self {
+        let selected = config.seat_index(config.current_turn).unwrap_or_default();
+        let sound = SoundPlayer::new(&config.sound);
+        Self {
+            i18n,
+            saved: config.clone(),
+            config,
+            journal,
+            sound,
+            selected,
+            pending_roulette: None,
+            status: String::new(),
+            dirty: false,
+            unsaved: Vec::new(),
+            game_finished: false,
+            quit: false,
+        }
+    }
+
+    /// キー入力を処理し、画面を描き直すループ
+    pub fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<(), TuiError> {
+        while !self.quit {
+            terminal.draw(|frame| self.draw(frame))?;
+
+            if let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+            {
+                self.handle_key(key.code)?;
+                self.persist()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// キー操作の処理（保存は`persist`で行う）
+    fn handle_key(&mut self, code: KeyCode) -> Result<(), TuiError> {
+        // ロシアンルーレットの確認中は y / Enter で実行、それ以外で取り消し
+        if let Some(target) = self.pending_roulette.take() {
+            return match code {
+                KeyCode::Char('y') | KeyCode::Enter => self.spin(target),
+                _ => {
+                    self.status.clear();
+                    Ok(())
+                }
+            };
+        }
+
+        match code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(self.config.players.len() - 1);
+            }
+            KeyCode::Char('r') => self.request_roulette()?,
+            KeyCode::Char('t') => self.pass_turn()?,
+            KeyCode::Char('c') => self.recover_selected()?,
+            KeyCode::Char('v') => self.revive_selected()?,
+            KeyCode::Char('n') if self.config.game_completed => self.new_game()?,
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// カーソルのあるプレイヤー
+    fn selected_player(&self) -> Option<&Player> {
+        self.config.players.get(self.selected)
+    }
+
+    /// プレイヤー名を引数にしたメッセージ
+    fn message_for(&self, key: &str, name: &str) -> Result<String, TuiError> {
+        let mut args = HashMap::new();
+        args.insert("name".to_string(), name.to_string());
+        Ok(self.i18n.get_message_with_args(key, &args)?)
+    }
+
+    /// カーソルのあるプレイヤーのロシアンルーレットの確認を求める
+    fn request_roulette(&mut self) -> Result<(), TuiError> {
+        let Some(player) = self.selected_player() else {
+            return Ok(());
+        };
+        let (id, name) = (player.id, player.name.clone());
+
+        self.status = if self.config.game_completed {
+            self.i18n.get_message("tui_game_over")?
+        } else if self.config.seat_status(player) != SeatStatus::Alive {
+            self.message_for("tui_not_alive", &name)?
+        } else {
+            self.pending_roulette = Some(id);
+            self.message_for("tui_confirm_roulette", &name)?
+        };
+        Ok(())
+    }
+
+    /// ロシアンルーレットを実行して結果を反映する
+    fn spin(&mut self, target: u8) -> Result<(), TuiError> {
+        let name = self
+            .config
+            .get_player(target)
+            .map(|p| p.name.clone())
+            .unwrap_or_default();
+
+        spectator::spin_started(&self.config, target);
+        self.sound.play(SoundEffect::Spin);
+        let result = spin(&self.config.roulette_config());
+        spectator::spin_result(&self.config, target, &result);
+        let (result_key, effect) = match result {
+            RouletteResult::Safe => ("roulette_result_safe", SoundEffect::Click),
+            RouletteResult::Out => ("roulette_result_out", SoundEffect::Bang),
+        };
+        self.sound.play(effect);
+        let message = self.message_for(result_key, &name)?;
+
+        let before = self.config.clone();
+        self.config.resolve_roulette(target, &result)?;
+        self.record(ActionKind::Roulette, message.clone(), before);
+        self.status = message;
+
+        if let Some(winner) = self.config.check_winner() {
+            self.status = self.message_for("game_winner", &winner.name.clone())?;
+            self.config.mark_game_completed();
+            self.game_finished = true;
+        }
+        Ok(())
+    }
+
+    /// 直前のプレイを信じて次のプレイヤーのターンに移す
+    fn pass_turn(&mut self) -> Result<(), TuiError> {
+        if self.config.game_completed {
+            self.status = self.i18n.get_message("tui_game_over")?;
+            return Ok(());
+        }
+
+        let before = self.config.clone();
+        self.config.next_turn();
+        let name = self
+            .config
+            .current_player()
+            .map(|p| p.name.clone())
+            .unwrap_or_default();
+        let message = self.message_for("next_turn", &name)?;
+        self.record(ActionKind::Turn, message.clone(), before);
+        self.status = message;
+        Ok(())
+    }
+
+    /// カーソルのある除外されたプレイヤーを回復する
+    fn recover_selected(&mut self) -> Result<(), TuiError> {
+        let Some(player) = self.selected_player() else {
+            return Ok(());
+        };
+        let (id, name) = (player.id, player.name.clone());
+        if self.config.seat_status(player) != SeatStatus::Out {
+            self.status = self.message_for("tui_not_recoverable", &name)?;
+            return Ok(());
+        }
+
+        let before = self.config.clone();
+        self.config.recover_player(id)?;
+        let message = self.message_for("player_recovered", &name)?;
+        self.record(ActionKind::Recovery, message.clone(), before);
+        self.status = message;
+        Ok(())
+    }
+
+    /// カーソルのある控え席のプレイヤーを蘇生する
+    fn revive_selected(&mut self) -> Result<(), TuiError> {
+        let Some(player) = self.selected_player() else {
+            return Ok(());
+        };
+        let (id, name) = (player.id, player.name.clone());
+        if self.config.seat_status(player) != SeatStatus::Benched {
+            self.status = self.message_for("tui_not_revivable", &name)?;
+            return Ok(());
+        }
+
+        let before = self.config.clone();
+        self.config.revive_player(id)?;
+        // 蘇生したプレイヤーは参加席の末尾に移るため、カーソルも追いかける
+        self.selected = self.config.seat_index(id).unwrap_or(self.selected);
+        let message = self.message_for("player_revived", &name)?;
+        self.record(ActionKind::Revive, message.clone(), before);
+        self.status = message;
+        Ok(())
+    }
+
+    /// 新しいゲームを開始する
+    fn new_game(&mut self) -> Result<(), TuiError> {
+        let message = self.i18n.get_message("game_reset")?;
+        let before = self.config.clone();
+        self.config.reset_game();
+        self.record(ActionKind::Reset, message.clone(), before);
+        self.selected = self
+            .config
+            .seat_index(self.config.current_turn)
+            .unwrap_or_default();
+        self.status = message;
+        Ok(())
+    }
+
+    /// 操作を取り消し履歴に記録する
+    fn record(&mut self, kind: ActionKind, description: String, before: GameConfig) {
+        spectator::record_change(&before, &self.config);
+        self.unsaved.push(description.clone());
+        self.journal
+            .record(kind, description, before, self.config.clone());
+        self.dirty = true;
+    }
+
+    /// 変更を保存し、勝者が決まった場合はトーナメントの得点に加算する
+    fn persist(&mut self) -> Result<(), TuiError> {
+        if !self.dirty {
+            return Ok(());
+        }
+        self.dirty = false;
+
+        // 別のプロセスが先に書き換えていた場合は、最新の設定に変更を反映し直す
+        let outcome = journal::save_changes(
+            &mut self.config,
+            &self.saved,
+            GameConfig::save,
+            GameConfig::load,
+        )?;
+        self.saved = self.config.clone();
+        let unsaved = std::mem::take(&mut self.unsaved);
+        if outcome != SaveOutcome::Saved {
+            // 観戦画面も保存された状態に合わせる
+            spectator::snapshot(&self.config);
+        }
+        match outcome {
+            SaveOutcome::Saved => {}
+            SaveOutcome::Rebased => {
+                self.status = self.i18n.get_message("error_config_stale")?;
+            }
+            // 反映できなかった操作は取り消し履歴からも外し、やり直しを促す
+            SaveOutcome::Lost => {
+                let mut args = HashMap::new();
+                args.insert("actions".to_string(), unsaved.join(" / "));
+                self.status = self
+                    .i18n
+                    .get_message_with_args("error_config_stale_lost", &args)?;
+                self.journal = Journal::load()?;
+                self.game_finished = false;
+                return Ok(());
+            }
+        }
+        self.journal.save()?;
+
+        if std::mem::take(&mut self.game_finished) && self.config.check_winner().is_some() {
+            Tournament::update(|tournament| tournament.record_game(&self.config))?;
+        }
+        Ok(())
+    }
+
+    /// 画面全体を描画する
+    fn draw(&self, frame: &mut Frame) {
+        let [header, body, footer] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(8),
+            Constraint::Length(4),
+        ])
+        .areas(frame.area());
+        let [seats, events] =
+            Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)])
+                .areas(body);
+
+        frame.render_widget(self.header(), header);
+        let mut state = TableState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(self.seat_table(), seats, &mut state);
+        frame.render_widget(self.event_list(), events);
+        frame.render_widget(self.footer(), footer);
+    }
+
+    /// 卓の名前・ラウンド・現在のターン
+    fn header(&self) -> Paragraph<'_> {
+        let table = match config_path_with_source() {
+            (_, ValueSource::Table(name)) => name,
+            (path, _) => path.display().to_string(),
+        };
+        let mut args = HashMap::new();
+        args.insert("table".to_string(), table);
+        args.insert("round".to_string(), self.config.round.to_string());
+        let title = self.text_with_args("tui_title", &args);
+
+        let turn = match self.config.check_winner() {
+            Some(winner) => self.text_for("game_winner", &winner.name),
+            None => self
+                .config
+                .current_player()
+                .map(|p| self.text_for("current_turn", &p.name))
+                .unwrap_or_default(),
+        };
+
+        Paragraph::new(turn).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .title_style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+    }
+
+    /// 座席ごとの状態とロシアンルーレットの確率
+    fn seat_table(&self) -> Table<'_> {
+        let odds = format!(
+            "{:.0}%",
+            self.config.roulette_config().probability() * 100.0
+        );
+        let rows = self
+            .config
+            .players
+            .iter()
+            .enumerate()
+            .map(|(index, player)| {
+                let status = self.config.seat_status(player);
+                let (status_key, color) = match status {
+                    SeatStatus::Alive => ("tui_status_alive", Color::Green),
+                    SeatStatus::Out => ("tui_status_out", Color::Red),
+                    SeatStatus::Benched => ("tui_status_benched", Color::DarkGray),
+                };
+                let turn = if status == SeatStatus::Alive && player.id == self.config.current_turn {
+                    "▶"
+                } else {
+                    ""
+                };
+                Row::new(vec![
+                    turn.to_string(),
+                    (index + 1).to_string(),
+                    player.name.clone(),
+                    self.text(status_key),
+                    if status == SeatStatus::Alive {
+                        odds.clone()
+                    } else {
+                        "-".to_string()
+                    },
+                ])
+                .style(Style::default().fg(color))
+            });
+
+        let header = Row::new(vec![
+            String::new(),
+            self.text("tui_column_seat"),
+            self.text("tui_column_name"),
+            self.text("tui_column_status"),
+            self.text("tui_column_odds"),
+        ])
+        .style(Style::default().add_modifier(Modifier::BOLD));
+
+        Table::new(
+            rows,
+            [
+                Constraint::Length(2),
+                Constraint::Length(4),
+                Constraint::Min(10),
+                Constraint::Length(8),
+                Constraint::Length(6),
+            ],
+        )
+        .header(header)
+        .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(self.text("tui_seats")),
+        )
+    }
+
+    /// 取り消し履歴に記録された最近の出来事（新しい順）
+    fn event_list(&self) -> List<'_> {
+        let mut items: Vec<ListItem> = self
+            .journal
+            .recent(RECENT_EVENTS)
+            .map(|entry| ListItem::new(entry.description.clone()))
+            .collect();
+        if items.is_empty() {
+            items.push(ListItem::new(self.text("tui_no_events")));
+        }
+
+        List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(self.text("tui_events")),
+        )
+    }
+
+    /// 直近の操作結果とキー操作の説明
+    fn footer(&self) -> Paragraph<'_> {
+        Paragraph::new(vec![
+            self.status.clone().into(),
+            self.text("tui_key_help").into(),
+        ])
+        .block(Block::default().borders(Borders::ALL))
+    }
+
+    /// 描画用のメッセージ（キーがない場合はキー名をそのまま表示）
+    fn text(&self, key: &str) -> String {
+        self.i18n
+            .get_message(key)
+            .unwrap_or_else(|_| key.to_string())
+    }
+
+    fn text_for(&self, key: &str, name: &str) -> String {
+        self.message_for(key, name)
+            .unwrap_or_else(|_| key.to_string())
+    }
+
+    fn text_with_args(&self, key: &str, args: &HashMap<String, String>) -> String {
+        self.i18n
+            .get_message_with_args(key, args)
+            .unwrap_or_else(|_| key.to_string())
+    }
+}
+
+/// 全画面ダッシュボードを起動する（`--tui`）
+pub fn run() -> Result<(), TuiError> {
+    let mut dashboard = Dashboard::new()?;
+    let mut terminal = ratatui::init();
+    let result = dashboard.run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dashboard() -> Dashboard {
+        Dashboard::with_state(
+            I18nManager::new().unwrap(),
+            GameConfig::default(),
+            Journal::default(),
+        )
+    }
+
+    #[test]
+    fn test_roulette_requires_confirmation() {
+        let mut dashboard = dashboard();
+        dashboard.handle_key(KeyCode::Char('r')).unwrap();
+        assert_eq!(dashboard.pending_roulette, Some(1));
+
+        // y / Enter 以外は取り消し
+        dashboard.handle_key(KeyCode::Char('n')).unwrap();
+        assert!(dashboard.pending_roulette.is_none());
+        assert!(dashboard.journal.peek_undo().is_none());
+
+        dashboard.handle_key(KeyCode::Char('r')).unwrap();
+        dashboard.handle_key(KeyCode::Char('y')).unwrap();
+        let entry = dashboard.journal.peek_undo().unwrap();
+        assert_eq!(entry.kind, ActionKind::Roulette);
+        assert_eq!(dashboard.config.round, 2);
+        assert!(dashboard.dirty);
+    }
+
+    #[test]
+    fn test_recover_and_revive_follow_seat_status() {
+        let mut dashboard = dashboard();
+        dashboard
+            .config
+            .add_player(Some("Extra".to_string()))
+            .unwrap();
+        dashboard.config.set_participant_count(4).unwrap();
+        dashboard.config.eliminate_player(2).unwrap();
+
+        // 生存中のプレイヤーは回復できない
+        dashboard.handle_key(KeyCode::Char('c')).unwrap();
+        assert!(dashboard.journal.peek_undo().is_none());
+
+        dashboard.handle_key(KeyCode::Down).unwrap();
+        dashboard.handle_key(KeyCode::Char('c')).unwrap();
+        assert!(dashboard.config.get_player(2).unwrap().is_active);
+
+        // 控え席（末尾）のプレイヤーを蘇生
+        for _ in 0..dashboard.config.players.len() {
+            dashboard.handle_key(KeyCode::Down).unwrap();
+        }
+        dashboard.handle_key(KeyCode::Char('v')).unwrap();
+        assert_eq!(
+            dashboard.journal.peek_undo().unwrap().kind,
+            ActionKind::Revive
+        );
+        assert_eq!(
+            dashboard.config.participant_count,
+            dashboard.config.players.len() as u8
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use crate::config::{GameConfig, SeatStatus};
+use crate::i18n::I18nManager;
+use crate::rng::{self, Random};
+use crate::roulette::{self, RouletteResult};
+use crate::storage::MemoryStorage;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Display;
+use wasm_bindgen::prelude::*;
+
+/// 座席の表示用の状態
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SeatView {
+    id: u8,
+    name: String,
+    status: &'static str, // "alive" / "out" / "benched"
+    turn: bool,
+}
+
+/// `state()`で返す卓の状態
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TableView {
+    language: String,
+    round: u32,
+    capacity: u8,
+    live_rounds: u8,
+    odds: f64,
+    current_turn: u8,
+    winner: Option<u8>,
+    game_completed: bool,
+    seats: Vec<SeatView>,
+}
+
+/// `spin()`で返すロシアンルーレットの結果
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpinView {
+    result: &'static str, // "safe" / "out"
+    chamber: usize,
+    loaded_chambers: Vec<usize>,
+    message: String,
+    winner: Option<u8>,
+}
+
+/// JavaScriptから操作する卓
+///
+/// 保存はJS側で行う（`save()`が返すTOMLをlocalStorageなどに置き、次回`new`に渡す）
+#[wasm_bindgen]
+pub struct LiarsTable {
+    config: GameConfig,
+    i18n: I18nManager,
+    storage: MemoryStorage,
+    rng: Random,
+}
+
+#[wasm_bindgen]
+impl LiarsTable {
+    /// 保存済みの内容（なければ`undefined`）から卓を作成
+    #[wasm_bindgen(constructor)]
+    pub fn new(saved: Option<String>) -> Result<LiarsTable, JsError> {
+        Self::with_rng(saved, rng::default_rng())
+    }
+
+    /// 乱数のシードを指定して作成（結果を再現したいテスト用）
+    #[wasm_bindgen(js_name = withSeed)]
+    pub fn with_seed(saved: Option<String>, seed: u32) -> Result<LiarsTable, JsError> {
+        Self::with_rng(saved, rng::seeded(seed as u64))
+    }
+
+    /// 現在の状態（座席・ターン・確率など）
+    pub fn state(&self) -> Result<JsValue, JsError> {
+        let config = &self.config;
+        let seats = config
+            .players
+            .iter()
+            .map(|player| {
+                let status = config.seat_status(player);
+                SeatView {
+                    id: player.id,
+                    name: player.name.clone(),
+                    status: match status {
+                        SeatStatus::Alive => "alive",
+                        SeatStatus::Out => "out",
+                        SeatStatus::Benched => "benched",
+                    },
+                    turn: status == SeatStatus::Alive && player.id == config.current_turn,
+                }
+            })
+            .collect();
+
+        let view = TableView {
+            language: config.language.clone(),
+            round: config.round,
+            capacity: config.bullet_capacity,
+            live_rounds: config.live_rounds,
+            odds: config.roulette_probability_percentage(),
+            current_turn: config.current_turn,
+            winner: config.check_winner().map(|winner| winner.id),
+            game_completed: config.game_completed,
+            seats,
+        };
+        serde_wasm_bindgen::to_value(&view).map_err(js_error)
+    }
+
+    /// 指定したプレイヤーでロシアンルーレットを実行
+    pub fn spin(&mut self, player_id: u8) -> Result<JsValue, JsError> {
+        if self.config.game_completed {
+            return Err(JsError::new("The game is over"));
+        }
+        let name = self.player_name(player_id)?;
+
+        let outcome = roulette::spin_chambers_with(&self.config.roulette_config(), &mut self.rng);
+        self.config
+            .resolve_roulette(player_id, &outcome.result)
+            .map_err(js_error)?;
+
+        let key = match outcome.result {
+            RouletteResult::Safe => "roulette_result_safe",
+            RouletteResult::Out => "roulette_result_out",
+        };
+        let mut message = self.message_for(key, &name)?;
+        let winner = self.config.check_winner().map(|p| (p.id, p.name.clone()));
+        if let Some((_, winner_name)) = &winner {
+            message = format!(
+                "{} {}",
+                message,
+                self.message_for("game_winner", winner_name)?
+            );
+            self.config.mark_game_completed();
+        }
+
+        let view = SpinView {
+            result: match outcome.result {
+                RouletteResult::Safe => "safe",
+                RouletteResult::Out => "out",
+            },
+            chamber: outcome.chamber,
+            loaded_chambers: outcome.loaded_chambers,
+            message,
+            winner: winner.map(|(id, _)| id),
+        };
+        serde_wasm_bindgen::to_value(&view).map_err(js_error)
+    }
+
+    /// 除外された参加者を回復
+    pub fn recover(&mut self, player_id: u8) -> Result<(), JsError> {
+        self.config.recover_player(player_id).map_err(js_error)
+    }
+
+    /// 控え席のプレイヤーを蘇生
+    pub fn revive(&mut self, player_id: u8) -> Result<(), JsError> {
+        self.config.revive_player(player_id).map_err(js_error)
+    }
+
+    /// 新しいゲームを開始
+    #[wasm_bindgen(js_name = newGame)]
+    pub fn new_game(&mut self) {
+        self.config.reset_game();
+    }
+
+    /// 表示言語を切り替える（"ja" / "en"）
+    #[wasm_bindgen(js_name = setLanguage)]
+    pub fn set_language(&mut self, language: &str) -> Result<(), JsError> {
+        self.i18n.load_language(language).map_err(js_error)?;
+        self.config.language = language.to_string();
+        Ok(())
+    }
+
+    /// 現在の言語のメッセージ
+    pub fn message(&self, key: &str) -> Result<String, JsError> {
+        self.i18n.get_message(key).map_err(js_error)
+    }
+
+    /// 保存する内容（TOML）を返す
+    pub fn save(&mut self) -> Result<String, JsError> {
+        self.config
+            .save_to_storage(&mut self.storage)
+            .map_err(js_error)?;
+        Ok(self.storage.contents().unwrap_or_default().to_string())
+    }
+}
+
+impl LiarsTable {
+    fn with_rng(saved: Option<String>, rng: Random) -> Result<LiarsTable, JsError> {
+        let storage = MemoryStorage::new(saved);
+        let (config, _) = GameConfig::load_from_storage(&storage).map_err(js_error)?;
+        let mut i18n = I18nManager::bundled().map_err(js_error)?;
+        if i18n.is_language_available(&config.language) {
+            i18n.load_language(&config.language).map_err(js_error)?;
+        }
+        Ok(Self {
+            config,
+            i18n,
+            storage,
+            rng,
+        })
+    }
+
+    fn player_name(&self, player_id: u8) -> Result<String, JsError> {
+        self.config
+            .get_player(player_id)
+            .map(|player| player.name.clone())
+            .ok_or_else(|| JsError::new(&format!("Player {} not found", player_id)))
+    }
+
+    fn message_for(&self, key: &str, name: &str) -> Result<String, JsError> {
+        let mut args = HashMap::new();
+        args.insert("name".to_string(), name.to_string());
+        self.i18n
+            .get_message_with_args(key, &args)
+            .map_err(js_error)
+    }
+}
+
+/// RustのエラーをJSの`Error`に変換
+fn js_error(error: impl Display) -> JsError {
+    JsError::new(&error.to_string())
+}
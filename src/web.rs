@@ -0,0 +1,734 @@
+use crate::journal::{ActionKind, Journal, JournalError};
+use crate::record::{self, RecordError};
+use liars_table::{
+    ConfigError, GameConfig, I18nError, I18nManager, RandomSource, RouletteResult, SeatStatus,
+    SpinOutcome, ValueSource, config_path_with_source, default_rng, spin_chambers,
+};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::net::{IpAddr, ToSocketAddrs};
+use thiserror::Error;
+use tiny_http::{Header, Method, Response, Server};
+
+/// 画面のHTML（バイナリに同梱）
+const INDEX_HTML: &str = include_str!("../web/index.html");
+
+/// 既定の待ち受けポート
+pub const DEFAULT_PORT: u16 = 7878;
+
+/// 既定の待ち受けホスト（このコンピューターからのみ操作できる）
+const LOCAL_HOST: &str = "127.0.0.1";
+
+/// `--lan`での待ち受けホスト（LAN内のスマートフォンから操作できるよう全インターフェース）
+const LAN_HOST: &str = "0.0.0.0";
+
+/// 画面に埋め込むセッショントークンの置き換え位置
+const TOKEN_PLACEHOLDER: &str = "{{SESSION_TOKEN}}";
+
+/// 書き換えのリクエストでセッショントークンを送るヘッダー
+const TOKEN_HEADER: &str = "X-Liars-Token";
+
+/// 「最近の出来事」に表示する件数
+const RECENT_EVENTS: usize = 8;
+
+/// 画面の表示に使う文言のキー
+const LABEL_KEYS: [&str; 14] = [
+    "tui_seats",
+    "tui_events",
+    "tui_status_alive",
+    "tui_status_out",
+    "tui_status_benched",
+    "roulette_spinning",
+    "animation_click",
+    "animation_bang",
+    "web_roulette",
+    "web_recover",
+    "web_revive",
+    "web_new_game",
+    "web_confirm_roulette",
+    "tui_no_events",
+];
+
+#[derive(Error, Debug)]
+#[allow(clippy::enum_variant_names)]
+pub enum WebError {
+    #[error("Configuration error: {0}")]
+    ConfigError(#[from] ConfigError),
+    #[error("I18n error: {0}")]
+    I18nError(#[from] I18nError),
+    #[error("Journal error: {0}")]
+    JournalError(#[from] JournalError),
+    #[error("Record error: {0}")]
+    RecordError(#[from] RecordError),
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Server error: {0}")]
+    ServerError(String),
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+}
+
+impl WebError {
+    /// エラーに対応するHTTPステータスコード
+    fn status_code(&self) -> u16 {
+        match self {
+            WebError::InvalidRequest(_) | WebError::ConfigError(ConfigError::InvalidValue(_)) => {
+                400
+            }
+            WebError::Forbidden(_) => 403,
+            WebError::ConfigError(ConfigError::StaleConfig) => 409,
+            _ => 500,
+        }
+    }
+}
+
+/// 画面から行える操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Roulette(u8),
+    Recover(u8),
+    Revive(u8),
+    NewGame,
+}
+
+/// 操作の対象プレイヤー（`{"player_id": 2}`）
+#[derive(Debug, Deserialize)]
+struct PlayerRequest {
+    player_id: u8,
+}
+
+/// 操作を反映した結果
+struct Applied {
+    kind: ActionKind,
+    message: String,
+    spin: Option<SpinOutcome>,
+}
+
+/// 画面の演出に使う直近のロシアンルーレットの結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SpinReport {
+    seq: u64, // 画面が新しい結果を見分けるための通し番号
+    player_id: u8,
+    outcome: SpinOutcome,
+    chambers: u8,
+    message: String,
+}
+
+/// 同じ`GameConfig`を操作するWeb画面のサーバー
+///
+/// リクエストのたびに設定と取り消し履歴を読み込み直すため、
+/// 対話式UIや別の端末での変更もそのまま反映される。
+/// 書き換えのリクエストは、起動ごとに作るセッショントークンを埋め込んだ
+/// このサーバーの画面からのものだけを受け付ける
+pub struct WebServer {
+    i18n: I18nManager,
+    last_spin: Option<SpinReport>,
+    token: String,
+    /// 待ち受けアドレスのホスト部分（`Host`ヘッダーとして受け付ける）
+    bound_host: String,
+    /// LANに公開しているか（IPアドレスでの接続も受け付ける）
+    lan: bool,
+}
+
+impl WebServer {
+    pub fn new() -> Result<Self, WebError> {
+        let mut random = default_rng();
+        Ok(Self {
+            i18n: I18nManager::new()?,
+            last_spin: None,
+            token: format!("{:016x}{:016x}", random.next_u64(), random.next_u64()),
+            bound_host: LOCAL_HOST.to_string(),
+            lan: false,
+        })
+    }
+
+    /// 指定したアドレスで待ち受ける（Ctrl+Cで終了）
+    pub fn serve(&mut self, addr: &str, lan: bool) -> Result<(), WebError> {
+        let server = Server::http(addr).map_err(|e| WebError::ServerError(e.to_string()))?;
+        self.bound_host = host_name(addr).to_ascii_lowercase();
+        self.lan = lan;
+
+        let config = GameConfig::load()?;
+        self.use_language(&config.language)?;
+        let mut args = HashMap::new();
+        args.insert("addr".to_string(), addr.to_string());
+        let key = if is_local(addr) {
+            "web_listening"
+        } else {
+            "web_listening_lan"
+        };
+        println!("{}", self.i18n.get_message_with_args(key, &args)?);
+
+        for mut request in server.incoming_requests() {
+            let mut body = String::new();
+            let (status, content_type, payload) =
+                match request.as_reader().read_to_string(&mut body) {
+                    Ok(_) => self.route(request.method(), request.url(), request.headers(), &body),
+                    Err(e) => error_response(&WebError::IoError(e)),
+                };
+
+            let header = Header::from_bytes("Content-Type", content_type)
+                .expect("content type header is valid");
+            let response = Response::from_string(payload)
+                .with_status_code(status)
+                .with_header(header);
+            // 応答前に接続が切れた場合は何もしない
+            let _ = request.respond(response);
+        }
+        Ok(())
+    }
+
+    /// リクエストを処理して (ステータス, Content-Type, 本文) を返す
+    fn route(
+        &mut self,
+        method: &Method,
+        url: &str,
+        headers: &[Header],
+        body: &str,
+    ) -> (u16, &'static str, String) {
+        // トークンを返す前に、別の名前を経由した接続（DNSリバインディング）を拒否する
+        if let Err(e) = self.check_host(headers) {
+            return error_response(&e);
+        }
+        if *method == Method::Post
+            && let Err(e) = self.authorize(headers)
+        {
+            return error_response(&e);
+        }
+
+        let path = url.split('?').next().unwrap_or_default();
+        let result = match (method, path) {
+            (Method::Get, "/") => {
+                let page = INDEX_HTML.replace(TOKEN_PLACEHOLDER, &self.token);
+                return (200, "text/html; charset=utf-8", page);
+            }
+            (Method::Get, "/api/state") => self.state(),
+            (Method::Post, "/api/roulette") => {
+                parse_player(body).and_then(|id| self.perform(Action::Roulette(id)))
+            }
+            (Method::Post, "/api/recover") => {
+                parse_player(body).and_then(|id| self.perform(Action::Recover(id)))
+            }
+            (Method::Post, "/api/revive") => {
+                parse_player(body).and_then(|id| self.perform(Action::Revive(id)))
+            }
+            (Method::Post, "/api/new-game") => self.perform(Action::NewGame),
+            _ => {
+                return (
+                    404,
+                    "application/json",
+                    json!({ "error": "Not found" }).to_string(),
+                );
+            }
+        };
+
+        match result {
+            Ok(value) => (200, "application/json", value.to_string()),
+            Err(e) => error_response(&e),
+        }
+    }
+
+    /// `Host`ヘッダーがこのサーバー宛ての名前か確認する
+    ///
+    /// ループバックの名前・アドレスと待ち受けアドレス、`--lan`の場合はIPアドレスでの接続のみ受け付ける
+    fn check_host(&self, headers: &[Header]) -> Result<(), WebError> {
+        let host = headers
+            .iter()
+            .find(|header| header.field.equiv("Host"))
+            .map(|header| header.value.as_str())
+            .unwrap_or_default();
+        let name = host_name(host).to_ascii_lowercase();
+        let allowed = match name.parse::<IpAddr>() {
+            Ok(ip) => ip.is_loopback() || self.lan || name == self.bound_host,
+            Err(_) => !name.is_empty() && (name == "localhost" || name == self.bound_host),
+        };
+        if allowed {
+            Ok(())
+        } else {
+            Err(WebError::Forbidden(format!("Unexpected Host: {}", host)))
+        }
+    }
+
+    /// 書き換えのリクエストを確認する
+    ///
+    /// セッショントークン・同じオリジン（`Origin`がある場合）・JSONの本文を必須とし、
+    /// 他のサイトのページからの操作を受け付けない
+    fn authorize(&self, headers: &[Header]) -> Result<(), WebError> {
+        let value = |name: &'static str| {
+            headers
+                .iter()
+                .find(|header| header.field.equiv(name))
+                .map(|header| header.value.as_str())
+        };
+
+        if value(TOKEN_HEADER) != Some(self.token.as_str()) {
+            return Err(WebError::Forbidden("Invalid session token".to_string()));
+        }
+        if let Some(origin) = value("Origin") {
+            let host = value("Host").unwrap_or_default();
+            if origin.strip_prefix("http://") != Some(host) {
+                return Err(WebError::Forbidden(format!(
+                    "Cross-origin request from {}",
+                    origin
+                )));
+            }
+        }
+        let content_type = value("Content-Type").unwrap_or_default();
+        if content_type.split(';').next().map(str::trim) != Some("application/json") {
+            return Err(WebError::InvalidRequest(
+                "Content-Type must be application/json".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// 現在の状態を読み込んで返す
+    fn state(&mut self) -> Result<Value, WebError> {
+        let config = GameConfig::load()?;
+        let journal = Journal::load()?;
+        self.use_language(&config.language)?;
+        Ok(state_json(
+            &config,
+            &journal,
+            &self.i18n,
+            self.last_spin.as_ref(),
+        ))
+    }
+
+    /// 操作を反映して保存し、新しい状態を返す
+    fn perform(&mut self, action: Action) -> Result<Value, WebError> {
+        let mut config = GameConfig::load()?;
+        self.use_language(&config.language)?;
+
+        let before = config.clone();
+        let applied = apply(&mut config, &self.i18n, action)?;
+        config.save()?;
+        let spin = match (action, &applied.spin) {
+            (Action::Roulette(player_id), Some(outcome)) => Some((player_id, outcome)),
+            _ => None,
+        };
+        let journal =
+            record::record_saved(applied.kind, applied.message.clone(), before, &config, spin)?;
+
+        if let (Action::Roulette(player_id), Some(outcome)) = (action, applied.spin) {
+            let seq = self.last_spin.as_ref().map_or(1, |spin| spin.seq + 1);
+            self.last_spin = Some(SpinReport {
+                seq,
+                player_id,
+                outcome,
+                chambers: config.bullet_capacity,
+                message: applied.message,
+            });
+        }
+
+        Ok(state_json(
+            &config,
+            &journal,
+            &self.i18n,
+            self.last_spin.as_ref(),
+        ))
+    }
+
+    /// 設定の言語に切り替える
+    fn use_language(&mut self, language: &str) -> Result<(), WebError> {
+        if self.i18n.current_language() != language && self.i18n.is_language_available(language) {
+            self.i18n.load_language(language)?;
+        }
+        Ok(())
+    }
+}
+
+/// リクエスト本文から対象プレイヤーを読み取る
+fn parse_player(body: &str) -> Result<u8, WebError> {
+    serde_json::from_str::<PlayerRequest>(body)
+        .map(|request| request.player_id)
+        .map_err(|e| WebError::InvalidRequest(e.to_string()))
+}
+
+/// エラーの応答
+fn error_response(error: &WebError) -> (u16, &'static str, String) {
+    (
+        error.status_code(),
+        "application/json",
+        json!({ "error": error.to_string() }).to_string(),
+    )
+}
+
+/// プレイヤー名を引数にしたメッセージ
+fn message_for(i18n: &I18nManager, key: &str, name: &str) -> Result<String, WebError> {
+    let mut args = HashMap::new();
+    args.insert("name".to_string(), name.to_string());
+    Ok(i18n.get_message_with_args(key, &args)?)
+}
+
+/// 操作を設定に反映する（保存は呼び出し側で行う）
+fn apply(config: &mut GameConfig, i18n: &I18nManager, action: Action) -> Result<Applied, WebError> {
+    // 操作できる状態の席かを確認し、プレイヤー名を返す
+    let seat = |id: u8, expected: SeatStatus, key: &str| {
+        let player = config
+            .get_player(id)
+            .ok_or_else(|| WebError::InvalidRequest(format!("Player {} not found", id)))?;
+        if config.seat_status(player) != expected {
+            return Err(WebError::InvalidRequest(message_for(
+                i18n,
+                key,
+                &player.name,
+            )?));
+        }
+        Ok(player.name.clone())
+    };
+
+    match action {
+        Action::Roulette(id) => {
+            if config.game_completed {
+                return Err(WebError::InvalidRequest(i18n.get_message("web_game_over")?));
+            }
+            let name = seat(id, SeatStatus::Alive, "tui_not_alive")?;
+
+            let outcome = spin_chambers(&config.roulette_config());
+            config.resolve_roulette(id, &outcome.result)?;
+            let key = match outcome.result {
+                RouletteResult::Safe => "roulette_result_safe",
+                RouletteResult::Out => "roulette_result_out",
+            };
+            let mut message = message_for(i18n, key, &name)?;
+            if let Some(winner) = config.check_winner() {
+                message = format!(
+                    "{} {}",
+                    message,
+                    message_for(i18n, "game_winner", &winner.name)?
+                );
+                config.mark_game_completed();
+            }
+            Ok(Applied {
+                kind: ActionKind::Roulette,
+                message,
+                spin: Some(outcome),
+            })
+        }
+        Action::Recover(id) => {
+            let name = seat(id, SeatStatus::Out, "tui_not_recoverable")?;
+            config.recover_player(id)?;
+            Ok(Applied {
+                kind: ActionKind::Recovery,
+                message: message_for(i18n, "player_recovered", &name)?,
+                spin: None,
+            })
+        }
+        Action::Revive(id) => {
+            let name = seat(id, SeatStatus::Benched, "tui_not_revivable")?;
+            config.revive_player(id)?;
+            Ok(Applied {
+                kind: ActionKind::Revive,
+                message: message_for(i18n, "player_revived", &name)?,
+                spin: None,
+            })
+        }
+        Action::NewGame => {
+            config.reset_game();
+            Ok(Applied {
+                kind: ActionKind::Reset,
+                message: i18n.get_message("game_reset")?,
+                spin: None,
+            })
+        }
+    }
+}
+
+/// 画面に渡す状態
+fn state_json(
+    config: &GameConfig,
+    journal: &Journal,
+    i18n: &I18nManager,
+    last_spin: Option<&SpinReport>,
+) -> Value {
+    let text = |key: &str| i18n.get_message(key).unwrap_or_else(|_| key.to_string());
+    let text_for =
+        |key: &str, name: &str| message_for(i18n, key, name).unwrap_or_else(|_| key.to_string());
+
+    let table = match config_path_with_source() {
+        (_, ValueSource::Table(name)) => name,
+        (path, _) => path.display().to_string(),
+    };
+    let mut args = HashMap::new();
+    args.insert("table".to_string(), table);
+    args.insert("round".to_string(), config.round.to_string());
+    let title = i18n
+        .get_message_with_args("tui_title", &args)
+        .unwrap_or_default();
+
+    let winner = config
+        .check_winner()
+        .map(|winner| text_for("game_winner", &winner.name));
+    let turn = match (&winner, config.current_player()) {
+        (None, Some(player)) => text_for("current_turn", &player.name),
+        _ => String::new(),
+    };
+
+    let seats: Vec<Value> = config
+        .players
+        .iter()
+        .map(|player| {
+            let status = match config.seat_status(player) {
+                SeatStatus::Alive => "alive",
+                SeatStatus::Out => "out",
+                SeatStatus::Benched => "benched",
+            };
+            json!({
+                "id": player.id,
+                "name": player.name,
+                "status": status,
+                "turn": status == "alive" && player.id == config.current_turn,
+            })
+        })
+        .collect();
+
+    let mut events: Vec<String> = journal
+        .recent(RECENT_EVENTS)
+        .map(|entry| entry.description.clone())
+        .collect();
+    if events.is_empty() {
+        events.push(text("tui_no_events"));
+    }
+
+    let labels: HashMap<&str, String> = LABEL_KEYS.iter().map(|&key| (key, text(key))).collect();
+    let last_spin = last_spin.map(|spin| {
+        json!({
+            "seq": spin.seq,
+            "player_id": spin.player_id,
+            "result": match spin.outcome.result {
+                RouletteResult::Safe => "safe",
+                RouletteResult::Out => "out",
+            },
+            "chamber": spin.outcome.chamber,
+            "chambers": spin.chambers,
+            "message": spin.message,
+        })
+    });
+
+    json!({
+        "title": title.trim(),
+        "language": config.language,
+        "round": config.round,
+        "capacity": config.bullet_capacity,
+        "live": config.live_rounds,
+        "odds": config.roulette_probability_percentage(),
+        "animation_ms": if config.animation.enabled { config.animation.duration_ms } else { 0 },
+        "turn": turn,
+        "winner": winner,
+        "game_completed": config.game_completed,
+        "seats": seats,
+        "events": events,
+        "last_spin": last_spin,
+        "labels": labels,
+    })
+}
+
+/// `ホスト:ポート`からホスト部分を取り出す（`[::1]:7878`のようなIPv6も扱う）
+fn host_name(host: &str) -> &str {
+    if let Some(rest) = host.strip_prefix('[') {
+        return rest.split(']').next().unwrap_or_default();
+    }
+    match host.rsplit_once(':') {
+        Some((name, port)) if !name.contains(':') && port.parse::<u16>().is_ok() => name,
+        _ => host,
+    }
+}
+
+/// 待ち受けアドレスがこのコンピューターだけのものか
+fn is_local(addr: &str) -> bool {
+    addr.to_socket_addrs()
+        .is_ok_and(|mut addrs| addrs.all(|addr| addr.ip().is_loopback()))
+}
+
+/// `web`サブコマンドの引数（`--lan`を除いたもの）から待ち受けアドレスを決める
+///
+/// 既定ではこのコンピューターからのみ操作でき、LANに公開するには`lan`（`--lan`）が必要
+pub fn listen_addr(lan: bool, args: &[&str]) -> Result<String, WebError> {
+    let host = if lan { LAN_HOST } else { LOCAL_HOST };
+    let addr = match args {
+        [] => format!("{}:{}", host, DEFAULT_PORT),
+        ["--port", port] => {
+            let port = port
+                .parse::<u16>()
+                .map_err(|e| WebError::InvalidRequest(format!("--port {}: {}", port, e)))?;
+            format!("{}:{}", host, port)
+        }
+        [addr] => addr.to_string(),
+        _ => {
+            return Err(WebError::InvalidRequest(
+                "usage: web [--lan] [--port N | ADDR]".to_string(),
+            ));
+        }
+    };
+
+    if !lan && !is_local(&addr) {
+        return Err(WebError::InvalidRequest(format!(
+            "{} is reachable from other computers; pass --lan to allow it",
+            addr
+        )));
+    }
+    Ok(addr)
+}
+
+/// Web画面のサーバーを起動する（`web`サブコマンド）
+pub fn run(addr: &str, lan: bool) -> Result<(), WebError> {
+    WebServer::new()?.serve(addr, lan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_actions() {
+        let i18n = I18nManager::new().unwrap();
+        let mut config = GameConfig::default();
+
+        // 存在しないプレイヤーは操作できない
+        assert!(apply(&mut config, &i18n, Action::Roulette(9)).is_err());
+
+        let applied = apply(&mut config, &i18n, Action::Roulette(2)).unwrap();
+        assert_eq!(applied.kind, ActionKind::Roulette);
+        let outcome = applied.spin.unwrap();
+        assert_eq!(
+            config.get_player(2).unwrap().is_active,
+            outcome.result == RouletteResult::Safe
+        );
+
+        config.players[2].is_active = false;
+        let applied = apply(&mut config, &i18n, Action::Recover(3)).unwrap();
+        assert_eq!(applied.kind, ActionKind::Recovery);
+        assert!(config.get_player(3).unwrap().is_active);
+
+        // 生存中のプレイヤーは回復できない
+        assert!(apply(&mut config, &i18n, Action::Recover(1)).is_err());
+    }
+
+    #[test]
+    fn test_state_json_describes_seats() {
+        let i18n = I18nManager::new().unwrap();
+        let mut config = GameConfig::default();
+        config.add_player(Some("Extra".to_string())).unwrap();
+        config.set_participant_count(4).unwrap();
+        config.eliminate_player(2).unwrap();
+
+        let state = state_json(&config, &Journal::default(), &i18n, None);
+        let statuses: Vec<&str> = state["seats"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|seat| seat["status"].as_str().unwrap())
+            .collect();
+        assert_eq!(statuses, vec!["alive", "out", "alive", "alive", "benched"]);
+        assert_eq!(state["seats"][0]["turn"], true);
+        assert_eq!(state["odds"], 17.0);
+        assert!(state["labels"]["web_roulette"].is_string());
+        assert!(state["last_spin"].is_null());
+    }
+
+    fn header(name: &str, value: &str) -> Header {
+        Header::from_bytes(name, value).unwrap()
+    }
+
+    #[test]
+    fn test_post_requires_session_token() {
+        let mut server = WebServer::new().unwrap();
+        server.token = "secret".to_string();
+        let valid = [
+            header(TOKEN_HEADER, "secret"),
+            header("Host", "127.0.0.1:7878"),
+            header("Origin", "http://127.0.0.1:7878"),
+            header("Content-Type", "application/json"),
+        ];
+        assert!(server.authorize(&valid).is_ok());
+
+        // トークンがない・違う場合は書き換えを拒否する
+        let (status, _, _) = server.route(&Method::Post, "/api/new-game", &valid[1..], "{}");
+        assert_eq!(status, 403);
+        let mut wrong = valid.clone();
+        wrong[0] = header(TOKEN_HEADER, "guess");
+        assert_eq!(server.authorize(&wrong).unwrap_err().status_code(), 403);
+
+        // 他のサイトのページ・JSON以外の本文も拒否する
+        let mut cross = valid.clone();
+        cross[2] = header("Origin", "http://evil.example");
+        assert_eq!(server.authorize(&cross).unwrap_err().status_code(), 403);
+        let mut form = valid.clone();
+        form[3] = header("Content-Type", "text/plain");
+        assert_eq!(server.authorize(&form).unwrap_err().status_code(), 400);
+
+        // 画面にはトークンが埋め込まれる
+        let (status, _, page) = server.route(&Method::Get, "/", &valid[1..2], "");
+        assert_eq!(status, 200);
+        assert!(page.contains("\"secret\""));
+        assert!(!page.contains(TOKEN_PLACEHOLDER));
+    }
+
+    #[test]
+    fn test_unexpected_host_is_rejected() {
+        let mut server = WebServer::new().unwrap();
+        for host in [
+            "127.0.0.1:7878",
+            "localhost:7878",
+            "[::1]:7878",
+            "LOCALHOST",
+        ] {
+            let (status, _, _) = server.route(&Method::Get, "/", &[header("Host", host)], "");
+            assert_eq!(status, 200, "{}", host);
+        }
+
+        // 他の名前・LANのアドレス・Hostなしではトークンを返さない
+        for headers in [
+            vec![header("Host", "evil.example:7878")],
+            vec![header("Host", "192.168.1.5:7878")],
+            vec![],
+        ] {
+            let (status, _, page) = server.route(&Method::Get, "/", &headers, "");
+            assert_eq!(status, 403);
+            assert!(!page.contains(&server.token));
+        }
+        let (status, _, _) = server.route(
+            &Method::Get,
+            "/api/state",
+            &[header("Host", "evil.example")],
+            "",
+        );
+        assert_eq!(status, 403);
+
+        // --lanではIPアドレスでの接続を受け付けるが、名前は待ち受けアドレスのみ
+        server.lan = true;
+        server.bound_host = "0.0.0.0".to_string();
+        let lan = [header("Host", "192.168.1.5:7878")];
+        assert_eq!(server.route(&Method::Get, "/", &lan, "").0, 200);
+        let evil = [header("Host", "evil.example:7878")];
+        assert_eq!(server.route(&Method::Get, "/", &evil, "").0, 403);
+    }
+
+    #[test]
+    fn test_listen_addr_is_local_unless_lan() {
+        assert_eq!(listen_addr(false, &[]).unwrap(), "127.0.0.1:7878");
+        assert_eq!(listen_addr(true, &[]).unwrap(), "0.0.0.0:7878");
+        assert_eq!(
+            listen_addr(false, &["--port", "8080"]).unwrap(),
+            "127.0.0.1:8080"
+        );
+        assert_eq!(
+            listen_addr(false, &["localhost:9000"]).unwrap(),
+            "localhost:9000"
+        );
+        assert!(listen_addr(false, &["0.0.0.0:9000"]).is_err());
+        assert!(listen_addr(true, &["0.0.0.0:9000"]).is_ok());
+        assert!(listen_addr(false, &["--port", "x"]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_request_body() {
+        let error = parse_player("{}").unwrap_err();
+        assert_eq!(error.status_code(), 400);
+        assert_eq!(parse_player(r#"{"player_id": 3}"#).unwrap(), 3);
+    }
+}
@@ -0,0 +1,105 @@
+// 公開APIだけを使う結合テスト（Discordボットなど外部のクレートと同じ使い方）
+
+use liars_table::{
+    CardType, ConfigError, Deck, Game, GameConfig, I18nManager, MemoryStorage, RouletteConfig,
+    RouletteResult, SeatStatus, Storage, seeded_rng, spin, spin_chambers_with,
+};
+use std::collections::HashMap;
+
+#[test]
+fn roulette_until_a_winner_is_decided() {
+    let mut config = GameConfig::default();
+    let mut random = seeded_rng(2024);
+
+    while config.check_winner().is_none() {
+        let shooter = config.current_player().unwrap().id;
+        let outcome = spin_chambers_with(&config.roulette_config(), &mut random);
+        config.resolve_roulette(shooter, &outcome.result).unwrap();
+    }
+    config.mark_game_completed();
+
+    let winner = config.check_winner().unwrap().id;
+    assert_eq!(config.active_player_count(), 1);
+    assert_eq!(config.finishing_order()[0], winner);
+    assert_eq!(config.placements().len(), config.participants().len());
+
+    let eliminated = config.players.iter().find(|p| p.id != winner).unwrap();
+    assert_eq!(config.seat_status(eliminated), SeatStatus::Out);
+}
+
+#[test]
+fn spin_respects_loaded_bullets() {
+    let always = RouletteConfig {
+        chambers: 6,
+        loaded_bullets: 6,
+    };
+    let never = RouletteConfig {
+        chambers: 6,
+        loaded_bullets: 0,
+    };
+    assert_eq!(spin(&always), RouletteResult::Out);
+    assert_eq!(spin(&never), RouletteResult::Safe);
+}
+
+#[test]
+fn config_round_trips_through_custom_storage() {
+    let mut storage = MemoryStorage::default();
+    let (mut config, report) = GameConfig::load_from_storage(&storage).unwrap();
+    assert!(report.is_none());
+
+    config.change_player_name(1, "Alice".to_string()).unwrap();
+    config.save_to_storage(&mut storage).unwrap();
+    assert!(storage.read().unwrap().unwrap().contains("Alice"));
+
+    // 別の利用者が先に保存した場合、古い内容からの上書きは拒否される
+    let (mut first, _) = GameConfig::load_from_storage(&storage).unwrap();
+    let (mut second, _) = GameConfig::load_from_storage(&storage).unwrap();
+    first.change_player_name(2, "Bob".to_string()).unwrap();
+    first.save_to_storage(&mut storage).unwrap();
+    second.change_player_name(3, "Carol".to_string()).unwrap();
+    assert!(matches!(
+        second.save_to_storage(&mut storage),
+        Err(ConfigError::StaleConfig)
+    ));
+}
+
+#[test]
+fn card_game_deals_and_challenges() {
+    let mut game = Game::try_new(3, 1).unwrap();
+    game.deal_cards_with(&mut seeded_rng(7)).unwrap();
+    assert!(game.players.iter().all(|p| p.hand.len() == 5));
+    assert_eq!(game.deck.remaining(), Deck::new().remaining() - 15);
+
+    game.play_cards(1, vec![0], CardType::Ace).unwrap();
+    let result = game.challenge(2).unwrap();
+    assert_eq!(result.target_player, 1);
+    assert_eq!(
+        result.is_liar,
+        !result
+            .actual_cards
+            .iter()
+            .all(|card| card.card_type.satisfies(CardType::Ace))
+    );
+
+    // 同じシードなら同じ手札が配られる
+    let mut again = Game::new(3, 1);
+    again.deal_cards_with(&mut seeded_rng(7)).unwrap();
+    let mut replay = Game::new(3, 1);
+    replay.deal_cards_with(&mut seeded_rng(7)).unwrap();
+    assert_eq!(again.players[0].hand, replay.players[0].hand);
+}
+
+#[test]
+fn bundled_messages_are_available_in_every_language() {
+    let mut i18n = I18nManager::bundled().unwrap();
+    let mut args = HashMap::new();
+    args.insert("name".to_string(), "Alice".to_string());
+
+    for code in ["ja", "en"] {
+        i18n.load_language(code).unwrap();
+        let message = i18n
+            .get_message_with_args("roulette_result_out", &args)
+            .unwrap();
+        assert!(message.contains("Alice"));
+    }
+}
@@ -0,0 +1,45 @@
+// wasm-bindgen-testでNode.js上で実行するテスト
+//
+//   cargo install wasm-bindgen-cli
+//   CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER=wasm-bindgen-test-runner \
+//     cargo test --target wasm32-unknown-unknown --no-default-features --features wasm
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use liars_table::LiarsTable;
+use serde_json::Value;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn state(table: &LiarsTable) -> Value {
+    serde_wasm_bindgen::from_value(table.state().unwrap()).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn new_table_has_default_seats() {
+    let table = LiarsTable::new(None).unwrap();
+    let state = state(&table);
+    assert_eq!(state["seats"].as_array().unwrap().len(), 4);
+    assert_eq!(state["currentTurn"], 1);
+    assert_eq!(state["seats"][0]["status"], "alive");
+}
+
+#[wasm_bindgen_test]
+fn seeded_spins_are_reproducible() {
+    let mut first = LiarsTable::with_seed(None, 7).unwrap();
+    let mut second = LiarsTable::with_seed(None, 7).unwrap();
+    let a: Value = serde_wasm_bindgen::from_value(first.spin(1).unwrap()).unwrap();
+    let b: Value = serde_wasm_bindgen::from_value(second.spin(1).unwrap()).unwrap();
+    assert_eq!(a, b);
+    assert!(first.spin(9).is_err());
+}
+
+#[wasm_bindgen_test]
+fn saved_state_round_trips() {
+    let mut table = LiarsTable::new(None).unwrap();
+    table.set_language("en").unwrap();
+    table.revive(1).unwrap_err();
+    let saved = table.save().unwrap();
+
+    let restored = LiarsTable::new(Some(saved)).unwrap();
+    assert_eq!(state(&restored)["language"], "en");
+    assert!(restored.message("game_reset").unwrap().contains("new game"));
+}